@@ -0,0 +1,272 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    sync::{Arc, Condvar, LazyLock, Mutex},
+    task::{Context, Waker},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    executor::BoxFuture,
+    runtime::builder::{DEFAULT_KEEP_ALIVE, DEFAULT_MAX_BLOCKING_THREADS, ThreadSpec},
+};
+
+thread_local! {
+    /// Pool de hilos bloqueantes del runtime que ejecuta este hilo
+    static CURRENT: RefCell<Option<BlockingPool>> = const { RefCell::new(None) };
+}
+
+/// Pool compartido por los hilos que no pertenecen a ningún `Runtime`
+static GLOBAL: LazyLock<BlockingPool> = LazyLock::new(|| {
+    BlockingPool::new(
+        DEFAULT_MAX_BLOCKING_THREADS,
+        DEFAULT_KEEP_ALIVE,
+        ThreadSpec::default(),
+    )
+});
+
+struct State {
+    /// Trabajos esperando un hilo libre
+    queue: VecDeque<BoxFuture>,
+    /// Hilos vivos
+    threads: usize,
+    /// Hilos esperando trabajo
+    idle: usize,
+    /// Hilos ociosos a los que ya se les asignó un trabajo de la cola
+    notified: usize,
+    shutdown: bool,
+    /// Contador para nombrar los hilos
+    next_id: usize,
+}
+
+struct Inner {
+    state: Mutex<State>,
+    /// Avisa a los hilos ociosos de que hay trabajo o de que hay que salir
+    work: Condvar,
+    /// Avisa a `shutdown` cada vez que termina un hilo
+    exited: Condvar,
+    max_threads: usize,
+    keep_alive: Duration,
+    spec: ThreadSpec,
+}
+
+/// Pool elástico de hilos para trabajo bloqueante
+///
+/// Los hilos se crean a demanda hasta `max_threads`; por encima, los
+/// trabajos esperan en cola. Un hilo que pasa `keep_alive` sin trabajo
+/// termina, así el pool se encoge cuando deja de usarse.
+#[derive(Clone)]
+pub(crate) struct BlockingPool {
+    inner: Arc<Inner>,
+}
+
+impl BlockingPool {
+    pub(crate) fn new(max_threads: usize, keep_alive: Duration, spec: ThreadSpec) -> Self {
+        BlockingPool {
+            inner: Arc::new(Inner {
+                state: Mutex::new(State {
+                    queue: VecDeque::new(),
+                    threads: 0,
+                    idle: 0,
+                    notified: 0,
+                    shutdown: false,
+                    next_id: 0,
+                }),
+                work: Condvar::new(),
+                exited: Condvar::new(),
+                max_threads,
+                keep_alive,
+                spec,
+            }),
+        }
+    }
+
+    /// Encola un trabajo y lo asigna a un hilo ocioso, o crea uno nuevo
+    ///
+    /// Tras el apagado el trabajo se descarta: su `JoinHandle` recibirá
+    /// `JoinError::Cancelled`.
+    ///
+    /// Si el sistema no permite crear el hilo, el trabajo queda en cola
+    /// para los hilos que ya existen; sin ninguno, la cola se descarta del
+    /// mismo modo que en el apagado.
+    pub(crate) fn spawn(&self, job: BoxFuture) {
+        let mut state = self.inner.state.lock().unwrap();
+        if state.shutdown {
+            return;
+        }
+        state.queue.push_back(job);
+
+        if state.idle > 0 {
+            state.idle -= 1;
+            state.notified += 1;
+            self.inner.work.notify_one();
+        } else if state.threads < self.inner.max_threads {
+            state.threads += 1;
+            let id = state.next_id;
+            state.next_id += 1;
+            drop(state);
+
+            let pool = self.clone();
+            let spawned = self
+                .inner
+                .spec
+                .spawn(format!("async_runtime-blocking-{}", id), move || pool.run());
+
+            if spawned.is_err() {
+                let mut state = self.inner.state.lock().unwrap();
+                state.threads -= 1;
+                if state.threads == 0 {
+                    // Nadie vaciará la cola: sus trabajos se cancelan
+                    let orphaned = std::mem::take(&mut state.queue);
+                    drop(state);
+                    drop(orphaned);
+                }
+            }
+        }
+    }
+
+    /// Bucle de cada hilo del pool
+    fn run(&self) {
+        let _enter = enter(self.clone());
+        let inner = &self.inner;
+        let mut state = inner.state.lock().unwrap();
+
+        loop {
+            while let Some(mut job) = state.queue.pop_front() {
+                drop(state);
+                // El trabajo termina en un único sondeo: nadie lo despertará
+                let _ = job.as_mut().poll(&mut Context::from_waker(Waker::noop()));
+                drop(job);
+                state = inner.state.lock().unwrap();
+            }
+            if state.shutdown {
+                break;
+            }
+
+            state.idle += 1;
+            let (guard, wait) = inner.work.wait_timeout(state, inner.keep_alive).unwrap();
+            state = guard;
+
+            if state.notified > 0 {
+                // Quien encoló ya lo descontó de `idle`
+                state.notified -= 1;
+                continue;
+            }
+            state.idle -= 1;
+            if state.shutdown || (wait.timed_out() && state.queue.is_empty()) {
+                break;
+            }
+        }
+
+        state.threads -= 1;
+        inner.exited.notify_all();
+    }
+
+    /// Descarta los trabajos en cola y espera a que terminen los hilos
+    ///
+    /// Los trabajos en curso no se pueden interrumpir: con `deadline` se
+    /// abandonan los hilos que sigan ocupados al vencer el plazo.
+    pub(crate) fn shutdown(&self, deadline: Option<Instant>) {
+        let inner = &self.inner;
+        let mut state = inner.state.lock().unwrap();
+        state.shutdown = true;
+        let queued = std::mem::take(&mut state.queue);
+        inner.work.notify_all();
+
+        while state.threads > 0 {
+            state = match deadline {
+                None => inner.exited.wait(state).unwrap(),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        break;
+                    }
+                    inner.exited.wait_timeout(state, deadline - now).unwrap().0
+                }
+            };
+        }
+        drop(state);
+        drop(queued);
+    }
+}
+
+/// Restaura el pool anterior al salir del runtime
+pub(crate) struct EnterGuard {
+    previous: Option<BlockingPool>,
+}
+
+impl Drop for EnterGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Marca `pool` como el pool bloqueante del hilo actual mientras viva el guard
+pub(crate) fn enter(pool: BlockingPool) -> EnterGuard {
+    let previous = CURRENT.with(|current| current.borrow_mut().replace(pool));
+    EnterGuard { previous }
+}
+
+/// Pool del runtime actual, o el global fuera de un `Runtime`
+pub(crate) fn current() -> BlockingPool {
+    CURRENT
+        .with(|current| current.borrow().clone())
+        .unwrap_or_else(|| GLOBAL.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlockingPool;
+    use crate::{future::join_all, runtime::builder::ThreadSpec, task::joinable};
+    use std::{sync::mpsc, time::Duration};
+
+    #[test]
+    fn pool_is_bounded_and_shrinks_when_idle() {
+        let pool = BlockingPool::new(2, Duration::from_millis(10), ThreadSpec::default());
+        let (started_tx, started_rx) = mpsc::channel();
+
+        let (releases, handles): (Vec<_>, Vec<_>) = (0..4)
+            .map(|i| {
+                let (release_tx, release_rx) = mpsc::channel::<()>();
+                let started_tx = started_tx.clone();
+                let (job, handle) = joinable(
+                    async move {
+                        started_tx.send(i).unwrap();
+                        release_rx.recv().unwrap();
+                    },
+                    None,
+                );
+                pool.spawn(job);
+                (release_tx, handle)
+            })
+            .unzip();
+        assert_eq!(pool.inner.state.lock().unwrap().threads, 2);
+
+        // Con los dos hilos ocupados el tercer trabajo espera en cola
+        let mut first = [started_rx.recv().unwrap(), started_rx.recv().unwrap()];
+        first.sort();
+        assert_eq!(first, [0, 1]);
+        assert!(started_rx.try_recv().is_err());
+
+        // Al liberar un hilo, este toma el siguiente de la cola
+        releases[0].send(()).unwrap();
+        assert_eq!(started_rx.recv().unwrap(), 2);
+
+        for release in &releases[1..] {
+            release.send(()).unwrap();
+        }
+        crate::block_on(join_all(handles));
+
+        // Pasado el keep-alive los hilos ociosos terminan
+        let state = pool.inner.state.lock().unwrap();
+        let state = pool
+            .inner
+            .exited
+            .wait_while(state, |state| state.threads > 0)
+            .unwrap();
+        assert_eq!(state.threads, 0);
+        drop(state);
+        pool.shutdown(None);
+    }
+}
@@ -0,0 +1,80 @@
+use std::{
+    cell::RefCell,
+    sync::{Arc, OnceLock},
+    thread,
+};
+
+use crate::driver::Handle;
+
+/// Driver del hilo de temporizadores de respaldo (ver `current_or_fallback`)
+static FALLBACK: OnceLock<Arc<Handle>> = OnceLock::new();
+
+thread_local! {
+    /// Driver del ejecutor que está sondeando tareas en este hilo
+    static CURRENT: RefCell<Option<Arc<Handle>>> = const { RefCell::new(None) };
+}
+
+/// Restaura el driver anterior al salir del ejecutor
+pub(crate) struct EnterGuard {
+    previous: Option<Arc<Handle>>,
+}
+
+impl Drop for EnterGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Marca `handle` como el driver del hilo actual mientras viva el guard
+///
+/// Permite anidar ejecutores: al soltar el guard vuelve el anterior.
+pub(crate) fn enter(handle: Arc<Handle>) -> EnterGuard {
+    let previous = CURRENT.with(|current| current.borrow_mut().replace(handle));
+    EnterGuard { previous }
+}
+
+/// Aplica `f` al driver del ejecutor actual, si lo hay
+pub(crate) fn with_current<R>(f: impl FnOnce(&Handle) -> R) -> Option<R> {
+    CURRENT.with(|current| current.borrow().as_deref().map(f))
+}
+
+/// Driver del ejecutor actual
+///
+/// # Pánico
+/// Si se llama fuera de un ejecutor de `async_runtime`
+pub(crate) fn current() -> Arc<Handle> {
+    CURRENT
+        .with(|current| current.borrow().clone())
+        .expect("debe usarse dentro de un ejecutor de async_runtime")
+}
+
+/// Driver del ejecutor actual o, fuera de uno, el del hilo de respaldo
+///
+/// Así un `Sleep` también funciona sondeado desde otro ejecutor: la
+/// primera vez se lanza un hilo que solo duerme en su propio driver hasta
+/// el próximo vencimiento y despierta a quien corresponda.
+///
+/// # Pánico
+/// Si el sistema no permite crear el hilo o su instancia de epoll
+pub(crate) fn current_or_fallback() -> Arc<Handle> {
+    CURRENT
+        .with(|current| current.borrow().clone())
+        .unwrap_or_else(|| {
+            FALLBACK
+                .get_or_init(|| {
+                    let handle = Arc::new(Handle::new());
+                    let driver = handle.clone();
+                    thread::Builder::new()
+                        .name("async_runtime-timer".into())
+                        .spawn(move || {
+                            loop {
+                                driver.park();
+                            }
+                        })
+                        .expect("no se pudo lanzar el hilo de temporizadores");
+                    handle
+                })
+                .clone()
+        })
+}
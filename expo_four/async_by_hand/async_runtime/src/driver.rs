@@ -0,0 +1,113 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    reactor::Reactor,
+    time::{Clock, driver::TimerHeap},
+};
+
+/// Recursos del sistema compartidos por las tareas de un ejecutor
+///
+/// Temporizadores y reactor de E/S. El hilo que se queda sin trabajo
+/// duerme aquí (`park`), bloqueado en `epoll_wait`, hasta el próximo
+/// vencimiento, hasta que un descriptor esté listo o hasta que algún
+/// waker lo despierte (`unpark`).
+pub(crate) struct Handle {
+    pub(crate) time: TimerHeap,
+    pub(crate) io: Reactor,
+    /// Reloj con el que se miden los temporizadores
+    pub(crate) clock: Clock,
+    /// Hay un hilo dormido en `park`
+    parked: AtomicBool,
+    /// Se pidió despertar: el próximo `park` regresa de inmediato
+    notified: AtomicBool,
+}
+
+impl Handle {
+    /// # Pánico
+    /// Si el sistema no permite crear la instancia de epoll
+    pub(crate) fn new() -> Self {
+        Handle::with_clock(Clock::System)
+    }
+
+    /// # Pánico
+    /// Si el sistema no permite crear la instancia de epoll
+    pub(crate) fn with_clock(clock: Clock) -> Self {
+        Handle {
+            time: TimerHeap::new(),
+            io: Reactor::new().expect("no se pudo crear el reactor de E/S"),
+            clock,
+            parked: AtomicBool::new(false),
+            notified: AtomicBool::new(false),
+        }
+    }
+
+    /// Duerme el hilo actual hasta el próximo evento o hasta `unpark`
+    ///
+    /// Al despertar procesa los temporizadores vencidos. Si se llamó a
+    /// `unpark` antes de dormir, regresa de inmediato: así no se pierde un
+    /// aviso que llegue entre la última revisión de las colas y el `park`.
+    pub(crate) fn park(&self) {
+        // `parked` y `notified` se publican en orden opuesto en `park` y
+        // `unpark` (SeqCst): al menos uno de los dos ve la marca del otro
+        self.parked.store(true, Ordering::SeqCst);
+        if self.notified.swap(false, Ordering::SeqCst) {
+            self.parked.store(false, Ordering::SeqCst);
+            self.poll_events();
+            return;
+        }
+
+        if self.clock.is_mock() {
+            self.park_mocked();
+        } else {
+            let timeout = self
+                .time
+                .next_deadline()
+                .map(|deadline| deadline.saturating_duration_since(Instant::now()));
+            self.io.turn(timeout);
+        }
+
+        self.parked.store(false, Ordering::SeqCst);
+        self.notified.store(false, Ordering::SeqCst);
+        self.time.process(self.clock.now());
+    }
+
+    /// `park` con el reloj simulado: el tiempo no pasa mientras se duerme
+    ///
+    /// Si no hay E/S lista y el reloj avanza solo, salta directamente al
+    /// próximo temporizador. En otro caso espera E/S o un `unpark` sin
+    /// límite: un temporizador simulado nunca vence por sí mismo.
+    fn park_mocked(&self) {
+        if self.io.turn(Some(Duration::ZERO)) > 0 {
+            return;
+        }
+        match self.time.next_deadline() {
+            Some(deadline) if self.clock.auto_advances() => self.clock.advance_to(deadline),
+            _ => {
+                self.io.turn(None);
+            }
+        }
+    }
+
+    /// Procesa los eventos ya ocurridos sin dormir
+    pub(crate) fn poll_events(&self) {
+        self.io.turn(Some(Duration::ZERO));
+        self.time.process(self.clock.now());
+    }
+
+    /// Despierta al hilo dormido en `park`, o evita que el próximo duerma
+    pub(crate) fn unpark(&self) {
+        self.notified.store(true, Ordering::SeqCst);
+        if self.parked.load(Ordering::SeqCst) {
+            self.io.wake();
+        }
+    }
+
+    /// Libera los recursos registrados al apagar el ejecutor
+    pub(crate) fn shutdown(&self) {
+        self.time.clear();
+        self.io.shutdown();
+    }
+}
@@ -1,57 +1,182 @@
-use crate::waker::create_raw_waker;
+use crate::{
+    context,
+    driver::Handle,
+    future,
+    metrics::{Dump, RuntimeMetrics, TaskSnapshot, TaskState, TaskStats},
+    task::{JoinHandle, TaskInfo, coop, joinable},
+    waker::{ArcWake, create_waker},
+};
 use std::{
-    collections::VecDeque,
+    collections::{HashMap, VecDeque},
     future::Future,
-    pin::Pin,
-    sync::{Arc, mpsc},
-    task::{Context, Poll, Waker},
+    pin::{Pin, pin},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, Poll},
 };
 
+/// Identificador único de una tarea dentro de un ejecutor
+pub type TaskId = usize;
+
+/// Valor por defecto de `Executor::set_event_interval`
+pub(crate) const DEFAULT_EVENT_INTERVAL: u32 = 61;
+
+/// Futuro ya envuelto y fijado en memoria, listo para ser sondeado
+pub(crate) type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
 /// Representa una tarea asíncrona en el ejecutor
 ///
 /// Contiene:
+/// - `id`: Identificador con el que la tarea se encola al ser despertada
 /// - `future`: El futuro a ejecutar, fijado en memoria (pinned)
-/// - `waker`: El mecanismo de notificación para reactivar la tarea
+/// - `waker`: El mecanismo de notificación propio de esta tarea
+/// - `info`: Nombre y contadores que muestra `Executor::dump`
 pub struct Task {
-    future: Pin<Box<dyn Future<Output = ()> + Send>>,
-    waker: Arc<Waker>,
+    id: TaskId,
+    future: BoxFuture,
+    waker: Arc<TaskWaker>,
+    info: TaskInfo,
 }
 
-/// Manejador para recuperar el resultado de una tarea asíncrona
+/// Cola de tareas listas para ejecutarse
 ///
-/// Proporciona una interfaz para bloquear la ejecución hasta que
-/// la tarea completa su procesamiento y devuelve un resultado.
-pub struct JoinHandle<T> {
-    receiver: mpsc::Receiver<T>,
+/// Se comparte entre el ejecutor y los wakers de cada tarea: cuando una
+/// tarea es despertada, su waker empuja su identificador a esta cola.
+struct ReadyQueue {
+    queue: Mutex<VecDeque<TaskId>>,
+    /// Driver donde duerme el hilo del ejecutor; se despierta al encolar
+    driver: Arc<Handle>,
 }
 
-impl<T> JoinHandle<T> {
-    /// Bloquea el hilo actual hasta obtener el resultado de la tarea
-    ///
-    /// # Retorno
-    /// - `Ok(T)` si se recibe el resultado exitosamente
-    /// - `Err(mpsc::RecvError)` si el canal se cierra antes de recibir el dato
-    pub fn block_on(self) -> Result<T, mpsc::RecvError> {
-        self.receiver.recv()
+impl ReadyQueue {
+    fn push(&self, id: TaskId) {
+        self.queue.lock().unwrap().push_back(id);
+        self.driver.unpark();
+    }
+
+    fn pop(&self) -> Option<TaskId> {
+        self.queue.lock().unwrap().pop_front()
+    }
+
+    /// Extrae una tarea cualquiera, elegida con el generador del hilo
+    fn pop_random(&self) -> Option<TaskId> {
+        let mut queue = self.queue.lock().unwrap();
+        if queue.is_empty() {
+            return None;
+        }
+        let index = future::random_index(queue.len());
+        queue.remove(index)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.queue.lock().unwrap().is_empty()
+    }
+}
+
+/// Waker de una tarea concreta
+///
+/// Al despertarse encola el identificador de la tarea en la cola de listas.
+/// La bandera `scheduled` evita encolar la misma tarea varias veces si
+/// se despierta repetidamente antes de volver a ser ejecutada.
+struct TaskWaker {
+    id: TaskId,
+    scheduled: AtomicBool,
+    ready: Arc<ReadyQueue>,
+    stats: Arc<TaskStats>,
+}
+
+impl ArcWake for TaskWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.stats.record_wake();
+        // Solo la primera notificación encola la tarea
+        if !arc_self.scheduled.swap(true, Ordering::AcqRel) {
+            arc_self.ready.push(arc_self.id);
+        }
+    }
+}
+
+/// Waker del futuro raíz de `block_on`
+///
+/// Marca que el futuro raíz debe volver a sondearse y despierta al hilo
+/// bloqueado por si estaba dormido.
+struct RootWaker {
+    woken: AtomicBool,
+    driver: Arc<Handle>,
+}
+
+impl ArcWake for RootWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.woken.store(true, Ordering::Release);
+        arc_self.driver.unpark();
     }
 }
 
 /// Ejecutor simple para tareas asíncronas
 ///
-/// Utiliza una cola de doble extremo (VecDeque) para gestionar
-/// las tareas pendientes de procesamiento.
+/// Mantiene dos estructuras separadas:
+/// - `idle`: Tareas en espera, indexadas por su identificador. No se
+///   vuelven a sondear hasta que algo las despierte.
+/// - `ready`: Cola con los identificadores de las tareas despertadas,
+///   compartida con los wakers de cada tarea.
+///
+/// Además tiene su propio driver con los temporizadores de sus tareas.
 pub struct Executor {
-    pub polling: VecDeque<Task>,
+    idle: HashMap<TaskId, Task>,
+    ready: Arc<ReadyQueue>,
+    next_id: TaskId,
+    /// Sondeos seguidos de `block_on` antes de revisar E/S y temporizadores
+    event_interval: u32,
+    /// Sondea las tareas listas en orden pseudoaleatorio en lugar de FIFO
+    shuffle: bool,
+}
+
+impl Default for Executor {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Executor {
     /// Crea una nueva instancia del ejecutor
     pub fn new() -> Self {
+        Executor::with_driver(Handle::new(), false)
+    }
+
+    /// Ejecutor sobre `driver`; con `shuffle` elige al azar entre las tareas
+    /// listas (ver `test`)
+    pub(crate) fn with_driver(driver: Handle, shuffle: bool) -> Self {
         Executor {
-            polling: VecDeque::new(),
+            idle: HashMap::new(),
+            ready: Arc::new(ReadyQueue {
+                queue: Mutex::new(VecDeque::new()),
+                driver: Arc::new(driver),
+            }),
+            next_id: 0,
+            event_interval: DEFAULT_EVENT_INTERVAL,
+            shuffle,
         }
     }
 
+    /// Driver del ejecutor, para el runtime de pruebas
+    pub(crate) fn driver(&self) -> &Arc<Handle> {
+        &self.ready.driver
+    }
+
+    /// Máximo de tareas que `block_on` sondea seguidas antes de revisar
+    /// la E/S y los temporizadores
+    ///
+    /// Valores bajos atienden antes los eventos externos; valores altos
+    /// reducen las llamadas al sistema cuando hay muchas tareas listas.
+    ///
+    /// # Pánico
+    /// Si `polls` es cero
+    pub fn set_event_interval(&mut self, polls: u32) {
+        assert!(polls > 0, "event_interval debe ser mayor que cero");
+        self.event_interval = polls;
+    }
+
     /// Añade una nueva tarea al ejecutor
     ///
     /// # Parámetros
@@ -61,77 +186,330 @@ impl Executor {
     /// `JoinHandle<T>`: Manejador para recuperar el resultado asíncrono
     ///
     /// # Comportamiento
-    /// 1. Crea un canal para comunicación con la tarea
-    /// 2. Envuelve el futuro original para que:
-    ///     a. Ejecute el futuro interno
-    ///     b. Envíe el resultado a través del canal
-    /// 3. Crea un waker para notificaciones
-    /// 4. Almacena la tarea en la cola de procesamiento
+    /// 1. Envuelve el futuro para que publique su resultado (ver `joinable`)
+    /// 2. Crea un waker propio de la tarea
+    /// 3. Guarda la tarea y la marca como lista para su primer sondeo
     pub fn spawn<F, T>(&mut self, future: F) -> JoinHandle<T>
     where
         F: Future<Output = T> + 'static + Send,
         T: Send + 'static,
     {
-        // Canal para comunicación con la tarea (Single Producer)
-        let (tx, rx) = mpsc::channel();
+        self.spawn_inner(future, None)
+    }
 
-        // Adaptador que envía el resultado al completarse
-        let wrapped_future = Box::pin(async move {
-            let result = future.await;
-            let _ = tx.send(result); // Ignora errores de envío
-        });
+    /// Como `spawn`, pero la tarea lleva un nombre
+    ///
+    /// El nombre se consulta con `JoinHandle::name` o, desde la propia
+    /// tarea, con `task::current()`.
+    pub fn spawn_named<F, T>(&mut self, name: impl Into<String>, future: F) -> JoinHandle<T>
+    where
+        F: Future<Output = T> + 'static + Send,
+        T: Send + 'static,
+    {
+        self.spawn_inner(future, Some(name.into()))
+    }
+
+    pub(crate) fn spawn_inner<F, T>(&mut self, future: F, name: Option<String>) -> JoinHandle<T>
+    where
+        F: Future<Output = T> + 'static + Send,
+        T: Send + 'static,
+    {
+        let (wrapped_future, handle) = joinable(future, name);
+
+        let id = self.next_id;
+        self.next_id += 1;
 
         // Construye la tarea con su mecanismo de notificación
+        let info = handle.info().clone();
         let task = Task {
+            id,
             future: wrapped_future,
-            waker: self.create_waker(),
+            waker: self.create_waker(id, info.stats().clone()),
+            info,
         };
 
-        // Añade la tarea a la cola de procesamiento
-        self.polling.push_back(task);
+        // Toda tarea nueva se sondea al menos una vez
+        self.idle.insert(id, task);
+        self.ready.push(id);
 
-        JoinHandle { receiver: rx }
+        handle
     }
 
-    /// Procesa una tarea de la cola de ejecución
+    /// Procesa una tarea de la cola de listas
     ///
     /// # Comportamiento
-    /// 1. Extrae la primera tarea de la cola
-    /// 2. Crea un contexto de ejecución con el waker
-    /// 3. Intenta progresar la ejecución del futuro
-    /// 4. Vuelve a encolar si la tarea está pendiente
+    /// 1. Despierta las tareas cuyos temporizadores ya vencieron
+    /// 2. Extrae el siguiente identificador despertado
+    /// 3. Retira la tarea correspondiente del conjunto de tareas en espera
+    /// 4. Intenta progresar la ejecución del futuro con su propio waker
+    /// 5. Si queda pendiente vuelve al conjunto de espera, sin reencolarla:
+    ///    solo volverá a la cola cuando su waker sea invocado
     pub fn poll(&mut self) {
-        // Extrae la siguiente tarea (si existe)
-        let mut task = match self.polling.pop_front() {
+        let _enter = context::enter(self.ready.driver.clone());
+        self.ready.driver.poll_events();
+        self.run_next();
+    }
+
+    /// Sondea la siguiente tarea despertada, si la hay
+    fn run_next(&mut self) {
+        // Extrae la siguiente tarea despertada (si existe)
+        let next = if self.shuffle {
+            self.ready.pop_random()
+        } else {
+            self.ready.pop()
+        };
+        let id = match next {
+            Some(id) => id,
+            None => return, // Finaliza si no hay tareas listas
+        };
+
+        // La tarea pudo completarse después de haber sido despertada
+        let mut task = match self.idle.remove(&id) {
             Some(task) => task,
-            None => return, // Finaliza si no hay tareas
+            None => return,
         };
 
+        // A partir de aquí cualquier notificación vuelve a encolar la tarea
+        task.waker.scheduled.store(false, Ordering::Release);
+
         // Prepara el contexto de ejecución
-        let waker_ref = task.waker.clone();
-        let context = &mut Context::from_waker(&waker_ref);
+        let waker = create_waker(task.waker.clone());
+        let context = &mut Context::from_waker(&waker);
 
-        // Ejecuta el futuro hasta su próximo punto de espera
-        match task.future.as_mut().poll(context) {
-            Poll::Ready(()) => {} // Tarea completada (no se vuelve a encolar)
+        // Ejecuta el futuro hasta su próximo punto de espera, con el
+        // presupuesto de operaciones completo
+        match coop::budget(|| task.future.as_mut().poll(context)) {
+            Poll::Ready(()) => {} // Tarea completada (se descarta)
             Poll::Pending => {
-                // Vuelve a encolar para procesamiento posterior
-                self.polling.push_back(task);
+                // Queda en espera hasta que su waker la despierte
+                self.idle.insert(task.id, task);
             }
         }
     }
 
-    /// Crea un nuevo Waker para notificar al ejecutor
+    /// Ejecuta un futuro raíz hasta completarse en el hilo actual
+    ///
+    /// # Parámetros
+    /// - `future`: Futuro raíz; no necesita ser `Send` ni `'static`
     ///
-    /// # Seguridad
-    /// Utiliza `create_raw_waker` que debe implementar correctamente:
-    /// - Clonación segura
-    /// - Notificación eficiente
-    /// - Manejo adecuado de memoria
-    fn create_waker(&self) -> Arc<Waker> {
-        Arc::new(unsafe {
-            // Conversión segura solo si create_raw_waker es correcta
-            Waker::from_raw(create_raw_waker())
+    /// # Retorno
+    /// El resultado del futuro raíz
+    ///
+    /// # Comportamiento
+    /// 1. Sondea el futuro raíz cada vez que su waker lo despierta
+    /// 2. Entre sondeos ejecuta las tareas lanzadas que estén listas
+    /// 3. Si nada está listo, duerme el hilo en el driver hasta el próximo
+    ///    temporizador o hasta que algún waker, del raíz o de una tarea,
+    ///    lo despierte
+    ///
+    /// Las tareas que sigan pendientes cuando el raíz termina se conservan
+    /// y avanzarán en la siguiente llamada a `block_on` o `poll`.
+    pub fn block_on<F: Future>(&mut self, future: F) -> F::Output {
+        let mut future = pin!(future);
+
+        let driver = self.ready.driver.clone();
+        let _enter = context::enter(driver.clone());
+
+        let root = Arc::new(RootWaker {
+            woken: AtomicBool::new(true),
+            driver: driver.clone(),
+        });
+        let waker = create_waker(root.clone());
+        let context = &mut Context::from_waker(&waker);
+
+        loop {
+            if root.woken.swap(false, Ordering::AcqRel)
+                && let Poll::Ready(output) = coop::budget(|| future.as_mut().poll(context))
+            {
+                return output;
+            }
+
+            // Solo las tareas listas en este momento, para no dejar sin
+            // turno al futuro raíz si alguna se despierta a sí misma, y
+            // como mucho `event_interval` antes de revisar los eventos
+            let ready = self
+                .ready
+                .queue
+                .lock()
+                .unwrap()
+                .len()
+                .min(self.event_interval as usize);
+            for _ in 0..ready {
+                self.run_next();
+            }
+
+            if !root.woken.load(Ordering::Acquire) && !self.has_ready_tasks() {
+                // Duerme hasta el próximo temporizador o hasta un waker
+                driver.park();
+            } else {
+                driver.poll_events();
+            }
+        }
+    }
+
+    /// Indica si hay tareas despertadas esperando ser sondeadas
+    pub fn has_ready_tasks(&self) -> bool {
+        !self.ready.is_empty()
+    }
+
+    /// Indica si el ejecutor ya no tiene tareas vivas
+    pub fn is_empty(&self) -> bool {
+        self.idle.is_empty()
+    }
+
+    /// Recuento de las tareas vivas según su estado
+    ///
+    /// Las tareas de este ejecutor solo se sondean dentro de sus propios
+    /// métodos, así que desde fuera nunca hay ninguna en ejecución.
+    pub fn metrics(&self) -> RuntimeMetrics {
+        RuntimeMetrics::new(1, self.dump().tasks())
+    }
+
+    /// Lista las tareas vivas con su nombre, estado y contadores
+    pub fn dump(&self) -> Dump {
+        let tasks = self
+            .idle
+            .values()
+            .map(|task| {
+                let state = if task.waker.scheduled.load(Ordering::Acquire) {
+                    TaskState::Ready
+                } else {
+                    TaskState::Idle
+                };
+                TaskSnapshot::new(&task.info, state)
+            })
+            .collect();
+        Dump::new(tasks)
+    }
+
+    /// Crea el estado del waker de la tarea `id`
+    ///
+    /// El waker comienza marcado como encolado porque `spawn`
+    /// encola la tarea inmediatamente. Antes de cada sondeo la bandera se
+    /// limpia para que cualquier notificación posterior la vuelva a encolar.
+    fn create_waker(&self, id: TaskId, stats: Arc<TaskStats>) -> Arc<TaskWaker> {
+        Arc::new(TaskWaker {
+            id,
+            scheduled: AtomicBool::new(true),
+            ready: self.ready.clone(),
+            stats,
         })
     }
 }
+
+impl Drop for Executor {
+    /// Suelta los temporizadores pendientes: sus wakers apuntan a este ejecutor
+    fn drop(&mut self) {
+        self.ready.driver.shutdown();
+    }
+}
+
+/// Ejecuta un futuro hasta completarse en el hilo actual
+///
+/// Atajo para crear un `Executor` nuevo y llamar a `Executor::block_on`.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    Executor::new().block_on(future)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Executor;
+    use std::{
+        future::Future,
+        pin::Pin,
+        sync::{Arc, Mutex},
+        task::{Context, Poll, Waker},
+        thread,
+        time::Duration,
+    };
+
+    /// Futuro que nunca termina y cuenta cuántas veces se sondea
+    struct Counted {
+        polls: Arc<Mutex<(usize, Option<Waker>)>>,
+    }
+
+    impl Future for Counted {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let mut polls = self.polls.lock().unwrap();
+            polls.0 += 1;
+            polls.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    /// Futuro que otro hilo completa tras un retraso
+    struct Remote {
+        state: Arc<Mutex<(bool, Option<Waker>)>>,
+    }
+
+    impl Future for Remote {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let mut state = self.state.lock().unwrap();
+            if state.0 {
+                Poll::Ready(())
+            } else {
+                state.1 = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn block_on_parks_until_a_task_is_woken() {
+        let mut executor = Executor::new();
+        let state = Arc::new(Mutex::new((false, None::<Waker>)));
+
+        let remote = Remote {
+            state: state.clone(),
+        };
+        let handle = executor.spawn(async move {
+            remote.await;
+            7
+        });
+
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            let mut state = state.lock().unwrap();
+            state.0 = true;
+            if let Some(waker) = state.1.take() {
+                waker.wake();
+            }
+        });
+
+        let result = executor.block_on(async { handle.await.unwrap() * 6 });
+        assert_eq!(result, 42);
+        assert!(executor.is_empty());
+    }
+
+    #[test]
+    fn pending_task_is_only_polled_again_after_its_waker_fires() {
+        let mut executor = Executor::new();
+        let polls = Arc::new(Mutex::new((0, None::<Waker>)));
+
+        let _handle = executor.spawn(Counted {
+            polls: polls.clone(),
+        });
+
+        executor.poll();
+        assert_eq!(polls.lock().unwrap().0, 1);
+
+        // Sin notificación la tarea no vuelve a la cola
+        for _ in 0..10 {
+            executor.poll();
+        }
+        assert_eq!(polls.lock().unwrap().0, 1);
+        assert!(!executor.has_ready_tasks());
+
+        let waker = polls.lock().unwrap().1.take().unwrap();
+        waker.wake();
+        assert!(executor.has_ready_tasks());
+
+        executor.poll();
+        assert_eq!(polls.lock().unwrap().0, 2);
+        assert!(!executor.is_empty());
+    }
+}
@@ -0,0 +1,431 @@
+use std::{
+    fs::{self, Metadata},
+    future::poll_fn,
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::Path,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, ready},
+};
+
+use super::{OpenOptions, asyncify, join_error};
+use crate::{
+    io::{AsyncRead, AsyncWrite},
+    task::{JoinHandle, spawn_blocking},
+};
+
+/// Máximo de bytes que mueve una sola operación en el pool de bloqueo
+const MAX_BUF: usize = 2 * 1024 * 1024;
+
+/// Buffer que viaja entre el `File` y el hilo del pool que lo usa
+#[derive(Default)]
+struct Buf {
+    data: Vec<u8>,
+    /// Bytes leídos ya entregados a quien llamó a `poll_read`
+    pos: usize,
+}
+
+impl Buf {
+    /// No quedan datos leídos sin entregar
+    fn is_empty(&self) -> bool {
+        self.pos == self.data.len()
+    }
+
+    fn copy_to(&mut self, dst: &mut [u8]) -> usize {
+        let len = dst.len().min(self.data.len() - self.pos);
+        dst[..len].copy_from_slice(&self.data[self.pos..self.pos + len]);
+        self.pos += len;
+        len
+    }
+
+    /// Guarda lo que quepa de `src` para escribirlo en segundo plano
+    fn copy_from(&mut self, src: &[u8]) -> usize {
+        let len = src.len().min(MAX_BUF);
+        self.data.clear();
+        self.data.extend_from_slice(&src[..len]);
+        self.pos = 0;
+        len
+    }
+
+    /// Descarta lo leído sin entregar
+    ///
+    /// # Retorno
+    /// Cuánto hay que mover la posición del archivo para que vuelva a
+    /// apuntar al primer byte no entregado (cero o negativo)
+    fn discard_read(&mut self) -> i64 {
+        let unread = (self.data.len() - self.pos) as i64;
+        self.data.clear();
+        self.pos = 0;
+        -unread
+    }
+
+    fn read_from(&mut self, mut file: &fs::File, len: usize) -> io::Result<usize> {
+        self.data.resize(len, 0);
+        self.pos = 0;
+        let result = loop {
+            match file.read(&mut self.data) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                result => break result,
+            }
+        };
+        self.data.truncate(*result.as_ref().unwrap_or(&0));
+        result
+    }
+
+    fn write_to(&mut self, mut file: &fs::File) -> io::Result<()> {
+        let result = file.write_all(&self.data);
+        self.data.clear();
+        result
+    }
+}
+
+/// Resultado de la última operación lanzada en el pool
+enum Operation {
+    Read(io::Result<usize>),
+    Write(io::Result<()>),
+}
+
+enum State {
+    /// Sin operación en curso; el buffer puede guardar datos leídos
+    Idle(Option<Buf>),
+    Busy(JoinHandle<(Operation, Buf)>),
+}
+
+/// Archivo abierto cuyas operaciones corren en el pool de bloqueo
+///
+/// Implementa `AsyncRead` y `AsyncWrite`. Las escrituras se completan en
+/// segundo plano: `write` vuelve en cuanto copia los datos, y un fallo se
+/// devuelve en la siguiente operación. Hay que llamar a `flush` antes de
+/// soltar el archivo para conocer el resultado de la última escritura;
+/// `flush` no fuerza los datos al disco, para eso está `sync_all`.
+///
+/// Solo puede haber una operación en curso por archivo, así que no aporta
+/// nada repartir un mismo `File` entre varias tareas.
+///
+/// # Ejemplo
+/// ```no_run
+/// use async_runtime::{
+///     fs::File,
+///     io::{AsyncReadExt, AsyncWriteExt},
+/// };
+/// use std::io::SeekFrom;
+///
+/// async_runtime::block_on(async {
+///     let mut file = File::options()
+///         .read(true)
+///         .write(true)
+///         .create(true)
+///         .truncate(true)
+///         .open("contador.txt")
+///         .await?;
+///     file.write_all(b"41").await?;
+///     file.seek(SeekFrom::Start(0)).await?;
+///
+///     let mut contents = Vec::new();
+///     file.read_to_end(&mut contents).await?;
+///     Ok::<_, std::io::Error>(())
+/// })
+/// .unwrap();
+/// ```
+pub struct File {
+    std: Arc<fs::File>,
+    state: State,
+    /// Error de una escritura en segundo plano aún no entregado
+    last_write_err: Option<io::Error>,
+    /// Error de una lectura abandonada, para el siguiente `poll_read`
+    last_read_err: Option<io::Error>,
+}
+
+impl File {
+    /// Abre `path` en modo solo lectura
+    pub async fn open(path: impl AsRef<Path>) -> io::Result<File> {
+        OpenOptions::new().read(true).open(path).await
+    }
+
+    /// Abre `path` en modo escritura, creándolo o truncándolo
+    pub async fn create(path: impl AsRef<Path>) -> io::Result<File> {
+        OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .await
+    }
+
+    /// Opciones para abrir un archivo, como `std::fs::File::options`
+    pub fn options() -> OpenOptions {
+        OpenOptions::new()
+    }
+
+    pub fn from_std(file: fs::File) -> File {
+        File {
+            std: Arc::new(file),
+            state: State::Idle(Some(Buf::default())),
+            last_write_err: None,
+            last_read_err: None,
+        }
+    }
+
+    /// Mueve la posición de lectura y escritura
+    ///
+    /// Espera antes a la escritura en curso, si la hay. Si se cancela, la
+    /// posición resultante queda sin determinar.
+    pub async fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let unread = self.settle().await?;
+        let pos = match pos {
+            SeekFrom::Current(offset) => SeekFrom::Current(offset + unread),
+            pos => pos,
+        };
+        let std = self.std.clone();
+        asyncify(move || (&*std).seek(pos)).await
+    }
+
+    /// Cambia el tamaño del archivo, rellenando con ceros si crece
+    pub async fn set_len(&mut self, len: u64) -> io::Result<()> {
+        let unread = self.settle().await?;
+        let std = self.std.clone();
+        asyncify(move || {
+            if unread != 0 {
+                (&*std).seek(SeekFrom::Current(unread))?;
+            }
+            std.set_len(len)
+        })
+        .await
+    }
+
+    /// Fuerza al disco los datos y metadatos escritos
+    pub async fn sync_all(&mut self) -> io::Result<()> {
+        self.settle().await?;
+        let std = self.std.clone();
+        asyncify(move || std.sync_all()).await
+    }
+
+    /// Como `sync_all`, sin los metadatos que no hacen falta para leer
+    pub async fn sync_data(&mut self) -> io::Result<()> {
+        self.settle().await?;
+        let std = self.std.clone();
+        asyncify(move || std.sync_data()).await
+    }
+
+    pub async fn metadata(&self) -> io::Result<Metadata> {
+        let std = self.std.clone();
+        asyncify(move || std.metadata()).await
+    }
+
+    /// Espera la operación en curso y descarta lo leído sin entregar
+    ///
+    /// También entrega el error de una lectura que terminó sin que nadie
+    /// la recogiera.
+    ///
+    /// # Retorno
+    /// Desplazamiento a aplicar a la posición del archivo (ver
+    /// `Buf::discard_read`)
+    async fn settle(&mut self) -> io::Result<i64> {
+        poll_fn(|cx| AsyncWrite::poll_flush(Pin::new(&mut *self), cx)).await?;
+        if let Some(e) = self.last_read_err.take() {
+            return Err(e);
+        }
+        match &mut self.state {
+            State::Idle(Some(buf)) => Ok(buf.discard_read()),
+            _ => unreachable!("tras poll_flush no queda ninguna operación en curso"),
+        }
+    }
+
+    /// Espera la operación en curso para escribir sobre el archivo
+    ///
+    /// Devuelve el error de una escritura anterior; el de una lectura se
+    /// guarda para quien vuelva a leer.
+    fn poll_write_idle(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match ready!(self.poll_idle(cx))? {
+            Some(Operation::Write(Err(e))) => return Poll::Ready(Err(e)),
+            Some(Operation::Read(Err(e))) => self.last_read_err = Some(e),
+            _ => {}
+        }
+        match self.last_write_err.take() {
+            Some(e) => Poll::Ready(Err(e)),
+            None => Poll::Ready(Ok(())),
+        }
+    }
+
+    /// Espera a que termine la operación en curso en el pool, si la hay
+    fn poll_idle(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Option<Operation>>> {
+        let State::Busy(handle) = &mut self.state else {
+            return Poll::Ready(Ok(None));
+        };
+        match ready!(Pin::new(handle).poll(cx)) {
+            Ok((operation, buf)) => {
+                self.state = State::Idle(Some(buf));
+                Poll::Ready(Ok(Some(operation)))
+            }
+            Err(e) => {
+                self.state = State::Idle(Some(Buf::default()));
+                Poll::Ready(Err(join_error(e)))
+            }
+        }
+    }
+}
+
+impl AsyncRead for File {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        dst: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if dst.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        if let Some(e) = this.last_read_err.take() {
+            return Poll::Ready(Err(e));
+        }
+
+        loop {
+            let operation = ready!(this.poll_idle(cx))?;
+            let State::Idle(slot) = &mut this.state else {
+                unreachable!("poll_idle deja el archivo libre");
+            };
+            let mut buf = slot.take().unwrap_or_default();
+
+            let just_read = matches!(operation, Some(Operation::Read(_)));
+            match operation {
+                Some(Operation::Read(Err(e))) => {
+                    *slot = Some(buf);
+                    return Poll::Ready(Err(e));
+                }
+                Some(Operation::Write(Err(e))) => this.last_write_err = Some(e),
+                _ => {}
+            }
+
+            // Datos pendientes, o el resultado de la lectura que acaba de
+            // terminar: si no trajo nada, es el fin del archivo
+            if !buf.is_empty() || just_read {
+                let len = buf.copy_to(dst);
+                *slot = Some(buf);
+                return Poll::Ready(Ok(len));
+            }
+
+            let len = dst.len().min(MAX_BUF);
+            let std = this.std.clone();
+            this.state = State::Busy(spawn_blocking(move || {
+                let result = buf.read_from(&std, len);
+                (Operation::Read(result), buf)
+            }));
+        }
+    }
+}
+
+impl AsyncWrite for File {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        src: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_write_idle(cx))?;
+
+        let State::Idle(slot) = &mut this.state else {
+            unreachable!("poll_idle deja el archivo libre");
+        };
+        let mut buf = slot.take().unwrap_or_default();
+        // Lo leído de más no se entregó: la escritura empieza donde se quedó el lector
+        let unread = buf.discard_read();
+        let len = buf.copy_from(src);
+
+        let std = this.std.clone();
+        this.state = State::Busy(spawn_blocking(move || {
+            let mut result = Ok(());
+            if unread != 0 {
+                result = (&*std).seek(SeekFrom::Current(unread)).map(|_| ());
+            }
+            let result = result.and_then(|()| buf.write_to(&std));
+            (Operation::Write(result), buf)
+        }));
+        Poll::Ready(Ok(len))
+    }
+
+    /// Espera a que termine la escritura en segundo plano
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().poll_write_idle(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Buf, File, Operation, State};
+    use crate::{
+        block_on,
+        io::{AsyncReadExt, AsyncWriteExt},
+        task::spawn_blocking,
+    };
+    use std::{
+        env, fs,
+        io::{self, SeekFrom},
+        process,
+    };
+
+    #[test]
+    fn reads_writes_and_seeks_share_one_position() {
+        let path = env::temp_dir().join(format!("async_runtime-file-{}", process::id()));
+
+        let contents = block_on(async {
+            let mut file = File::options()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(&path)
+                .await
+                .unwrap();
+            file.write_all(b"0123456789").await.unwrap();
+            assert_eq!(file.seek(SeekFrom::Start(0)).await.unwrap(), 0);
+
+            let mut head = [0; 3];
+            file.read_exact(&mut head).await.unwrap();
+            assert_eq!(&head, b"012");
+
+            // La escritura va justo detrás de lo leído
+            file.write_all(b"X").await.unwrap();
+            assert_eq!(file.seek(SeekFrom::Current(0)).await.unwrap(), 4);
+
+            file.seek(SeekFrom::Start(0)).await.unwrap();
+            let mut contents = Vec::new();
+            file.read_to_end(&mut contents).await.unwrap();
+            contents
+        });
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(contents, b"012X456789");
+    }
+
+    #[test]
+    fn abandoned_read_error_reaches_the_next_read() {
+        let path = env::temp_dir().join(format!("async_runtime-read-err-{}", process::id()));
+        fs::write(&path, b"datos").unwrap();
+
+        block_on(async {
+            let mut file = File::open(&path).await.unwrap();
+
+            // Una lectura que falló y que nadie llegó a recoger
+            file.state = State::Busy(spawn_blocking(|| {
+                let error = io::Error::other("lectura fallida");
+                (Operation::Read(Err(error)), Buf::default())
+            }));
+
+            // `flush` no la pierde: la recibe la siguiente lectura
+            file.flush().await.unwrap();
+            let error = file.read(&mut [0; 8]).await.unwrap_err();
+            assert_eq!(error.to_string(), "lectura fallida");
+
+            let mut contents = Vec::new();
+            file.read_to_end(&mut contents).await.unwrap();
+            assert_eq!(contents, b"datos");
+        });
+
+        fs::remove_file(&path).unwrap();
+    }
+}
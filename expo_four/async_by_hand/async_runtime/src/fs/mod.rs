@@ -0,0 +1,139 @@
+mod file;
+mod open_options;
+
+use std::{
+    fs::{self, Metadata},
+    io, panic,
+    path::Path,
+};
+
+use crate::task::{JoinError, spawn_blocking};
+
+pub use file::File;
+pub use open_options::OpenOptions;
+
+/// Ejecuta una operación de archivos en el pool de bloqueo
+///
+/// Linux no ofrece readiness para archivos regulares (epoll siempre los da
+/// por listos), así que no pueden ir al reactor como los sockets.
+async fn asyncify<F, T>(f: F) -> io::Result<T>
+where
+    F: FnOnce() -> io::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    match spawn_blocking(f).await {
+        Ok(result) => result,
+        Err(e) => Err(join_error(e)),
+    }
+}
+
+/// Traduce el fallo de una tarea del pool de bloqueo
+///
+/// Un pánico se propaga a quien esperaba la operación; una cancelación
+/// solo ocurre si el runtime se está apagando.
+fn join_error(e: JoinError) -> io::Error {
+    match e {
+        JoinError::Panicked(payload) => panic::resume_unwind(payload),
+        JoinError::Cancelled => io::Error::other("el runtime se apagó durante la operación"),
+    }
+}
+
+/// Lee el archivo completo
+///
+/// # Ejemplo
+/// ```no_run
+/// async_runtime::block_on(async {
+///     let bytes = async_runtime::fs::read("mensaje.bin").await?;
+///     println!("{} bytes", bytes.len());
+///     Ok::<_, std::io::Error>(())
+/// })
+/// .unwrap();
+/// ```
+pub async fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || fs::read(path)).await
+}
+
+/// Lee el archivo completo como texto UTF-8
+pub async fn read_to_string(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || fs::read_to_string(path)).await
+}
+
+/// Escribe `contents` en el archivo, creándolo o truncándolo
+pub async fn write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let (path, contents) = (path.as_ref().to_owned(), contents.as_ref().to_owned());
+    asyncify(move || fs::write(path, contents)).await
+}
+
+/// Copia el contenido y los permisos de `from` en `to`
+///
+/// # Retorno
+/// Bytes copiados
+pub async fn copy(from: impl AsRef<Path>, to: impl AsRef<Path>) -> io::Result<u64> {
+    let (from, to) = (from.as_ref().to_owned(), to.as_ref().to_owned());
+    asyncify(move || fs::copy(from, to)).await
+}
+
+pub async fn rename(from: impl AsRef<Path>, to: impl AsRef<Path>) -> io::Result<()> {
+    let (from, to) = (from.as_ref().to_owned(), to.as_ref().to_owned());
+    asyncify(move || fs::rename(from, to)).await
+}
+
+pub async fn remove_file(path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || fs::remove_file(path)).await
+}
+
+pub async fn create_dir(path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || fs::create_dir(path)).await
+}
+
+/// Crea el directorio y los que falten por encima; no falla si ya existe
+pub async fn create_dir_all(path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || fs::create_dir_all(path)).await
+}
+
+/// Borra un directorio vacío
+pub async fn remove_dir(path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || fs::remove_dir(path)).await
+}
+
+/// Borra un directorio con todo su contenido
+pub async fn remove_dir_all(path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || fs::remove_dir_all(path)).await
+}
+
+/// Metadatos de `path`, siguiendo enlaces simbólicos
+pub async fn metadata(path: impl AsRef<Path>) -> io::Result<Metadata> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || fs::metadata(path)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::block_on;
+    use std::{env, io, process};
+
+    #[test]
+    fn files_and_directories_round_trip() {
+        let dir = env::temp_dir().join(format!("async_runtime-fs-{}", process::id()));
+
+        block_on(async {
+            let path = dir.join("a/b/datos.bin");
+            super::create_dir_all(path.parent().unwrap()).await.unwrap();
+            super::write(&path, b"hola").await.unwrap();
+
+            assert_eq!(super::read(&path).await.unwrap(), b"hola");
+            assert_eq!(super::metadata(&path).await.unwrap().len(), 4);
+
+            super::remove_dir_all(&dir).await.unwrap();
+            let missing = super::read_to_string(&path).await.unwrap_err();
+            assert_eq!(missing.kind(), io::ErrorKind::NotFound);
+        });
+    }
+}
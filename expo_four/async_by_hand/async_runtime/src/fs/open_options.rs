@@ -0,0 +1,72 @@
+use std::{fs, io, path::Path};
+
+use super::{File, asyncify};
+
+/// Opciones para abrir un `File`; versión asíncrona de `std::fs::OpenOptions`
+///
+/// # Ejemplo
+/// ```no_run
+/// use async_runtime::{fs::OpenOptions, io::AsyncWriteExt};
+///
+/// async_runtime::block_on(async {
+///     let mut log = OpenOptions::new()
+///         .append(true)
+///         .create(true)
+///         .open("server.log")
+///         .await?;
+///     log.write_all(b"arrancado\n").await?;
+///     log.flush().await
+/// })
+/// .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct OpenOptions(fs::OpenOptions);
+
+impl OpenOptions {
+    pub fn new() -> Self {
+        OpenOptions(fs::OpenOptions::new())
+    }
+
+    pub fn read(&mut self, read: bool) -> &mut Self {
+        self.0.read(read);
+        self
+    }
+
+    pub fn write(&mut self, write: bool) -> &mut Self {
+        self.0.write(write);
+        self
+    }
+
+    pub fn append(&mut self, append: bool) -> &mut Self {
+        self.0.append(append);
+        self
+    }
+
+    pub fn truncate(&mut self, truncate: bool) -> &mut Self {
+        self.0.truncate(truncate);
+        self
+    }
+
+    pub fn create(&mut self, create: bool) -> &mut Self {
+        self.0.create(create);
+        self
+    }
+
+    pub fn create_new(&mut self, create_new: bool) -> &mut Self {
+        self.0.create_new(create_new);
+        self
+    }
+
+    /// Abre `path` con estas opciones
+    pub async fn open(&self, path: impl AsRef<Path>) -> io::Result<File> {
+        let (options, path) = (self.0.clone(), path.as_ref().to_owned());
+        let file = asyncify(move || options.open(path)).await?;
+        Ok(File::from_std(file))
+    }
+}
+
+impl Default for OpenOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
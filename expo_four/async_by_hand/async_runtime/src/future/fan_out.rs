@@ -0,0 +1,165 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    task::{Context, Waker},
+};
+
+use crate::waker::{ArcWake, create_waker};
+
+/// Reparte el waker del futuro padre entre varios hijos
+///
+/// Cada hijo se sondea con un waker propio que apunta a su índice: al
+/// despertar, el índice entra en la cola de listos y después se despierta
+/// al padre. Así el padre solo vuelve a sondear a los hijos que pidieron
+/// progresar, en lugar de recorrerlos todos en cada sondeo.
+pub(crate) struct FanOut {
+    state: Mutex<State>,
+}
+
+struct State {
+    /// Waker del padre, actualizado en cada sondeo
+    parent: Option<Waker>,
+    /// Índices despertados, en orden de llegada
+    ready: VecDeque<usize>,
+    /// Evita encolar dos veces el mismo índice
+    queued: Vec<bool>,
+}
+
+impl FanOut {
+    pub(crate) fn new() -> Arc<Self> {
+        Arc::new(FanOut {
+            state: Mutex::new(State {
+                parent: None,
+                ready: VecDeque::new(),
+                queued: Vec::new(),
+            }),
+        })
+    }
+
+    /// Guarda el waker del padre si cambió desde el último sondeo
+    pub(crate) fn register(&self, cx: &Context<'_>) {
+        let mut state = self.state.lock().unwrap();
+        match &state.parent {
+            Some(parent) if parent.will_wake(cx.waker()) => {}
+            _ => state.parent = Some(cx.waker().clone()),
+        }
+    }
+
+    /// Marca un hijo como listo sin despertar al padre
+    ///
+    /// Se usa al añadir hijos: el padre los sondeará en su siguiente turno.
+    pub(crate) fn schedule(&self, index: usize) {
+        self.state.lock().unwrap().push(index);
+    }
+
+    /// Extrae el siguiente hijo despertado
+    pub(crate) fn pop(&self) -> Option<usize> {
+        let mut state = self.state.lock().unwrap();
+        let index = state.ready.pop_front()?;
+        state.queued[index] = false;
+        Some(index)
+    }
+
+    /// Indica si quedan hijos despertados sin sondear
+    pub(crate) fn has_ready(&self) -> bool {
+        !self.state.lock().unwrap().ready.is_empty()
+    }
+
+    /// Marca un hijo como listo y despierta al padre
+    fn wake(&self, index: usize) {
+        let parent = {
+            let mut state = self.state.lock().unwrap();
+            if !state.push(index) {
+                return;
+            }
+            state.parent.clone()
+        };
+        if let Some(parent) = parent {
+            parent.wake();
+        }
+    }
+}
+
+impl State {
+    /// Encola `index` si no lo estaba ya
+    fn push(&mut self, index: usize) -> bool {
+        if index >= self.queued.len() {
+            self.queued.resize(index + 1, false);
+        }
+        if self.queued[index] {
+            return false;
+        }
+        self.queued[index] = true;
+        self.ready.push_back(index);
+        true
+    }
+}
+
+/// Waker de un hijo: recuerda su índice dentro del padre
+struct ChildWaker {
+    index: usize,
+    fan_out: Arc<FanOut>,
+}
+
+impl ArcWake for ChildWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.fan_out.wake(arc_self.index);
+    }
+}
+
+/// Crea el waker del hijo `index`
+pub(crate) fn child_waker(fan_out: &Arc<FanOut>, index: usize) -> Waker {
+    create_waker(Arc::new(ChildWaker {
+        index,
+        fan_out: fan_out.clone(),
+    }))
+}
+
+/// Wakers propios de cada rama de `join!` y `try_join!`
+///
+/// Es público solo para esos macros.
+#[doc(hidden)]
+pub struct Branches {
+    fan_out: Arc<FanOut>,
+    wakers: Vec<Waker>,
+}
+
+impl Branches {
+    /// Crea los wakers de `count` ramas, todas listas para su primer sondeo
+    pub fn new(count: usize) -> Self {
+        let fan_out = FanOut::new();
+        let wakers = (0..count)
+            .map(|index| {
+                fan_out.schedule(index);
+                child_waker(&fan_out, index)
+            })
+            .collect();
+        Branches { fan_out, wakers }
+    }
+
+    /// Llama a `poll` solo con las ramas despertadas desde el último turno
+    ///
+    /// `poll` recibe el índice de la rama y un contexto con su waker, y
+    /// puede cortar el recorrido devolviendo `Some`. Como en `join_all`,
+    /// cada turno sondea como mucho tantas ramas como haya.
+    pub fn poll_woken<R>(
+        &self,
+        cx: &mut Context<'_>,
+        mut poll: impl FnMut(usize, &mut Context<'_>) -> Option<R>,
+    ) -> Option<R> {
+        self.fan_out.register(cx);
+
+        for _ in 0..self.wakers.len() {
+            let index = self.fan_out.pop()?;
+            let mut branch_cx = Context::from_waker(&self.wakers[index]);
+            if let Some(result) = poll(index, &mut branch_cx) {
+                return Some(result);
+            }
+        }
+
+        if self.fan_out.has_ready() {
+            cx.waker().wake_by_ref();
+        }
+        None
+    }
+}
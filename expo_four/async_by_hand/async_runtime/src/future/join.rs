@@ -0,0 +1,349 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Waker},
+};
+
+use super::{
+    fan_out::{FanOut, child_waker},
+    maybe_done::MaybeDone,
+};
+
+/// Futuro devuelto por `join`
+pub struct Join<A: Future, B: Future> {
+    a: MaybeDone<A>,
+    b: MaybeDone<B>,
+}
+
+impl<A: Future, B: Future> Future for Join<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Seguro: `a` y `b` nunca se mueven fuera de `self`
+        let this = unsafe { self.get_unchecked_mut() };
+        let (mut a, mut b) = unsafe {
+            (
+                Pin::new_unchecked(&mut this.a),
+                Pin::new_unchecked(&mut this.b),
+            )
+        };
+        // Se sondean ambos aunque el primero siga pendiente
+        let a_done = a.as_mut().poll_done(cx);
+        let b_done = b.as_mut().poll_done(cx);
+        if a_done && b_done {
+            Poll::Ready((a.take_output(), b.take_output()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Espera a dos futuros a la vez y devuelve ambos resultados
+///
+/// Los dos avanzan de forma concurrente dentro de la misma tarea. Para más
+/// de dos futuros de tipos distintos existe el macro `join!`.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::future::join;
+///
+/// let (a, b) = async_runtime::block_on(join(async { 1 }, async { "dos" }));
+/// assert_eq!((a, b), (1, "dos"));
+/// ```
+pub fn join<A: Future, B: Future>(a: A, b: B) -> Join<A, B> {
+    Join {
+        a: MaybeDone::new(a),
+        b: MaybeDone::new(b),
+    }
+}
+
+/// Futuro devuelto por `try_join`
+pub struct TryJoin<A: Future, B: Future> {
+    a: MaybeDone<A>,
+    b: MaybeDone<B>,
+}
+
+impl<T, U, E, A, B> Future for TryJoin<A, B>
+where
+    A: Future<Output = Result<T, E>>,
+    B: Future<Output = Result<U, E>>,
+{
+    type Output = Result<(T, U), E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Seguro: `a` y `b` nunca se mueven fuera de `self`
+        let this = unsafe { self.get_unchecked_mut() };
+        let (mut a, mut b) = unsafe {
+            (
+                Pin::new_unchecked(&mut this.a),
+                Pin::new_unchecked(&mut this.b),
+            )
+        };
+        let a_done = match a.as_mut().poll_try(cx) {
+            Ok(done) => done,
+            Err(error) => return Poll::Ready(Err(error)),
+        };
+        let b_done = match b.as_mut().poll_try(cx) {
+            Ok(done) => done,
+            Err(error) => return Poll::Ready(Err(error)),
+        };
+        if a_done && b_done {
+            Poll::Ready(Ok((a.take_ok(), b.take_ok())))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Espera a dos futuros que devuelven `Result`
+///
+/// Termina con el primer error sin esperar al otro futuro, que se descarta
+/// junto con el combinador.
+pub fn try_join<T, U, E, A, B>(a: A, b: B) -> TryJoin<A, B>
+where
+    A: Future<Output = Result<T, E>>,
+    B: Future<Output = Result<U, E>>,
+{
+    TryJoin {
+        a: MaybeDone::new(a),
+        b: MaybeDone::new(b),
+    }
+}
+
+/// Hijos de `JoinAll` y `TryJoinAll`, cada uno con su propio waker
+///
+/// Los hijos comparten una sola reserva en el heap, que nunca cambia de
+/// tamaño: así quedan fijados sin una caja por hijo.
+struct Children<F: Future> {
+    futures: Pin<Box<[MaybeDone<F>]>>,
+    wakers: Vec<Waker>,
+    fan_out: Arc<FanOut>,
+    pending: usize,
+}
+
+impl<F: Future> Children<F> {
+    fn new(iter: impl IntoIterator<Item = F>) -> Self {
+        let fan_out = FanOut::new();
+        let futures: Pin<Box<[_]>> = iter
+            .into_iter()
+            .map(MaybeDone::new)
+            .collect::<Box<_>>()
+            .into();
+        let wakers = (0..futures.len())
+            .map(|index| {
+                fan_out.schedule(index);
+                child_waker(&fan_out, index)
+            })
+            .collect();
+        Children {
+            pending: futures.len(),
+            futures,
+            wakers,
+            fan_out,
+        }
+    }
+
+    /// Hijo `index`, fijado en su sitio
+    fn child(futures: &mut Pin<Box<[MaybeDone<F>]>>, index: usize) -> Pin<&mut MaybeDone<F>> {
+        // Seguro: el slice nunca se redimensiona ni se mueve fuera de la caja
+        unsafe {
+            futures
+                .as_mut()
+                .map_unchecked_mut(|futures| &mut futures[index])
+        }
+    }
+
+    /// Recoge los resultados de todos los hijos, en orden
+    fn take_all<R>(&mut self, mut take: impl FnMut(Pin<&mut MaybeDone<F>>) -> R) -> Vec<R> {
+        (0..self.futures.len())
+            .map(|index| take(Self::child(&mut self.futures, index)))
+            .collect()
+    }
+
+    /// Sondea solo a los hijos despertados desde el último turno
+    ///
+    /// `check` se llama con cada hijo que termina y puede cortar el
+    /// recorrido devolviendo `Some`. Para no acaparar el hilo, cada turno
+    /// sondea como mucho tantos hijos como haya; si quedan más despertados
+    /// se vuelve a despertar al padre.
+    fn poll_ready<R>(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut check: impl FnMut(Pin<&mut MaybeDone<F>>) -> Option<R>,
+    ) -> Option<R> {
+        self.fan_out.register(cx);
+
+        for _ in 0..self.futures.len() {
+            let index = self.fan_out.pop()?;
+            let mut child = Self::child(&mut self.futures, index);
+            // Un hijo ya terminado puede recibir despertares tardíos
+            if !child.is_pending() {
+                continue;
+            }
+            let mut child_cx = Context::from_waker(&self.wakers[index]);
+            if child.as_mut().poll_done(&mut child_cx) {
+                self.pending -= 1;
+                if let Some(result) = check(child) {
+                    return Some(result);
+                }
+            }
+        }
+
+        if self.pending > 0 {
+            cx.waker().wake_by_ref();
+        }
+        None
+    }
+}
+
+/// Futuro devuelto por `join_all`
+pub struct JoinAll<F: Future> {
+    children: Children<F>,
+}
+
+impl<F: Future> Future for JoinAll<F> {
+    type Output = Vec<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let children = &mut self.children;
+        children.poll_ready(cx, |_| None::<()>);
+        if children.pending > 0 {
+            return Poll::Pending;
+        }
+        Poll::Ready(children.take_all(MaybeDone::take_output))
+    }
+}
+
+/// Espera a todos los futuros de `iter` y devuelve sus resultados en orden
+///
+/// Cada futuro recibe su propio waker: cuando uno despierta solo se vuelve
+/// a sondear ese, no todo el conjunto.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::future::join_all;
+///
+/// let squares = async_runtime::block_on(join_all((1..=3).map(|n| async move { n * n })));
+/// assert_eq!(squares, vec![1, 4, 9]);
+/// ```
+pub fn join_all<I>(iter: I) -> JoinAll<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    JoinAll {
+        children: Children::new(iter),
+    }
+}
+
+/// Futuro devuelto por `try_join_all`
+pub struct TryJoinAll<F: Future> {
+    children: Children<F>,
+}
+
+impl<T, E, F: Future<Output = Result<T, E>>> Future for TryJoinAll<F> {
+    type Output = Result<Vec<T>, E>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let children = &mut self.children;
+        let failed = children.poll_ready(cx, |child| match *child {
+            MaybeDone::Done(Err(_)) => match child.take_output() {
+                Err(error) => Some(error),
+                Ok(_) => unreachable!(),
+            },
+            _ => None,
+        });
+        if let Some(error) = failed {
+            // Los hijos restantes ya no se esperarán
+            children.futures = Box::pin([]);
+            children.pending = 0;
+            return Poll::Ready(Err(error));
+        }
+        if children.pending > 0 {
+            return Poll::Pending;
+        }
+        Poll::Ready(Ok(children.take_all(MaybeDone::take_ok)))
+    }
+}
+
+/// Espera a todos los futuros de `iter` o al primer error
+///
+/// Con el primer `Err` se descartan los futuros que seguían en curso.
+pub fn try_join_all<I, T, E>(iter: I) -> TryJoinAll<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Future<Output = Result<T, E>>,
+{
+    TryJoinAll {
+        children: Children::new(iter),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join_all, try_join_all};
+    use crate::{executor::Executor, sync::oneshot};
+    use std::{
+        future::{Future, poll_fn},
+        pin::Pin,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+    };
+
+    #[test]
+    fn join_all_only_repolls_woken_children() {
+        let mut executor = Executor::new();
+        let polls: Vec<_> = (0..4).map(|_| Arc::new(AtomicUsize::new(0))).collect();
+        let (senders, receivers): (Vec<_>, Vec<_>) = (0..4).map(|_| oneshot::channel()).unzip();
+
+        let children = receivers.into_iter().zip(&polls).map(|(mut rx, polls)| {
+            let polls = polls.clone();
+            poll_fn(move |cx| {
+                polls.fetch_add(1, Ordering::SeqCst);
+                Pin::new(&mut rx).poll(cx).map(Result::unwrap)
+            })
+        });
+        let handle = executor.spawn(join_all(children));
+
+        let polls_of = || {
+            polls
+                .iter()
+                .map(|p| p.load(Ordering::SeqCst))
+                .collect::<Vec<_>>()
+        };
+        while executor.has_ready_tasks() {
+            executor.poll();
+        }
+        assert_eq!(polls_of(), vec![1, 1, 1, 1]);
+
+        // Despertar a un hijo no vuelve a sondear a sus hermanos
+        let mut senders = senders.into_iter();
+        senders.next().unwrap().send(0).unwrap();
+        while executor.has_ready_tasks() {
+            executor.poll();
+        }
+        assert_eq!(polls_of(), vec![2, 1, 1, 1]);
+
+        for (i, tx) in senders.enumerate() {
+            tx.send(i + 1).unwrap();
+        }
+        assert_eq!(executor.block_on(handle).unwrap(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn try_join_all_stops_at_first_error() {
+        let (_tx, rx) = oneshot::channel::<()>();
+        let result = crate::block_on(try_join_all(vec![
+            Box::pin(async move {
+                // Nunca termina: el error del otro hijo no debe esperarlo
+                rx.await.unwrap();
+                Ok(0)
+            }) as Pin<Box<dyn Future<Output = Result<i32, &str>>>>,
+            Box::pin(async { Err("falla") }),
+        ]));
+        assert_eq!(result, Err("falla"));
+    }
+}
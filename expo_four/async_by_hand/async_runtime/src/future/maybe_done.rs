@@ -0,0 +1,95 @@
+use std::{
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Futuro que conserva su resultado hasta que se recoge
+///
+/// Lo usan los combinadores que esperan a varios futuros: cada uno se
+/// sondea hasta terminar y su resultado queda guardado mientras los demás
+/// siguen en curso. Es público solo para los macros `join!` y `try_join!`.
+///
+/// El futuro vive dentro del propio `MaybeDone`, sin caja: quien lo usa lo
+/// fija en su sitio y lo maneja a través de `Pin<&mut MaybeDone<F>>`.
+#[doc(hidden)]
+pub enum MaybeDone<F: Future> {
+    /// Aún en curso; fijado mientras exista
+    Future(F),
+    /// Terminado, con el resultado sin recoger
+    Done(F::Output),
+    /// Resultado ya recogido
+    Gone,
+}
+
+impl<F: Future> MaybeDone<F> {
+    pub fn new(future: F) -> Self {
+        MaybeDone::Future(future)
+    }
+
+    /// Sondea el futuro si sigue en curso
+    ///
+    /// # Retorno
+    /// `true` si ya tiene resultado (o si ya se recogió)
+    pub fn poll_done(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        // Seguro: el futuro se sondea sin moverlo y solo se sustituye por
+        // su resultado una vez terminado, lo que lo suelta en su sitio
+        let this = unsafe { self.get_unchecked_mut() };
+        let MaybeDone::Future(future) = this else {
+            return true;
+        };
+        match unsafe { Pin::new_unchecked(future) }.poll(cx) {
+            Poll::Ready(output) => {
+                *this = MaybeDone::Done(output);
+                true
+            }
+            Poll::Pending => false,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self, MaybeDone::Future(_))
+    }
+
+    /// Recoge el resultado, dejando `Gone` en su lugar
+    ///
+    /// # Panics
+    /// Si el futuro no ha terminado o el resultado ya se recogió
+    pub fn take_output(self: Pin<&mut Self>) -> F::Output {
+        assert!(
+            matches!(*self, MaybeDone::Done(_)),
+            "MaybeDone::take_output sin resultado disponible"
+        );
+        // Seguro: no queda ningún futuro fijado que mover
+        let this = unsafe { self.get_unchecked_mut() };
+        match mem::replace(this, MaybeDone::Gone) {
+            MaybeDone::Done(output) => output,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<T, E, F: Future<Output = Result<T, E>>> MaybeDone<F> {
+    /// Como `poll_done`, pero extrae el error en cuanto aparece
+    pub fn poll_try(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Result<bool, E> {
+        if !self.as_mut().poll_done(cx) {
+            return Ok(false);
+        }
+        if let MaybeDone::Done(Err(_)) = *self {
+            let Err(error) = self.take_output() else {
+                unreachable!()
+            };
+            return Err(error);
+        }
+        Ok(true)
+    }
+
+    /// Recoge el valor de un resultado correcto
+    pub fn take_ok(self: Pin<&mut Self>) -> T {
+        match self.take_output() {
+            Ok(value) => value,
+            Err(_) => panic!("MaybeDone::take_ok sobre un error"),
+        }
+    }
+}
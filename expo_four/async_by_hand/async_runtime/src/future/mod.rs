@@ -0,0 +1,55 @@
+mod fan_out;
+mod join;
+mod maybe_done;
+mod select;
+
+use std::{
+    cell::Cell,
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+#[doc(hidden)]
+pub use fan_out::Branches;
+pub(crate) use fan_out::{FanOut, child_waker};
+pub use join::{Join, JoinAll, TryJoin, TryJoinAll, join, join_all, try_join, try_join_all};
+#[doc(hidden)]
+pub use maybe_done::MaybeDone;
+pub use select::{Either, Select, select, select_biased};
+
+thread_local! {
+    /// Estado del generador xorshift de cada hilo
+    static RNG: Cell<u64> = Cell::new(seed());
+}
+
+/// Semilla aleatoria tomada de las claves de `RandomState`
+pub(crate) fn seed() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u8(0);
+    // xorshift nunca debe partir de cero
+    hasher.finish() | 1
+}
+
+/// Índice pseudoaleatorio en `0..n`, para repartir la prioridad en `select`
+///
+/// Es público solo para el macro `select!`.
+#[doc(hidden)]
+pub fn random_index(n: usize) -> usize {
+    RNG.with(|rng| {
+        let mut x = rng.get();
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        rng.set(x);
+        (x % n as u64) as usize
+    })
+}
+
+/// Sustituye el estado del generador de este hilo y devuelve el anterior
+///
+/// Con el mismo estado, `random_index` da la misma secuencia: así el
+/// runtime de pruebas reproduce el orden de `select!` y de su planificador.
+pub(crate) fn swap_rng(state: u64) -> u64 {
+    // xorshift nunca debe partir de cero
+    RNG.with(|rng| rng.replace(state | 1))
+}
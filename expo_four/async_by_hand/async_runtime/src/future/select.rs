@@ -0,0 +1,133 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::random_index;
+
+/// Resultado de `select`: cuál de los dos futuros terminó primero
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Los dos futuros de un `Select`, fijados en el heap para no exigir `Unpin`
+type Pair<A, B> = (Pin<Box<A>>, Pin<Box<B>>);
+
+/// Futuro devuelto por `select` y `select_biased`
+pub struct Select<A, B> {
+    /// Ambos futuros, hasta que uno termina
+    inner: Option<Pair<A, B>>,
+    biased: bool,
+}
+
+impl<A: Future, B: Future> Future for Select<A, B> {
+    type Output = Either<(A::Output, Pin<Box<B>>), (B::Output, Pin<Box<A>>)>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let left_first = self.biased || random_index(2) == 0;
+        let (a, b) = self
+            .inner
+            .as_mut()
+            .expect("Select sondeado después de terminar");
+
+        let ready = if left_first {
+            poll_left(a, cx).or_else(|| poll_right(b, cx))
+        } else {
+            poll_right(b, cx).or_else(|| poll_left(a, cx))
+        };
+
+        match ready {
+            Some(side) => {
+                let (a, b) = self.inner.take().unwrap();
+                Poll::Ready(match side {
+                    Either::Left(output) => Either::Left((output, b)),
+                    Either::Right(output) => Either::Right((output, a)),
+                })
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+fn poll_left<A: Future, R>(
+    a: &mut Pin<Box<A>>,
+    cx: &mut Context<'_>,
+) -> Option<Either<A::Output, R>> {
+    match a.as_mut().poll(cx) {
+        Poll::Ready(output) => Some(Either::Left(output)),
+        Poll::Pending => None,
+    }
+}
+
+fn poll_right<B: Future, L>(
+    b: &mut Pin<Box<B>>,
+    cx: &mut Context<'_>,
+) -> Option<Either<L, B::Output>> {
+    match b.as_mut().poll(cx) {
+        Poll::Ready(output) => Some(Either::Right(output)),
+        Poll::Pending => None,
+    }
+}
+
+/// Espera al primero de dos futuros en terminar
+///
+/// En cada sondeo el orden se elige al azar, para que un futuro siempre
+/// listo no deje sin turno al otro. El futuro que no terminó se devuelve
+/// junto con el resultado y puede seguir esperándose.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::future::{Either, select};
+/// use async_runtime::time::sleep;
+/// use std::time::Duration;
+///
+/// async_runtime::block_on(async {
+///     match select(sleep(Duration::from_secs(60)), async { 7 }).await {
+///         Either::Right((value, _slow)) => assert_eq!(value, 7),
+///         Either::Left(_) => unreachable!(),
+///     }
+/// });
+/// ```
+pub fn select<A: Future, B: Future>(a: A, b: B) -> Select<A, B> {
+    Select {
+        inner: Some((Box::pin(a), Box::pin(b))),
+        biased: false,
+    }
+}
+
+/// Como `select`, pero siempre sondea primero `a`
+///
+/// Si ambos están listos gana `a`. Útil cuando uno de los futuros tiene
+/// prioridad, por ejemplo una señal de cancelación.
+pub fn select_biased<A: Future, B: Future>(a: A, b: B) -> Select<A, B> {
+    Select {
+        inner: Some((Box::pin(a), Box::pin(b))),
+        biased: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Either, select, select_biased};
+    use std::future::ready;
+
+    #[test]
+    fn biased_prefers_left_and_unbiased_is_fair() {
+        for _ in 0..32 {
+            let winner = crate::block_on(select_biased(ready(1), ready(2)));
+            assert!(matches!(winner, Either::Left((1, _))));
+        }
+
+        // Con ambos listos, cada lado debe ganar alguna vez
+        let lefts = (0..256)
+            .filter(|_| matches!(crate::block_on(select(ready(1), ready(2))), Either::Left(_)))
+            .count();
+        assert!(
+            lefts > 0 && lefts < 256,
+            "ganó la izquierda {lefts} de 256 veces"
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use super::{AsyncBufRead, AsyncRead, AsyncWrite};
+
+/// Capacidad por defecto de `BufReader` y `BufWriter`
+pub(super) const DEFAULT_BUF_SIZE: usize = 8 * 1024;
+
+/// Añade un buffer de lectura a un `AsyncRead`
+///
+/// Agrupa muchas lecturas pequeñas en pocas llamadas al sistema y permite
+/// leer por líneas o hasta un delimitador (ver `AsyncBufReadExt`). Las
+/// escrituras pasan directamente al objeto interno.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::io::{AsyncBufReadExt, BufReader};
+///
+/// async_runtime::block_on(async {
+///     let mut reader = BufReader::new(&b"GET / HTTP/1.1\r\nHost: x\r\n"[..]);
+///     let mut line = String::new();
+///     reader.read_line(&mut line).await.unwrap();
+///     assert_eq!(line, "GET / HTTP/1.1\r\n");
+/// });
+/// ```
+pub struct BufReader<R> {
+    inner: R,
+    buf: Box<[u8]>,
+    /// Siguiente byte sin entregar
+    pos: usize,
+    /// Fin de los bytes válidos en `buf`
+    filled: usize,
+}
+
+impl<R: AsyncRead> BufReader<R> {
+    pub fn new(inner: R) -> Self {
+        BufReader::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    pub fn with_capacity(capacity: usize, inner: R) -> Self {
+        BufReader {
+            inner,
+            buf: vec![0; capacity].into_boxed_slice(),
+            pos: 0,
+            filled: 0,
+        }
+    }
+}
+
+impl<R> BufReader<R> {
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Acceso al objeto interno; leer de él directamente se salta el buffer
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Devuelve el objeto interno; lo que quedaba en el buffer se pierde
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Bytes ya leídos del objeto interno y aún no entregados
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..self.filled]
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for BufReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        // Con el buffer vacío y una lectura grande, copiar no aporta nada
+        if self.pos == self.filled && buf.len() >= self.buf.len() {
+            return Pin::new(&mut self.inner).poll_read(cx, buf);
+        }
+
+        let available = ready!(self.as_mut().poll_fill_buf(cx))?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Poll::Ready(Ok(len))
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncBufRead for BufReader<R> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        if this.pos == this.filled {
+            let len = ready!(Pin::new(&mut this.inner).poll_read(cx, &mut this.buf))?;
+            this.pos = 0;
+            this.filled = len;
+        }
+        Poll::Ready(Ok(&this.buf[this.pos..this.filled]))
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amount: usize) {
+        self.pos = (self.pos + amount).min(self.filled);
+    }
+}
+
+impl<R: AsyncWrite + Unpin> AsyncWrite for BufReader<R> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use super::{AsyncRead, AsyncWrite, buf_reader::DEFAULT_BUF_SIZE};
+
+/// Añade un buffer de escritura a un `AsyncWrite`
+///
+/// Acumula las escrituras pequeñas y las entrega juntas cuando el buffer
+/// se llena, en `flush` o en `shutdown`. Lo que quede en el buffer al
+/// soltarlo se pierde: hay que llamar a `flush` antes. Las lecturas pasan
+/// directamente al objeto interno.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::io::{AsyncWriteExt, BufWriter};
+///
+/// async_runtime::block_on(async {
+///     let mut writer = BufWriter::new(Vec::new());
+///     writer.write_all(b"hola ").await.unwrap();
+///     writer.write_all(b"mundo").await.unwrap();
+///     assert!(writer.get_ref().is_empty());
+///
+///     writer.flush().await.unwrap();
+///     assert_eq!(writer.get_ref(), b"hola mundo");
+/// });
+/// ```
+pub struct BufWriter<W> {
+    inner: W,
+    buf: Vec<u8>,
+    /// Bytes de `buf` ya entregados en un vaciado que quedó a medias
+    written: usize,
+}
+
+impl<W: AsyncWrite> BufWriter<W> {
+    pub fn new(inner: W) -> Self {
+        BufWriter::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    pub fn with_capacity(capacity: usize, inner: W) -> Self {
+        BufWriter {
+            inner,
+            buf: Vec::with_capacity(capacity),
+            written: 0,
+        }
+    }
+}
+
+impl<W> BufWriter<W> {
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Acceso al objeto interno; escribir en él directamente se salta el buffer
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Devuelve el objeto interno; lo que quedaba en el buffer se pierde
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Bytes aceptados y aún no entregados al objeto interno
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.written..]
+    }
+}
+
+impl<W: AsyncWrite + Unpin> BufWriter<W> {
+    /// Entrega el contenido del buffer al objeto interno
+    fn poll_flush_buf(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.written < self.buf.len() {
+            let pending = &self.buf[self.written..];
+            match ready!(Pin::new(&mut self.inner).poll_write(cx, pending)) {
+                Ok(0) => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                Ok(len) => self.written += len,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+        self.buf.clear();
+        self.written = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for BufWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if this.buf.len() + buf.len() > this.buf.capacity() {
+            ready!(this.poll_flush_buf(cx))?;
+        }
+
+        // Lo que no cabe en el buffer vacío se escribe directamente
+        if buf.len() >= this.buf.capacity() {
+            Pin::new(&mut this.inner).poll_write(cx, buf)
+        } else {
+            this.buf.extend_from_slice(buf);
+            Poll::Ready(Ok(buf.len()))
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_flush_buf(cx))?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_flush_buf(cx))?;
+        Pin::new(&mut this.inner).poll_shutdown(cx)
+    }
+}
+
+impl<W: AsyncRead + Unpin> AsyncRead for BufWriter<W> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
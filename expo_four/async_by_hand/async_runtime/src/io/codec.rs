@@ -0,0 +1,137 @@
+use std::io;
+
+/// Extrae mensajes (frames) de un flujo de bytes
+///
+/// Lo usa `Framed` para convertir lo que llega de un `AsyncRead` en una
+/// secuencia de mensajes.
+pub trait Decoder {
+    type Item;
+    type Error: From<io::Error>;
+
+    /// Intenta decodificar un frame del principio de `src`
+    ///
+    /// # Retorno
+    /// - `Ok(Some((item, len)))`: un frame que ocupaba los primeros `len` bytes
+    /// - `Ok(None)`: faltan bytes; se volverá a llamar cuando lleguen más
+    fn decode(&mut self, src: &[u8]) -> Result<Option<(Self::Item, usize)>, Self::Error>;
+
+    /// Como `decode`, pero cuando ya no llegarán más bytes
+    ///
+    /// Por defecto, los bytes que no forman un frame completo son un error
+    /// `UnexpectedEof`.
+    fn decode_eof(&mut self, src: &[u8]) -> Result<Option<(Self::Item, usize)>, Self::Error> {
+        match self.decode(src)? {
+            Some(frame) => Ok(Some(frame)),
+            None if src.is_empty() => Ok(None),
+            None => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "el flujo terminó a mitad de un frame",
+            )
+            .into()),
+        }
+    }
+}
+
+/// Convierte mensajes en bytes; la contraparte de `Decoder`
+pub trait Encoder<Item> {
+    type Error: From<io::Error>;
+
+    /// Añade la representación de `item` al final de `dst`
+    fn encode(&mut self, item: Item, dst: &mut Vec<u8>) -> Result<(), Self::Error>;
+}
+
+/// Longitud máxima por defecto de un frame de `LengthDelimitedCodec`
+const DEFAULT_MAX_FRAME_LENGTH: usize = 8 * 1024 * 1024;
+
+/// Frames de bytes precedidos por su longitud (`u32` big-endian)
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::io::{Decoder, Encoder, LengthDelimitedCodec};
+///
+/// let mut codec = LengthDelimitedCodec::new();
+/// let mut bytes = Vec::new();
+/// codec.encode(&b"hola"[..], &mut bytes).unwrap();
+/// assert_eq!(bytes, [0, 0, 0, 4, b'h', b'o', b'l', b'a']);
+///
+/// let (frame, len) = codec.decode(&bytes).unwrap().unwrap();
+/// assert_eq!((frame.as_slice(), len), (&b"hola"[..], 8));
+/// assert!(codec.decode(&bytes[..6]).unwrap().is_none());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LengthDelimitedCodec {
+    max_frame_length: usize,
+}
+
+impl LengthDelimitedCodec {
+    pub fn new() -> Self {
+        LengthDelimitedCodec {
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+        }
+    }
+
+    /// Longitud máxima de un frame; uno mayor es un error `InvalidData`
+    ///
+    /// Evita reservar memoria para una longitud arbitraria recibida de la red.
+    pub fn with_max_frame_length(max_frame_length: usize) -> Self {
+        LengthDelimitedCodec { max_frame_length }
+    }
+
+    fn check_length(&self, len: usize) -> io::Result<()> {
+        if len > self.max_frame_length {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "frame de {} bytes, el máximo es {}",
+                    len, self.max_frame_length
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for LengthDelimitedCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder for LengthDelimitedCodec {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &[u8]) -> io::Result<Option<(Vec<u8>, usize)>> {
+        let Some(header) = src.first_chunk::<4>() else {
+            return Ok(None);
+        };
+        let len = u32::from_be_bytes(*header) as usize;
+        self.check_length(len)?;
+
+        match src[4..].get(..len) {
+            Some(frame) => Ok(Some((frame.to_vec(), 4 + len))),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Encoder<&[u8]> for LengthDelimitedCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: &[u8], dst: &mut Vec<u8>) -> io::Result<()> {
+        self.check_length(item.len())?;
+        let len = u32::try_from(item.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "frame demasiado largo"))?;
+        dst.extend_from_slice(&len.to_be_bytes());
+        dst.extend_from_slice(item);
+        Ok(())
+    }
+}
+
+impl Encoder<Vec<u8>> for LengthDelimitedCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Vec<u8>, dst: &mut Vec<u8>) -> io::Result<()> {
+        self.encode(item.as_slice(), dst)
+    }
+}
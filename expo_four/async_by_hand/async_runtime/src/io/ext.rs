@@ -0,0 +1,384 @@
+use std::{
+    future::Future,
+    io, mem,
+    pin::Pin,
+    str,
+    task::{Context, Poll, ready},
+};
+
+use super::{AsyncBufRead, AsyncRead, AsyncWrite, poll_read_to_vec};
+
+/// Métodos de conveniencia para cualquier `AsyncRead`
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::io::AsyncReadExt;
+///
+/// async_runtime::block_on(async {
+///     let mut source: &[u8] = b"hola mundo";
+///     let mut first = [0; 4];
+///     source.read_exact(&mut first).await.unwrap();
+///     let mut rest = Vec::new();
+///     source.read_to_end(&mut rest).await.unwrap();
+///     assert_eq!((&first, rest.as_slice()), (b"hola", &b" mundo"[..]));
+/// });
+/// ```
+pub trait AsyncReadExt: AsyncRead {
+    /// Lee los bytes disponibles en `buf`; `0` indica fin de los datos
+    fn read<'a>(&'a mut self, buf: &'a mut [u8]) -> Read<'a, Self>
+    where
+        Self: Unpin,
+    {
+        Read { reader: self, buf }
+    }
+
+    /// Lee exactamente `buf.len()` bytes
+    ///
+    /// Falla con `UnexpectedEof` si los datos terminan antes.
+    fn read_exact<'a>(&'a mut self, buf: &'a mut [u8]) -> ReadExact<'a, Self>
+    where
+        Self: Unpin,
+    {
+        ReadExact {
+            reader: self,
+            buf,
+            filled: 0,
+        }
+    }
+
+    /// Lee hasta el final, añadiendo a `buf`, y devuelve cuántos bytes leyó
+    fn read_to_end<'a>(&'a mut self, buf: &'a mut Vec<u8>) -> ReadToEnd<'a, Self>
+    where
+        Self: Unpin,
+    {
+        let start = buf.len();
+        ReadToEnd {
+            reader: self,
+            buf,
+            start,
+        }
+    }
+}
+
+impl<R: AsyncRead + ?Sized> AsyncReadExt for R {}
+
+/// Métodos de conveniencia para cualquier `AsyncWrite`
+pub trait AsyncWriteExt: AsyncWrite {
+    /// Escribe parte de `buf` y devuelve cuántos bytes aceptó
+    fn write<'a>(&'a mut self, buf: &'a [u8]) -> Write<'a, Self>
+    where
+        Self: Unpin,
+    {
+        Write { writer: self, buf }
+    }
+
+    /// Escribe `buf` completo, reanudando tras cada escritura parcial
+    fn write_all<'a>(&'a mut self, buf: &'a [u8]) -> WriteAll<'a, Self>
+    where
+        Self: Unpin,
+    {
+        WriteAll { writer: self, buf }
+    }
+
+    /// Entrega lo que haya quedado en buffers intermedios
+    fn flush(&mut self) -> Flush<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Flush { writer: self }
+    }
+
+    /// Vacía los buffers y cierra el sentido de escritura
+    fn shutdown(&mut self) -> Shutdown<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Shutdown { writer: self }
+    }
+}
+
+impl<W: AsyncWrite + ?Sized> AsyncWriteExt for W {}
+
+/// Métodos de conveniencia para cualquier `AsyncBufRead`
+pub trait AsyncBufReadExt: AsyncBufRead {
+    /// Lee hasta `delimiter` incluido, o hasta el final, añadiendo a `buf`
+    fn read_until<'a>(&'a mut self, delimiter: u8, buf: &'a mut Vec<u8>) -> ReadUntil<'a, Self>
+    where
+        Self: Unpin,
+    {
+        ReadUntil {
+            reader: self,
+            delimiter,
+            buf,
+            read: 0,
+        }
+    }
+
+    /// Lee una línea, con su `\n` si lo tiene, y la añade a `buf`
+    ///
+    /// Falla con `InvalidData` si la línea no es UTF-8; en ese caso `buf`
+    /// no se modifica.
+    fn read_line<'a>(&'a mut self, buf: &'a mut String) -> ReadLine<'a, Self>
+    where
+        Self: Unpin,
+    {
+        ReadLine {
+            reader: self,
+            output: buf,
+            bytes: Vec::new(),
+            read: 0,
+        }
+    }
+}
+
+impl<R: AsyncBufRead + ?Sized> AsyncBufReadExt for R {}
+
+/// Futuro devuelto por `AsyncReadExt::read`
+pub struct Read<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut [u8],
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> Future for Read<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        Pin::new(&mut *this.reader).poll_read(cx, this.buf)
+    }
+}
+
+/// Futuro devuelto por `AsyncReadExt::read_exact`
+pub struct ReadExact<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut [u8],
+    filled: usize,
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> Future for ReadExact<'_, R> {
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        while this.filled < this.buf.len() {
+            let rest = &mut this.buf[this.filled..];
+            match ready!(Pin::new(&mut *this.reader).poll_read(cx, rest)) {
+                Ok(0) => return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into())),
+                Ok(len) => this.filled += len,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Futuro devuelto por `AsyncReadExt::read_to_end`
+pub struct ReadToEnd<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut Vec<u8>,
+    /// Longitud de `buf` antes de empezar
+    start: usize,
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> Future for ReadToEnd<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        loop {
+            match ready!(poll_read_to_vec(Pin::new(&mut *this.reader), cx, this.buf)) {
+                Ok(0) => return Poll::Ready(Ok(this.buf.len() - this.start)),
+                Ok(_) => {}
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+    }
+}
+
+/// Futuro devuelto por `AsyncWriteExt::write`
+pub struct Write<'a, W: ?Sized> {
+    writer: &'a mut W,
+    buf: &'a [u8],
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> Future for Write<'_, W> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        Pin::new(&mut *this.writer).poll_write(cx, this.buf)
+    }
+}
+
+/// Futuro devuelto por `AsyncWriteExt::write_all`
+pub struct WriteAll<'a, W: ?Sized> {
+    writer: &'a mut W,
+    /// Lo que falta por escribir
+    buf: &'a [u8],
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> Future for WriteAll<'_, W> {
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        while !this.buf.is_empty() {
+            match ready!(Pin::new(&mut *this.writer).poll_write(cx, this.buf)) {
+                Ok(0) => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                Ok(len) => this.buf = &this.buf[len..],
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Futuro devuelto por `AsyncWriteExt::flush`
+pub struct Flush<'a, W: ?Sized> {
+    writer: &'a mut W,
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> Future for Flush<'_, W> {
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.writer).poll_flush(cx)
+    }
+}
+
+/// Futuro devuelto por `AsyncWriteExt::shutdown`
+pub struct Shutdown<'a, W: ?Sized> {
+    writer: &'a mut W,
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> Future for Shutdown<'_, W> {
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.writer).poll_shutdown(cx)
+    }
+}
+
+/// Futuro devuelto por `AsyncBufReadExt::read_until`
+pub struct ReadUntil<'a, R: ?Sized> {
+    reader: &'a mut R,
+    delimiter: u8,
+    buf: &'a mut Vec<u8>,
+    /// Bytes añadidos a `buf` hasta ahora
+    read: usize,
+}
+
+impl<R: AsyncBufRead + Unpin + ?Sized> Future for ReadUntil<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        poll_read_until(
+            Pin::new(&mut *this.reader),
+            cx,
+            this.delimiter,
+            this.buf,
+            &mut this.read,
+        )
+    }
+}
+
+fn poll_read_until<R: AsyncBufRead + ?Sized>(
+    mut reader: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    delimiter: u8,
+    buf: &mut Vec<u8>,
+    read: &mut usize,
+) -> Poll<io::Result<usize>> {
+    loop {
+        let (done, used) = {
+            let available = ready!(reader.as_mut().poll_fill_buf(cx))?;
+            match available.iter().position(|&byte| byte == delimiter) {
+                Some(index) => {
+                    buf.extend_from_slice(&available[..=index]);
+                    (true, index + 1)
+                }
+                None => {
+                    buf.extend_from_slice(available);
+                    (available.is_empty(), available.len())
+                }
+            }
+        };
+        reader.as_mut().consume(used);
+        *read += used;
+        if done {
+            return Poll::Ready(Ok(mem::take(read)));
+        }
+    }
+}
+
+/// Futuro devuelto por `AsyncBufReadExt::read_line`
+pub struct ReadLine<'a, R: ?Sized> {
+    reader: &'a mut R,
+    output: &'a mut String,
+    /// Bytes de la línea, hasta validarlos como UTF-8 al completarla
+    bytes: Vec<u8>,
+    read: usize,
+}
+
+impl<R: AsyncBufRead + Unpin + ?Sized> Future for ReadLine<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let read = ready!(poll_read_until(
+            Pin::new(&mut *this.reader),
+            cx,
+            b'\n',
+            &mut this.bytes,
+            &mut this.read,
+        ))?;
+        match str::from_utf8(&this.bytes) {
+            Ok(line) => {
+                this.output.push_str(line);
+                this.bytes.clear();
+                Poll::Ready(Ok(read))
+            }
+            Err(_) => Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "la línea no es UTF-8 válido",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt};
+    use crate::{block_on, io::BufReader};
+    use std::io;
+
+    #[test]
+    fn read_exact_reports_eof_and_lines_keep_their_newline() {
+        block_on(async {
+            let mut short: &[u8] = b"abc";
+            let mut buf = [0; 4];
+            let error = short.read_exact(&mut buf).await.unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+            let mut reader = BufReader::with_capacity(4, &b"uno\ndos\ntres"[..]);
+            let mut lines = Vec::new();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).await.unwrap() == 0 {
+                    break;
+                }
+                lines.push(line);
+            }
+            assert_eq!(lines, ["uno\n", "dos\n", "tres"]);
+
+            let mut sink = Vec::new();
+            sink.write_all(b"hola").await.unwrap();
+            sink.flush().await.unwrap();
+            assert_eq!(sink, b"hola");
+        });
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    future::poll_fn,
+    io,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use super::{AsyncRead, AsyncWrite, Decoder, Encoder, poll_read_to_vec};
+use crate::stream::Stream;
+
+/// Une un flujo de bytes con un codec para leer y escribir mensajes
+///
+/// Como `Stream` produce los frames que decodifica `C`; `send` codifica un
+/// mensaje y lo envía. Los bytes que sobran tras un frame se guardan para
+/// el siguiente, así que nunca hace falta un bucle de lectura a mano.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::{
+///     io::{Framed, LengthDelimitedCodec},
+///     stream::StreamExt,
+/// };
+///
+/// async_runtime::block_on(async {
+///     // Escribe dos frames en memoria y los vuelve a leer
+///     let mut writer = Framed::new(Vec::new(), LengthDelimitedCodec::new());
+///     writer.send(&b"uno"[..]).await.unwrap();
+///     writer.send(&b"dos"[..]).await.unwrap();
+///     let bytes = writer.into_inner();
+///
+///     let mut reader = Framed::new(bytes.as_slice(), LengthDelimitedCodec::new());
+///     assert_eq!(reader.next().await.unwrap().unwrap(), b"uno");
+///     assert_eq!(reader.next().await.unwrap().unwrap(), b"dos");
+///     assert!(reader.next().await.is_none());
+/// });
+/// ```
+pub struct Framed<T, C> {
+    io: T,
+    codec: C,
+    read_buf: Vec<u8>,
+    /// Inicio de los bytes de `read_buf` aún sin decodificar
+    read_pos: usize,
+    /// El objeto de E/S devolvió fin de datos
+    eof: bool,
+    write_buf: Vec<u8>,
+    /// Bytes de `write_buf` ya entregados en un envío que quedó a medias
+    written: usize,
+}
+
+impl<T, C> Framed<T, C> {
+    pub fn new(io: T, codec: C) -> Self {
+        Framed {
+            io,
+            codec,
+            read_buf: Vec::new(),
+            read_pos: 0,
+            eof: false,
+            write_buf: Vec::new(),
+            written: 0,
+        }
+    }
+
+    pub fn get_ref(&self) -> &T {
+        &self.io
+    }
+
+    /// Acceso al objeto interno; leer o escribir directamente se salta los buffers
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.io
+    }
+
+    pub fn codec(&self) -> &C {
+        &self.codec
+    }
+
+    pub fn codec_mut(&mut self) -> &mut C {
+        &mut self.codec
+    }
+
+    /// Bytes recibidos que aún no forman un frame
+    pub fn read_buffer(&self) -> &[u8] {
+        &self.read_buf[self.read_pos..]
+    }
+
+    /// Devuelve el objeto interno; lo que quedaba en los buffers se pierde
+    pub fn into_inner(self) -> T {
+        self.io
+    }
+}
+
+impl<T: AsyncWrite + Unpin, C> Framed<T, C> {
+    /// Codifica `item` en el buffer de escritura, sin enviarlo todavía
+    ///
+    /// Permite agrupar varios mensajes en un solo `flush`.
+    pub fn feed<I>(&mut self, item: I) -> Result<(), C::Error>
+    where
+        C: Encoder<I>,
+    {
+        self.codec.encode(item, &mut self.write_buf)
+    }
+
+    /// Codifica `item` y lo envía junto con lo que hubiera pendiente
+    pub async fn send<I>(&mut self, item: I) -> Result<(), C::Error>
+    where
+        C: Encoder<I>,
+    {
+        self.feed(item)?;
+        self.flush().await?;
+        Ok(())
+    }
+
+    /// Envía todo el buffer de escritura
+    pub async fn flush(&mut self) -> io::Result<()> {
+        poll_fn(|cx| self.poll_flush(cx)).await
+    }
+
+    /// Versión por sondeo de `flush`
+    pub fn poll_flush(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.written < self.write_buf.len() {
+            let pending = &self.write_buf[self.written..];
+            match ready!(Pin::new(&mut self.io).poll_write(cx, pending)) {
+                Ok(0) => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                Ok(len) => self.written += len,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+        self.write_buf.clear();
+        self.written = 0;
+        Pin::new(&mut self.io).poll_flush(cx)
+    }
+}
+
+impl<T: AsyncRead + Unpin, C: Decoder + Unpin> Stream for Framed<T, C> {
+    type Item = Result<C::Item, C::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            let pending = &this.read_buf[this.read_pos..];
+            let decoded = if this.eof {
+                this.codec.decode_eof(pending)
+            } else {
+                this.codec.decode(pending)
+            };
+            match decoded {
+                Ok(Some((item, len))) => {
+                    this.read_pos += len;
+                    return Poll::Ready(Some(Ok(item)));
+                }
+                Ok(None) if this.eof => return Poll::Ready(None),
+                Ok(None) => {}
+                Err(e) => {
+                    // Tras un error de formato no se puede resincronizar
+                    this.read_buf.clear();
+                    this.read_pos = 0;
+                    this.eof = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+
+            // Descarta lo ya decodificado antes de leer más
+            this.read_buf.drain(..this.read_pos);
+            this.read_pos = 0;
+            match ready!(poll_read_to_vec(
+                Pin::new(&mut this.io),
+                cx,
+                &mut this.read_buf
+            )) {
+                Ok(0) => this.eof = true,
+                Ok(_) => {}
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Poll::Ready(Some(Err(e.into()))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Framed;
+    use crate::{
+        io::{AsyncRead, LengthDelimitedCodec},
+        stream::StreamExt,
+    };
+    use std::{
+        io,
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    /// Entrega los bytes de uno en uno, con un `Pending` entre cada uno
+    struct Trickle {
+        data: Vec<u8>,
+        pos: usize,
+        ready: bool,
+    }
+
+    impl AsyncRead for Trickle {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            if !std::mem::replace(&mut self.ready, false) {
+                self.ready = true;
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            match self.data.get(self.pos) {
+                Some(&byte) => {
+                    buf[0] = byte;
+                    self.pos += 1;
+                    Poll::Ready(Ok(1))
+                }
+                None => Poll::Ready(Ok(0)),
+            }
+        }
+    }
+
+    #[test]
+    fn frames_survive_partial_reads_and_truncation_is_an_error() {
+        // Dos frames completos y uno cortado a la mitad
+        let data = vec![0, 0, 0, 2, b'h', b'i', 0, 0, 0, 0, 0, 0, 0, 5, b'x'];
+        let io = Trickle {
+            data,
+            pos: 0,
+            ready: false,
+        };
+
+        let frames = crate::block_on(async {
+            let mut framed = Framed::new(io, LengthDelimitedCodec::new());
+            let mut frames = Vec::new();
+            while let Some(frame) = framed.next().await {
+                frames.push(frame.map_err(|e| e.kind()));
+            }
+            frames
+        });
+
+        assert_eq!(
+            frames,
+            [
+                Ok(b"hi".to_vec()),
+                Ok(Vec::new()),
+                Err(io::ErrorKind::UnexpectedEof)
+            ]
+        );
+    }
+}
@@ -0,0 +1,196 @@
+mod buf_reader;
+mod buf_writer;
+mod codec;
+mod ext;
+mod framed;
+
+use std::{
+    io,
+    ops::DerefMut,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pub use buf_reader::BufReader;
+pub use buf_writer::BufWriter;
+pub use codec::{Decoder, Encoder, LengthDelimitedCodec};
+pub use ext::{
+    AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, Flush, Read, ReadExact, ReadLine, ReadToEnd,
+    ReadUntil, Shutdown, Write, WriteAll,
+};
+pub use framed::Framed;
+
+/// Fuente de bytes asíncrona
+///
+/// Equivalente por sondeo de `std::io::Read`: si no hay datos disponibles
+/// devuelve `Poll::Pending` y se encarga de que la tarea se despierte
+/// cuando los haya. Los métodos cómodos (`read_exact`, `read_to_end`, ...)
+/// están en `AsyncReadExt`.
+pub trait AsyncRead {
+    /// Lee en `buf` los bytes disponibles
+    ///
+    /// `Ok(0)` con `buf` no vacío indica fin de los datos.
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>>;
+}
+
+/// Destino de bytes asíncrono (ver `AsyncRead` y `AsyncWriteExt`)
+pub trait AsyncWrite {
+    /// Escribe parte de `buf` y devuelve cuántos bytes aceptó
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>>;
+
+    /// Entrega lo que haya quedado en buffers intermedios
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+
+    /// Vacía los buffers y cierra el sentido de escritura
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+}
+
+/// Fuente de bytes con buffer interno, como `BufReader`
+pub trait AsyncBufRead: AsyncRead {
+    /// Devuelve los bytes en el buffer, llenándolo antes si está vacío
+    ///
+    /// Un resultado vacío indica fin de los datos.
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>>;
+
+    /// Marca como leídos los primeros `amount` bytes del buffer
+    fn consume(self: Pin<&mut Self>, amount: usize);
+}
+
+impl<T: AsyncRead + Unpin + ?Sized> AsyncRead for &mut T {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut **self).poll_read(cx, buf)
+    }
+}
+
+impl<T: AsyncRead + Unpin + ?Sized> AsyncRead for Box<T> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(self.deref_mut().as_mut()).poll_read(cx, buf)
+    }
+}
+
+/// Un slice se lee de inmediato, avanzando por su contenido
+impl AsyncRead for &[u8] {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Poll::Ready(io::Read::read(&mut *self, buf))
+    }
+}
+
+impl<T: AsyncWrite + Unpin + ?Sized> AsyncWrite for &mut T {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut **self).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut **self).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut **self).poll_shutdown(cx)
+    }
+}
+
+impl<T: AsyncWrite + Unpin + ?Sized> AsyncWrite for Box<T> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(self.deref_mut().as_mut()).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(self.deref_mut().as_mut()).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(self.deref_mut().as_mut()).poll_shutdown(cx)
+    }
+}
+
+/// Un `Vec` acepta siempre todos los bytes, como en std
+impl AsyncWrite for Vec<u8> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T: AsyncBufRead + Unpin + ?Sized> AsyncBufRead for &mut T {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Pin::new(&mut **self.get_mut()).poll_fill_buf(cx)
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amount: usize) {
+        Pin::new(&mut **self).consume(amount)
+    }
+}
+
+impl AsyncBufRead for &[u8] {
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Poll::Ready(Ok(*self.get_mut()))
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amount: usize) {
+        *self = &self[amount..];
+    }
+}
+
+/// Lee de `reader` al final de `buf`, usando su capacidad libre
+///
+/// Lo comparten `read_to_end` y `Framed`: ninguno necesita un buffer
+/// intermedio de tamaño fijo.
+pub(crate) fn poll_read_to_vec<R: AsyncRead + ?Sized>(
+    reader: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    buf: &mut Vec<u8>,
+) -> Poll<io::Result<usize>> {
+    // Duplica la capacidad cuando se agota, con un mínimo razonable
+    if buf.capacity() - buf.len() < 32 {
+        buf.reserve(buf.len().max(1024));
+    }
+    let filled = buf.len();
+    buf.resize(buf.capacity(), 0);
+    let result = reader.poll_read(cx, &mut buf[filled..]);
+    let read = match &result {
+        Poll::Ready(Ok(len)) => *len,
+        _ => 0,
+    };
+    buf.truncate(filled + read);
+    result
+}
@@ -1,5 +1,31 @@
+// Los macros generan rutas `::async_runtime::...`, también dentro del crate
+extern crate self as async_runtime;
+
+mod blocking;
+mod context;
+mod driver;
 pub mod executor;
+pub mod fs;
+pub mod future;
+pub mod io;
+mod macros;
+pub mod metrics;
+pub mod net;
+pub mod process;
+pub mod reactor;
 pub mod reciever;
+pub mod runtime;
 pub mod sender;
+pub mod signal;
 pub mod sleep;
+pub mod stream;
+pub mod sync;
+pub mod task;
+pub mod test;
+pub mod time;
 pub mod waker;
+
+pub use async_runtime_macros::test;
+pub use executor::block_on;
+pub use runtime::{Builder, Runtime};
+pub use task::spawn_blocking;
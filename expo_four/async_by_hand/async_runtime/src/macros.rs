@@ -0,0 +1,294 @@
+/// Espera a varios futuros a la vez y devuelve una tupla con sus resultados
+///
+/// Debe usarse dentro de un contexto `async`. Todos los futuros avanzan de
+/// forma concurrente dentro de la tarea actual; para una colección de
+/// futuros del mismo tipo es preferible `future::join_all`.
+///
+/// # Ejemplo
+/// ```
+/// async_runtime::block_on(async {
+///     let (a, b, c) = async_runtime::join!(async { 1 }, async { "dos" }, async { 3.0 });
+///     assert_eq!((a, b, c), (1, "dos", 3.0));
+/// });
+/// ```
+#[macro_export]
+macro_rules! join {
+    // Cada paso declara su propio `future`, fijado en la pila: la higiene
+    // de macros evita que los nombres choquen, y la lista acumulada los
+    // recuerda todos junto con su índice
+    (@bind $index:expr; [$(($i:expr, $bound:ident))*] $head:expr, $($rest:expr,)*) => {{
+        let mut future = ::std::pin::pin!($crate::future::MaybeDone::new($head));
+        $crate::join!(@bind $index + 1; [$(($i, $bound))* ($index, future)] $($rest,)*)
+    }};
+    (@bind $count:expr; [$(($i:expr, $bound:ident))*]) => {{
+        // Cada rama tiene su propio waker: solo se sondean las despertadas
+        let branches = $crate::future::Branches::new($count);
+        ::std::future::poll_fn(|cx| {
+            branches.poll_woken(cx, |index, cx| {
+                $( if index == $i { $bound.as_mut().poll_done(cx); } )*
+                ::std::option::Option::None::<()>
+            });
+            if true $( && !$bound.is_pending() )* {
+                ::std::task::Poll::Ready(($( $bound.as_mut().take_output(), )*))
+            } else {
+                ::std::task::Poll::Pending
+            }
+        })
+        .await
+    }};
+    ($($future:expr),+ $(,)?) => {
+        $crate::join!(@bind 0usize; [] $($future,)+)
+    };
+}
+
+/// Como `join!`, para futuros que devuelven `Result`
+///
+/// Termina con el primer error; los futuros que seguían en curso se
+/// descartan.
+///
+/// # Ejemplo
+/// ```
+/// async_runtime::block_on(async {
+///     let ok: Result<_, &str> = async_runtime::try_join!(async { Ok(1) }, async { Ok(2) });
+///     assert_eq!(ok, Ok((1, 2)));
+///
+///     let err: Result<(i32, i32), _> = async_runtime::try_join!(async { Ok(1) }, async { Err("falla") });
+///     assert_eq!(err, Err("falla"));
+/// });
+/// ```
+#[macro_export]
+macro_rules! try_join {
+    (@bind $index:expr; [$(($i:expr, $bound:ident))*] $head:expr, $($rest:expr,)*) => {{
+        let mut future = ::std::pin::pin!($crate::future::MaybeDone::new($head));
+        $crate::try_join!(@bind $index + 1; [$(($i, $bound))* ($index, future)] $($rest,)*)
+    }};
+    (@bind $count:expr; [$(($i:expr, $bound:ident))*]) => {{
+        let branches = $crate::future::Branches::new($count);
+        ::std::future::poll_fn(|cx| {
+            let failed = branches.poll_woken(cx, |index, cx| {
+                $(
+                    if index == $i {
+                        if let ::std::result::Result::Err(error) = $bound.as_mut().poll_try(cx) {
+                            return ::std::option::Option::Some(error);
+                        }
+                    }
+                )*
+                ::std::option::Option::None
+            });
+            if let ::std::option::Option::Some(error) = failed {
+                return ::std::task::Poll::Ready(::std::result::Result::Err(error));
+            }
+            if true $( && !$bound.is_pending() )* {
+                ::std::task::Poll::Ready(::std::result::Result::Ok(($( $bound.as_mut().take_ok(), )*)))
+            } else {
+                ::std::task::Poll::Pending
+            }
+        })
+        .await
+    }};
+    ($($future:expr),+ $(,)?) => {
+        $crate::try_join!(@bind 0usize; [] $($future,)+)
+    };
+}
+
+/// Espera al primero de varios futuros y ejecuta la rama correspondiente
+///
+/// Cada rama tiene la forma `patrón = futuro => expresión`. Cuando un futuro
+/// termina, su resultado se asigna al patrón (que debe ser irrefutable), se
+/// evalúa la expresión de esa rama y los demás futuros se descartan. La
+/// expresión se evalúa fuera del sondeo, así que puede usar `.await`,
+/// `return` o `break`.
+///
+/// Por defecto el orden de sondeo empieza en una rama al azar, para que
+/// una rama siempre lista no deje sin turno a las demás. Con `biased;` al
+/// principio se sondean en el orden escrito.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::time::sleep;
+/// use std::time::Duration;
+///
+/// async_runtime::block_on(async {
+///     let winner = async_runtime::select! {
+///         _ = sleep(Duration::from_secs(60)) => "lento",
+///         value = async { 5 } => if value == 5 { "rápido" } else { "?" },
+///     };
+///     assert_eq!(winner, "rápido");
+/// });
+/// ```
+#[macro_export]
+macro_rules! select {
+    (@bind $start:expr; $index:expr; [$(($i:expr, $future:ident, $output:ident, $pat:pat, $body:expr))*];
+        $p:pat = $f:expr => $b:expr, $($rest:tt)*) => {{
+        let mut future = ::std::pin::pin!($f);
+        let mut output = ::std::option::Option::None;
+        $crate::select!(@bind $start; $index + 1;
+            [$(($i, $future, $output, $pat, $body))* ($index, future, output, $p, $b)]; $($rest)*)
+    }};
+    (@bind $start:expr; $count:expr; [$(($i:expr, $future:ident, $output:ident, $pat:pat, $body:expr))*];) => {{
+        let count: usize = $count;
+        let start: usize = $start(count);
+        ::std::future::poll_fn(|cx| {
+            for offset in 0..count {
+                let branch = (start + offset) % count;
+                $(
+                    if branch == $i {
+                        if let ::std::task::Poll::Ready(value) =
+                            ::std::future::Future::poll($future.as_mut(), cx)
+                        {
+                            $output = ::std::option::Option::Some(value);
+                            return ::std::task::Poll::Ready(());
+                        }
+                    }
+                )*
+            }
+            ::std::task::Poll::Pending
+        })
+        .await;
+        $(
+            if let ::std::option::Option::Some($pat) = $output.take() { $body } else
+        )*
+        { ::std::unreachable!("select! terminó sin ninguna rama lista") }
+    }};
+    (biased; $($p:pat = $f:expr => $b:expr),+ $(,)?) => {
+        $crate::select!(@bind |_| 0; 0usize; []; $($p = $f => $b,)+)
+    };
+    ($($p:pat = $f:expr => $b:expr),+ $(,)?) => {
+        $crate::select!(@bind $crate::future::random_index; 0usize; []; $($p = $f => $b,)+)
+    };
+}
+
+/// Declara claves de valores locales a la tarea (ver `task::LocalKey`)
+///
+/// Cada `static` declarado es un `task::LocalKey<T>`: el valor se fija con
+/// `scope` y lo leen `with`/`get` desde cualquier punto del futuro, también
+/// después de un `.await`.
+///
+/// # Ejemplo
+/// ```
+/// async_runtime::task_local! {
+///     pub static REQUEST_ID: u64;
+///     static USER: String;
+/// }
+///
+/// async_runtime::block_on(REQUEST_ID.scope(42, async {
+///     assert_eq!(REQUEST_ID.get(), 42);
+///     assert!(USER.try_with(|user| user.clone()).is_err());
+/// }));
+/// ```
+#[macro_export]
+macro_rules! task_local {
+    () => {};
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty; $($rest:tt)*) => {
+        $crate::task_local!(@key $(#[$attr])* $vis $name, $t);
+        $crate::task_local!($($rest)*);
+    };
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty) => {
+        $crate::task_local!(@key $(#[$attr])* $vis $name, $t);
+    };
+    (@key $(#[$attr:meta])* $vis:vis $name:ident, $t:ty) => {
+        $(#[$attr])*
+        $vis static $name: $crate::task::LocalKey<$t> = {
+            ::std::thread_local! {
+                static KEY: ::std::cell::RefCell<::std::option::Option<$t>> =
+                    const { ::std::cell::RefCell::new(::std::option::Option::None) };
+            }
+            $crate::task::LocalKey { inner: KEY }
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{executor::Executor, sync::oneshot, time::sleep};
+    use std::{
+        future::{Future, poll_fn},
+        pin::Pin,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    #[test]
+    fn select_body_can_break_out_of_loop() {
+        let ticks = crate::block_on(async {
+            let mut ticks = 0;
+            let deadline = sleep(Duration::from_millis(50));
+            let mut deadline = std::pin::pin!(deadline);
+            loop {
+                crate::select! {
+                    biased;
+                    _ = deadline.as_mut() => break ticks,
+                    _ = sleep(Duration::from_millis(5)) => ticks += 1,
+                }
+            }
+        });
+        assert!(ticks >= 3, "solo hubo {ticks} ticks");
+    }
+
+    #[test]
+    fn join_runs_futures_concurrently() {
+        crate::test::Builder::new().run(async {
+            let start = crate::time::now();
+            let (a, b) = crate::join!(
+                async {
+                    sleep(Duration::from_millis(40)).await;
+                    'a'
+                },
+                async {
+                    sleep(Duration::from_millis(40)).await;
+                    'b'
+                },
+            );
+            assert_eq!((a, b), ('a', 'b'));
+            // En serie el reloj simulado habría avanzado 80 ms
+            assert_eq!(crate::time::now() - start, Duration::from_millis(40));
+        });
+    }
+
+    #[test]
+    fn join_only_repolls_woken_branches() {
+        let mut executor = Executor::new();
+        let polls: Vec<_> = (0..2).map(|_| Arc::new(AtomicUsize::new(0))).collect();
+        let (tx_a, mut rx_a) = oneshot::channel::<u8>();
+        let (tx_b, mut rx_b) = oneshot::channel::<char>();
+
+        let (polls_a, polls_b) = (polls[0].clone(), polls[1].clone());
+        let handle = executor.spawn(async move {
+            crate::join!(
+                poll_fn(move |cx| {
+                    polls_a.fetch_add(1, Ordering::SeqCst);
+                    Pin::new(&mut rx_a).poll(cx).map(Result::unwrap)
+                }),
+                poll_fn(move |cx| {
+                    polls_b.fetch_add(1, Ordering::SeqCst);
+                    Pin::new(&mut rx_b).poll(cx).map(Result::unwrap)
+                }),
+            )
+        });
+
+        let polls_of = || {
+            polls
+                .iter()
+                .map(|p| p.load(Ordering::SeqCst))
+                .collect::<Vec<_>>()
+        };
+        while executor.has_ready_tasks() {
+            executor.poll();
+        }
+        assert_eq!(polls_of(), vec![1, 1]);
+
+        // Despertar una rama no vuelve a sondear la otra
+        tx_a.send(7).unwrap();
+        while executor.has_ready_tasks() {
+            executor.poll();
+        }
+        assert_eq!(polls_of(), vec![2, 1]);
+
+        tx_b.send('b').unwrap();
+        assert_eq!(executor.block_on(handle).unwrap(), (7, 'b'));
+        assert_eq!(polls_of(), vec![2, 2]);
+    }
+}
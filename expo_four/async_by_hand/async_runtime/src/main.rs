@@ -1,13 +1,10 @@
 use std::{
-    future::{self, Future},
+    future::Future,
     pin::Pin,
     task::{Context, Poll},
 };
 
-use async_runtime::{
-    executor::{self, Executor},
-    waker,
-};
+use async_runtime::executor::Executor;
 
 pub struct CountingFuture {
     pub count: i32,
@@ -39,14 +36,8 @@ fn main() {
     let handle = executor.spawn(counter);
     let handle_two = executor.spawn(counter_two);
 
-    std::thread::spawn(move || {
-        loop {
-            executor.poll();
-        }
-    });
-
-    let result = handle.block_on().unwrap();
-    let result_2 = handle_two.block_on().unwrap();
+    let (result, result_2) =
+        executor.block_on(async { (handle.await.unwrap(), handle_two.await.unwrap()) });
 
     println!("Results: {} - {}", result, result_2);
 }
@@ -0,0 +1,294 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use crate::task::{Id, TaskInfo};
+
+/// Contadores de una tarea, actualizados por su ejecutor y sus wakers
+#[derive(Debug, Default)]
+pub(crate) struct TaskStats {
+    polls: AtomicU64,
+    wakes: AtomicU64,
+    /// Tiempo total dentro de `poll`, en nanosegundos
+    busy: AtomicU64,
+    /// Sondeo más largo, en nanosegundos
+    max_poll: AtomicU64,
+}
+
+impl TaskStats {
+    pub(crate) fn record_poll(&self, elapsed: Duration) {
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.polls.fetch_add(1, Ordering::Relaxed);
+        self.busy.fetch_add(nanos, Ordering::Relaxed);
+        self.max_poll.fetch_max(nanos, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_wake(&self) {
+        self.wakes.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// En qué punto de su ciclo está una tarea viva
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskState {
+    /// Esperando a que algo la despierte
+    Idle,
+    /// Despertada, en cola para sondearse
+    Ready,
+    /// Un worker la está sondeando
+    Running,
+}
+
+impl fmt::Display for TaskState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self {
+            TaskState::Idle => "en espera",
+            TaskState::Ready => "lista",
+            TaskState::Running => "en ejecución",
+        };
+        f.pad(state)
+    }
+}
+
+/// Foto de una tarea viva y de sus contadores
+#[derive(Debug, Clone)]
+pub struct TaskSnapshot {
+    info: TaskInfo,
+    state: TaskState,
+    polls: u64,
+    wakes: u64,
+    busy: Duration,
+    max_poll: Duration,
+}
+
+impl TaskSnapshot {
+    pub(crate) fn new(info: &TaskInfo, state: TaskState) -> Self {
+        let stats = info.stats();
+        TaskSnapshot {
+            info: info.clone(),
+            state,
+            polls: stats.polls.load(Ordering::Relaxed),
+            wakes: stats.wakes.load(Ordering::Relaxed),
+            busy: Duration::from_nanos(stats.busy.load(Ordering::Relaxed)),
+            max_poll: Duration::from_nanos(stats.max_poll.load(Ordering::Relaxed)),
+        }
+    }
+
+    pub fn id(&self) -> Id {
+        self.info.id()
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.info.name()
+    }
+
+    pub fn state(&self) -> TaskState {
+        self.state
+    }
+
+    /// Veces que se ha sondeado
+    pub fn polls(&self) -> u64 {
+        self.polls
+    }
+
+    /// Veces que se ha despertado, incluidas las que no la reencolaron
+    pub fn wakes(&self) -> u64 {
+        self.wakes
+    }
+
+    /// Tiempo total que ha pasado dentro de `poll`
+    pub fn busy(&self) -> Duration {
+        self.busy
+    }
+
+    /// Su sondeo más largo: un valor alto indica que bloquea al worker
+    pub fn max_poll(&self) -> Duration {
+        self.max_poll
+    }
+}
+
+/// Recuento de las tareas vivas de un ejecutor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RuntimeMetrics {
+    workers: usize,
+    idle: usize,
+    ready: usize,
+    running: usize,
+}
+
+impl RuntimeMetrics {
+    pub(crate) fn new(workers: usize, tasks: &[TaskSnapshot]) -> Self {
+        let mut metrics = RuntimeMetrics {
+            workers,
+            ..RuntimeMetrics::default()
+        };
+        for task in tasks {
+            match task.state {
+                TaskState::Idle => metrics.idle += 1,
+                TaskState::Ready => metrics.ready += 1,
+                TaskState::Running => metrics.running += 1,
+            }
+        }
+        metrics
+    }
+
+    /// Hilos que sondean tareas
+    pub fn num_workers(&self) -> usize {
+        self.workers
+    }
+
+    /// Tareas lanzadas que aún no terminaron
+    pub fn live_tasks(&self) -> usize {
+        self.idle + self.ready + self.running
+    }
+
+    pub fn idle_tasks(&self) -> usize {
+        self.idle
+    }
+
+    pub fn ready_tasks(&self) -> usize {
+        self.ready
+    }
+
+    pub fn running_tasks(&self) -> usize {
+        self.running
+    }
+}
+
+/// Listado de las tareas vivas de un ejecutor, ordenadas por identificador
+///
+/// Se muestra como una tabla, una tarea por línea.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::{executor::Executor, time::sleep};
+/// use std::time::Duration;
+///
+/// let mut executor = Executor::new();
+/// executor.spawn_named("dormilona", sleep(Duration::from_secs(60)));
+/// executor.poll();
+///
+/// let dump = executor.dump();
+/// assert_eq!(dump.tasks()[0].name(), Some("dormilona"));
+/// println!("{dump}");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dump {
+    tasks: Vec<TaskSnapshot>,
+}
+
+impl Dump {
+    pub(crate) fn new(mut tasks: Vec<TaskSnapshot>) -> Self {
+        tasks.sort_by_key(TaskSnapshot::id);
+        Dump { tasks }
+    }
+
+    pub fn tasks(&self) -> &[TaskSnapshot] {
+        &self.tasks
+    }
+}
+
+impl fmt::Display for Dump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>6}  {:<20}  {:<12}  {:>8}  {:>8}  {:>12}  {:>12}",
+            "id", "nombre", "estado", "sondeos", "wakes", "ocupada", "máx. sondeo"
+        )?;
+        for task in &self.tasks {
+            writeln!(
+                f,
+                "{:>6}  {:<20}  {:<12}  {:>8}  {:>8}  {:>12}  {:>12}",
+                task.id().to_string(),
+                task.name().unwrap_or("-"),
+                task.state,
+                task.polls,
+                task.wakes,
+                format!("{:?}", task.busy),
+                format!("{:?}", task.max_poll),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaskState;
+    use crate::{executor::Executor, runtime::Runtime, task::yield_now, time::sleep};
+    use std::{sync::mpsc, time::Duration};
+
+    #[test]
+    fn dump_reports_states_and_counters() {
+        // Una tarea que cede una vez y luego espera: dos sondeos, un despertar
+        let mut executor = Executor::new();
+        executor.spawn_named("lenta", async {
+            yield_now().await;
+            sleep(Duration::from_secs(60)).await;
+        });
+        assert_eq!(executor.metrics().ready_tasks(), 1);
+        while executor.has_ready_tasks() {
+            executor.poll();
+        }
+
+        let dump = executor.dump();
+        let task = &dump.tasks()[0];
+        assert_eq!(
+            (task.name(), task.state()),
+            (Some("lenta"), TaskState::Idle)
+        );
+        assert_eq!((task.polls(), task.wakes()), (2, 1));
+        assert!(task.max_poll() <= task.busy());
+
+        // Con un solo worker, la tarea dormida ya cedió el hilo cuando la
+        // acaparadora empieza
+        let runtime = Runtime::new(1).unwrap();
+        let (started, polled) = mpsc::channel();
+        let (release, blocked) = mpsc::channel::<()>();
+
+        let sleeper_started = started.clone();
+        runtime.spawn_named("dormida", async move {
+            sleeper_started.send(()).unwrap();
+            sleep(Duration::from_secs(60)).await;
+        });
+        polled.recv().unwrap();
+        let hog = runtime.spawn_named("acaparadora", async move {
+            started.send(()).unwrap();
+            blocked.recv().unwrap()
+        });
+        polled.recv().unwrap();
+
+        let metrics = runtime.metrics();
+        assert_eq!((metrics.num_workers(), metrics.live_tasks()), (1, 2));
+        assert_eq!((metrics.running_tasks(), metrics.idle_tasks()), (1, 1));
+
+        let states: Vec<_> = runtime
+            .dump()
+            .tasks()
+            .iter()
+            .map(|task| (task.name().unwrap().to_owned(), task.state()))
+            .collect();
+        assert_eq!(
+            states,
+            [
+                ("dormida".to_owned(), TaskState::Idle),
+                ("acaparadora".to_owned(), TaskState::Running),
+            ]
+        );
+
+        release.send(()).unwrap();
+        hog.block_on().unwrap();
+        // El único worker retira la acaparadora del registro antes de
+        // sondear cualquier tarea posterior
+        runtime.spawn(async {}).block_on().unwrap();
+        let named: Vec<_> = runtime
+            .dump()
+            .tasks()
+            .iter()
+            .filter_map(|task| task.name().map(str::to_owned))
+            .collect();
+        assert_eq!(named, ["dormida"]);
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    io,
+    net::{self, IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    panic,
+};
+
+use crate::task::{JoinError, spawn_blocking};
+
+/// Direcciones que aceptan `TcpStream::connect` y `UdpSocket`
+///
+/// Equivale a `std::net::ToSocketAddrs`, pero sin bloquear al worker: las
+/// direcciones literales se usan tal cual y los nombres de host se
+/// resuelven con `getaddrinfo` en el pool de `spawn_blocking`.
+pub trait ToSocketAddrs {
+    #[doc(hidden)]
+    fn to_resolve(&self) -> Resolve;
+}
+
+/// Resultado inmediato o nombre pendiente de resolver
+#[doc(hidden)]
+pub enum Resolve {
+    Ready(Vec<SocketAddr>),
+    /// `host:puerto` para `getaddrinfo`
+    Lookup(String),
+}
+
+impl ToSocketAddrs for SocketAddr {
+    fn to_resolve(&self) -> Resolve {
+        Resolve::Ready(vec![*self])
+    }
+}
+
+impl ToSocketAddrs for (IpAddr, u16) {
+    fn to_resolve(&self) -> Resolve {
+        Resolve::Ready(vec![SocketAddr::from(*self)])
+    }
+}
+
+impl ToSocketAddrs for (Ipv4Addr, u16) {
+    fn to_resolve(&self) -> Resolve {
+        Resolve::Ready(vec![SocketAddr::from(*self)])
+    }
+}
+
+impl ToSocketAddrs for (Ipv6Addr, u16) {
+    fn to_resolve(&self) -> Resolve {
+        Resolve::Ready(vec![SocketAddr::from(*self)])
+    }
+}
+
+impl ToSocketAddrs for str {
+    fn to_resolve(&self) -> Resolve {
+        match self.parse() {
+            Ok(addr) => Resolve::Ready(vec![addr]),
+            Err(_) => Resolve::Lookup(self.to_owned()),
+        }
+    }
+}
+
+impl ToSocketAddrs for String {
+    fn to_resolve(&self) -> Resolve {
+        self.as_str().to_resolve()
+    }
+}
+
+impl ToSocketAddrs for (&str, u16) {
+    fn to_resolve(&self) -> Resolve {
+        let (host, port) = *self;
+        match host.parse::<IpAddr>() {
+            Ok(ip) => Resolve::Ready(vec![SocketAddr::new(ip, port)]),
+            Err(_) => Resolve::Lookup(format!("{}:{}", host, port)),
+        }
+    }
+}
+
+impl ToSocketAddrs for (String, u16) {
+    fn to_resolve(&self) -> Resolve {
+        (self.0.as_str(), self.1).to_resolve()
+    }
+}
+
+impl<T: ToSocketAddrs + ?Sized> ToSocketAddrs for &T {
+    fn to_resolve(&self) -> Resolve {
+        (**self).to_resolve()
+    }
+}
+
+/// Resuelve `host` sin bloquear el hilo del ejecutor
+///
+/// Sirve para resolver un nombre una sola vez y reutilizar la dirección,
+/// por ejemplo en cada `UdpSocket::send_to`.
+///
+/// # Ejemplo
+/// ```no_run
+/// async_runtime::block_on(async {
+///     let statsd = async_runtime::net::lookup_host("statsd:8125").await?.next();
+///     println!("{:?}", statsd);
+///     Ok::<_, std::io::Error>(())
+/// })
+/// .unwrap();
+/// ```
+pub async fn lookup_host(host: impl ToSocketAddrs) -> io::Result<impl Iterator<Item = SocketAddr>> {
+    resolve(&host).await.map(Vec::into_iter)
+}
+
+/// Direcciones de `addr`; solo los nombres pasan por el pool de bloqueo
+pub(super) async fn resolve(addr: &(impl ToSocketAddrs + ?Sized)) -> io::Result<Vec<SocketAddr>> {
+    let host = match addr.to_resolve() {
+        Resolve::Ready(addrs) => return Ok(addrs),
+        Resolve::Lookup(host) => host,
+    };
+
+    let lookup =
+        spawn_blocking(move || net::ToSocketAddrs::to_socket_addrs(&host).map(Iterator::collect));
+    match lookup.await {
+        Ok(result) => result,
+        Err(JoinError::Panicked(payload)) => panic::resume_unwind(payload),
+        Err(JoinError::Cancelled) => Err(io::Error::other(
+            "el runtime se apagó durante la resolución",
+        )),
+    }
+}
+
+pub(super) fn no_addresses() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "no se pudo resolver ninguna dirección",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Resolve, ToSocketAddrs, lookup_host};
+    use crate::block_on;
+    use std::net::SocketAddr;
+
+    #[test]
+    fn literals_resolve_without_a_lookup() {
+        let expected: SocketAddr = "127.0.0.1:80".parse().unwrap();
+        for addr in [
+            "127.0.0.1:80".to_resolve(),
+            ("127.0.0.1", 80).to_resolve(),
+            expected.to_resolve(),
+        ] {
+            assert!(matches!(addr, Resolve::Ready(addrs) if addrs == [expected]));
+        }
+        assert!(
+            matches!(("localhost", 80).to_resolve(), Resolve::Lookup(host) if host == "localhost:80")
+        );
+    }
+
+    #[test]
+    fn host_names_resolve_on_the_blocking_pool() {
+        let addrs: Vec<_> = block_on(lookup_host("localhost:80")).unwrap().collect();
+        assert!(
+            addrs
+                .iter()
+                .all(|addr| addr.port() == 80 && addr.ip().is_loopback())
+        );
+        assert!(!addrs.is_empty());
+    }
+}
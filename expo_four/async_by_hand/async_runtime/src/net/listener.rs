@@ -0,0 +1,53 @@
+use std::{
+    io,
+    net::{self, SocketAddr, ToSocketAddrs},
+};
+
+use super::TcpStream;
+use crate::reactor::AsyncFd;
+
+/// Socket TCP que acepta conexiones de forma asíncrona
+///
+/// # Ejemplo
+/// ```no_run
+/// use async_runtime::net::TcpListener;
+///
+/// async_runtime::block_on(async {
+///     let listener = TcpListener::bind("0.0.0.0:7878")?;
+///     let (mut stream, addr) = listener.accept().await?;
+///     println!("Conexión de {}", addr);
+///     stream.write_all(b"hola").await?;
+///     Ok::<_, std::io::Error>(())
+/// })
+/// .unwrap();
+/// ```
+pub struct TcpListener {
+    io: AsyncFd<net::TcpListener>,
+}
+
+impl TcpListener {
+    /// Crea un socket escuchando en `addr`
+    ///
+    /// # Pánico
+    /// Si se llama fuera de un ejecutor de `async_runtime`
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<TcpListener> {
+        TcpListener::from_std(net::TcpListener::bind(addr)?)
+    }
+
+    /// Adopta un listener de std, pasándolo a modo no bloqueante
+    pub fn from_std(listener: net::TcpListener) -> io::Result<TcpListener> {
+        Ok(TcpListener {
+            io: AsyncFd::new(listener)?,
+        })
+    }
+
+    /// Espera a la siguiente conexión entrante
+    pub async fn accept(&self) -> io::Result<(TcpStream, SocketAddr)> {
+        let (stream, addr) = self.io.read_with(|listener| listener.accept()).await?;
+        Ok((TcpStream::from_std(stream)?, addr))
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.io.get_ref().local_addr()
+    }
+}
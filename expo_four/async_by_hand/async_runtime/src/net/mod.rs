@@ -0,0 +1,82 @@
+mod addr;
+mod listener;
+mod split;
+mod stream;
+mod udp;
+mod unix;
+
+pub use addr::{ToSocketAddrs, lookup_host};
+pub use listener::TcpListener;
+pub use split::{OwnedReadHalf, OwnedWriteHalf};
+pub use stream::TcpStream;
+pub use udp::UdpSocket;
+pub use unix::{UnixListener, UnixStream};
+
+#[cfg(test)]
+mod tests {
+    use super::{TcpListener, TcpStream};
+    use crate::block_on;
+    use std::{
+        io::{Read, Write},
+        net, thread,
+    };
+
+    #[test]
+    fn split_stream_talks_to_a_blocking_peer() {
+        let listener = net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // Eco: devuelve lo recibido y cierra
+        let peer = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0; 5];
+            stream.read_exact(&mut buf).unwrap();
+            stream.write_all(&buf).unwrap();
+        });
+
+        let response = block_on(async move {
+            let stream = TcpStream::connect(addr).await.unwrap();
+            let (mut reader, mut writer) = stream.into_split();
+            writer.write_all(b"hola!").await.unwrap();
+
+            let mut response = Vec::new();
+            reader.read_to_end(&mut response).await.unwrap();
+            response
+        });
+
+        peer.join().unwrap();
+        assert_eq!(response, b"hola!");
+    }
+
+    #[test]
+    fn listener_accepts_connections() {
+        let received = block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let addr = listener.local_addr().unwrap();
+
+            let peer = thread::spawn(move || {
+                let mut stream = net::TcpStream::connect(addr).unwrap();
+                stream.write_all(b"ping").unwrap();
+            });
+
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 4];
+            stream.read_exact(&mut buf).await.unwrap();
+            peer.join().unwrap();
+            buf
+        });
+
+        assert_eq!(&received, b"ping");
+    }
+
+    #[test]
+    fn connect_to_closed_port_fails() {
+        let addr = {
+            let listener = net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap()
+        };
+
+        let result = block_on(TcpStream::connect(addr));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    io,
+    net::{Shutdown, SocketAddr},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use super::{TcpStream, stream};
+use crate::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Mitad de lectura de un `TcpStream`, creada con `TcpStream::into_split`
+pub struct OwnedReadHalf {
+    inner: Arc<TcpStream>,
+}
+
+/// Mitad de escritura de un `TcpStream`, creada con `TcpStream::into_split`
+///
+/// Al soltarse cierra el sentido de escritura del socket.
+pub struct OwnedWriteHalf {
+    inner: Arc<TcpStream>,
+}
+
+impl OwnedReadHalf {
+    pub(super) fn new(inner: Arc<TcpStream>) -> Self {
+        OwnedReadHalf { inner }
+    }
+
+    /// Ver `TcpStream::read`
+    pub async fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        AsyncReadExt::read(self, buf).await
+    }
+
+    /// Ver `TcpStream::read_exact`
+    pub async fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        AsyncReadExt::read_exact(self, buf).await
+    }
+
+    /// Ver `TcpStream::read_to_end`
+    pub async fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        AsyncReadExt::read_to_end(self, buf).await
+    }
+
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.inner.peer_addr()
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+}
+
+impl OwnedWriteHalf {
+    pub(super) fn new(inner: Arc<TcpStream>) -> Self {
+        OwnedWriteHalf { inner }
+    }
+
+    /// Ver `TcpStream::write`
+    pub async fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        AsyncWriteExt::write(self, buf).await
+    }
+
+    /// Ver `TcpStream::write_all`
+    pub async fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        AsyncWriteExt::write_all(self, buf).await
+    }
+
+    /// Ver `TcpStream::shutdown`
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        AsyncWriteExt::shutdown(self).await
+    }
+
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.inner.peer_addr()
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+}
+
+impl AsyncRead for OwnedReadHalf {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        stream::poll_read(&self.inner.io, cx, buf)
+    }
+}
+
+impl AsyncWrite for OwnedWriteHalf {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        stream::poll_write(&self.inner.io, cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        stream::shutdown(&self.inner.io)
+    }
+}
+
+impl Drop for OwnedWriteHalf {
+    fn drop(&mut self) {
+        // El socket pudo cerrarse ya desde el otro extremo
+        let _ = self.inner.io.get_ref().shutdown(Shutdown::Write);
+    }
+}
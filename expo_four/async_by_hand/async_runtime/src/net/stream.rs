@@ -0,0 +1,203 @@
+use std::{
+    io::{self, Read, Write},
+    net::{self, Shutdown, SocketAddr},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use super::{
+    OwnedReadHalf, OwnedWriteHalf, ToSocketAddrs,
+    addr::{no_addresses, resolve},
+};
+use crate::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    reactor::{AsyncFd, sys},
+};
+
+/// Conexión TCP asíncrona
+///
+/// El socket es no bloqueante desde su construcción y queda registrado en
+/// el reactor del ejecutor actual. Implementa `AsyncRead` y `AsyncWrite`,
+/// así que admite `BufReader`, `Framed` y demás adaptadores de
+/// `async_runtime::io`. Para leer y escribir desde tareas distintas se
+/// divide con `into_split`.
+///
+/// # Ejemplo
+/// ```no_run
+/// use async_runtime::net::TcpStream;
+///
+/// async_runtime::block_on(async {
+///     let mut stream = TcpStream::connect("127.0.0.1:7878").await?;
+///     stream.write_all(b"hola").await?;
+///     let mut response = Vec::new();
+///     stream.read_to_end(&mut response).await?;
+///     Ok::<_, std::io::Error>(())
+/// })
+/// .unwrap();
+/// ```
+pub struct TcpStream {
+    pub(super) io: AsyncFd<net::TcpStream>,
+}
+
+impl TcpStream {
+    /// Abre una conexión con `addr` sin bloquear el hilo
+    ///
+    /// Si `addr` resuelve a varias direcciones se prueban en orden y se
+    /// devuelve el último error. Los nombres de host se resuelven en el pool
+    /// de `spawn_blocking`.
+    pub async fn connect(addr: impl ToSocketAddrs) -> io::Result<TcpStream> {
+        let mut last_error = None;
+
+        for addr in resolve(&addr).await? {
+            match TcpStream::connect_addr(&addr).await {
+                Ok(stream) => return Ok(stream),
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(no_addresses))
+    }
+
+    async fn connect_addr(addr: &SocketAddr) -> io::Result<TcpStream> {
+        let stream = TcpStream::from_std(sys::connect(addr)?)?;
+
+        // La conexión termina (o falla) cuando el socket pasa a ser escribible
+        stream.io.writable().await;
+        if let Some(e) = stream.io.get_ref().take_error()? {
+            return Err(e);
+        }
+        Ok(stream)
+    }
+
+    /// Adopta un socket de std, pasándolo a modo no bloqueante
+    ///
+    /// # Pánico
+    /// Si se llama fuera de un ejecutor de `async_runtime`
+    pub fn from_std(stream: net::TcpStream) -> io::Result<TcpStream> {
+        Ok(TcpStream {
+            io: AsyncFd::new(stream)?,
+        })
+    }
+
+    /// Lee datos disponibles en `buf`
+    ///
+    /// # Retorno
+    /// Bytes leídos; `0` indica que el otro extremo cerró la conexión
+    pub async fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        AsyncReadExt::read(self, buf).await
+    }
+
+    /// Lee exactamente `buf.len()` bytes
+    ///
+    /// Falla con `UnexpectedEof` si la conexión se cierra antes.
+    pub async fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        AsyncReadExt::read_exact(self, buf).await
+    }
+
+    /// Lee hasta que el otro extremo cierre la conexión
+    pub async fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        AsyncReadExt::read_to_end(self, buf).await
+    }
+
+    /// Lee sin esperar: devuelve `WouldBlock` si no hay datos disponibles
+    pub fn try_read(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.io.get_ref().read(buf)
+    }
+
+    /// Escribe sin esperar: devuelve `WouldBlock` si el socket está lleno
+    pub fn try_write(&self, buf: &[u8]) -> io::Result<usize> {
+        self.io.get_ref().write(buf)
+    }
+
+    /// Escribe parte de `buf` y devuelve cuántos bytes se enviaron
+    pub async fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        AsyncWriteExt::write(self, buf).await
+    }
+
+    /// Escribe `buf` completo, reanudando donde se quedó cada escritura parcial
+    pub async fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        AsyncWriteExt::write_all(self, buf).await
+    }
+
+    /// Cierra el sentido de escritura: el otro extremo leerá EOF
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        AsyncWriteExt::shutdown(self).await
+    }
+
+    /// Divide la conexión en una mitad de lectura y otra de escritura
+    ///
+    /// Cada mitad puede moverse a una tarea distinta. Soltar la mitad de
+    /// escritura cierra el sentido de escritura.
+    pub fn into_split(self) -> (OwnedReadHalf, OwnedWriteHalf) {
+        let stream = Arc::new(self);
+        (
+            OwnedReadHalf::new(stream.clone()),
+            OwnedWriteHalf::new(stream),
+        )
+    }
+
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.io.get_ref().peer_addr()
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.io.get_ref().local_addr()
+    }
+
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.io.get_ref().set_nodelay(nodelay)
+    }
+}
+
+// Operaciones compartidas por `TcpStream` y sus mitades. Solo necesitan
+// `&AsyncFd` porque std implementa `Read` y `Write` para `&TcpStream`.
+
+pub(super) fn poll_read(
+    io: &AsyncFd<net::TcpStream>,
+    cx: &mut Context<'_>,
+    buf: &mut [u8],
+) -> Poll<io::Result<usize>> {
+    io.poll_read_with(cx, |mut stream| stream.read(buf))
+}
+
+pub(super) fn poll_write(
+    io: &AsyncFd<net::TcpStream>,
+    cx: &mut Context<'_>,
+    buf: &[u8],
+) -> Poll<io::Result<usize>> {
+    io.poll_write_with(cx, |mut stream| stream.write(buf))
+}
+
+pub(super) fn shutdown(io: &AsyncFd<net::TcpStream>) -> Poll<io::Result<()>> {
+    Poll::Ready(io.get_ref().shutdown(Shutdown::Write))
+}
+
+impl AsyncRead for TcpStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        poll_read(&self.io, cx, buf)
+    }
+}
+
+impl AsyncWrite for TcpStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        poll_write(&self.io, cx, buf)
+    }
+
+    /// TCP no guarda nada en espacio de usuario: no hay nada que vaciar
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        shutdown(&self.io)
+    }
+}
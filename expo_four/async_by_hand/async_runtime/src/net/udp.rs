@@ -0,0 +1,145 @@
+use std::{
+    io,
+    net::{self, SocketAddr},
+};
+
+use super::{
+    ToSocketAddrs,
+    addr::{no_addresses, resolve},
+};
+use crate::reactor::AsyncFd;
+
+/// Socket UDP asíncrono
+///
+/// Cada operación envía o recibe un datagrama completo. Con `connect` se
+/// fija un destino por defecto para `send` y `recv`, y se descartan los
+/// datagramas de otros orígenes.
+///
+/// # Ejemplo
+/// ```no_run
+/// use async_runtime::net::UdpSocket;
+///
+/// async_runtime::block_on(async {
+///     let socket = UdpSocket::bind("0.0.0.0:0").await?;
+///     socket.send_to(b"requests:1|c", "127.0.0.1:8125").await?;
+///     Ok::<_, std::io::Error>(())
+/// })
+/// .unwrap();
+/// ```
+pub struct UdpSocket {
+    io: AsyncFd<net::UdpSocket>,
+}
+
+impl UdpSocket {
+    /// Crea un socket enlazado a `addr`
+    ///
+    /// Si `addr` resuelve a varias direcciones se prueban en orden y se
+    /// devuelve el último error.
+    ///
+    /// # Pánico
+    /// Si se llama fuera de un ejecutor de `async_runtime`
+    pub async fn bind(addr: impl ToSocketAddrs) -> io::Result<UdpSocket> {
+        let mut last_error = None;
+
+        for addr in resolve(&addr).await? {
+            match net::UdpSocket::bind(addr) {
+                Ok(socket) => return UdpSocket::from_std(socket),
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(no_addresses))
+    }
+
+    /// Adopta un socket de std, pasándolo a modo no bloqueante
+    pub fn from_std(socket: net::UdpSocket) -> io::Result<UdpSocket> {
+        Ok(UdpSocket {
+            io: AsyncFd::new(socket)?,
+        })
+    }
+
+    /// Fija el destino de `send` y el único origen aceptado por `recv`
+    ///
+    /// En UDP no hay intercambio con el otro extremo: solo se espera a la
+    /// resolución del nombre, si hace falta.
+    pub async fn connect(&self, addr: impl ToSocketAddrs) -> io::Result<()> {
+        let addrs = resolve(&addr).await?;
+        self.io.get_ref().connect(&addrs[..])
+    }
+
+    /// Envía `buf` como un datagrama a `target`
+    ///
+    /// Un nombre de host se resuelve en cada llamada; para enviar muchos
+    /// datagramas al mismo destino conviene resolverlo una vez con
+    /// `net::lookup_host`.
+    ///
+    /// # Retorno
+    /// Bytes enviados; en UDP siempre es el datagrama completo
+    pub async fn send_to(&self, buf: &[u8], target: impl ToSocketAddrs) -> io::Result<usize> {
+        let target = resolve(&target)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(no_addresses)?;
+        self.io
+            .write_with(|socket| socket.send_to(buf, target))
+            .await
+    }
+
+    /// Espera un datagrama y devuelve su tamaño y su origen
+    ///
+    /// Si `buf` es más pequeño que el datagrama, el resto se descarta.
+    pub async fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        self.io.read_with(|socket| socket.recv_from(buf)).await
+    }
+
+    /// Envía `buf` al destino fijado con `connect`
+    pub async fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        self.io.write_with(|socket| socket.send(buf)).await
+    }
+
+    /// Espera un datagrama del destino fijado con `connect`
+    pub async fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.io.read_with(|socket| socket.recv(buf)).await
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.io.get_ref().local_addr()
+    }
+
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.io.get_ref().peer_addr()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UdpSocket;
+    use crate::{block_on, join};
+
+    #[test]
+    fn datagrams_keep_their_boundaries_and_origin() {
+        block_on(async {
+            let a = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+            let b = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+            let b_addr = b.local_addr().unwrap();
+
+            let receive = async {
+                let mut buf = [0; 16];
+                let (len, from) = b.recv_from(&mut buf).await.unwrap();
+                let first = (buf[..len].to_vec(), from);
+                let (len, _) = b.recv_from(&mut buf).await.unwrap();
+                (first, buf[..len].to_vec())
+            };
+            let send = async {
+                a.send_to(b"uno", b_addr).await.unwrap();
+                a.send_to(b"dos", b_addr).await.unwrap();
+            };
+
+            let (((first, from), second), ()) = join!(receive, send);
+            assert_eq!(first, b"uno");
+            assert_eq!(from, a.local_addr().unwrap());
+            assert_eq!(second, b"dos");
+        });
+    }
+}
@@ -0,0 +1,271 @@
+use std::{
+    io::{self, Read, Write},
+    net::Shutdown,
+    os::unix::net::{self, SocketAddr},
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use crate::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    reactor::{AsyncFd, sys},
+    time,
+};
+
+/// Primera espera de `UnixStream::connect` ante un listener saturado
+const CONNECT_BACKOFF_MIN: Duration = Duration::from_millis(1);
+
+/// Espera máxima entre reintentos de `UnixStream::connect`
+const CONNECT_BACKOFF_MAX: Duration = Duration::from_millis(100);
+
+/// Socket Unix de tipo stream que acepta conexiones de forma asíncrona
+///
+/// `bind` crea el fichero del socket y falla si ya existe; borrarlo al
+/// terminar es responsabilidad de quien lo creó.
+///
+/// # Ejemplo
+/// ```no_run
+/// use async_runtime::net::UnixListener;
+///
+/// async_runtime::block_on(async {
+///     let listener = UnixListener::bind("/tmp/sidecar.sock")?;
+///     let (mut stream, _) = listener.accept().await?;
+///     stream.write_all(b"hola").await?;
+///     Ok::<_, std::io::Error>(())
+/// })
+/// .unwrap();
+/// ```
+pub struct UnixListener {
+    io: AsyncFd<net::UnixListener>,
+}
+
+impl UnixListener {
+    /// Crea un socket escuchando en `path`
+    ///
+    /// # Pánico
+    /// Si se llama fuera de un ejecutor de `async_runtime`
+    pub fn bind(path: impl AsRef<Path>) -> io::Result<UnixListener> {
+        UnixListener::from_std(net::UnixListener::bind(path)?)
+    }
+
+    /// Adopta un listener de std, pasándolo a modo no bloqueante
+    pub fn from_std(listener: net::UnixListener) -> io::Result<UnixListener> {
+        Ok(UnixListener {
+            io: AsyncFd::new(listener)?,
+        })
+    }
+
+    /// Espera a la siguiente conexión entrante
+    ///
+    /// La dirección del otro extremo suele ser anónima: los clientes no
+    /// acostumbran a enlazar su socket a una ruta.
+    pub async fn accept(&self) -> io::Result<(UnixStream, SocketAddr)> {
+        let (stream, addr) = self.io.read_with(|listener| listener.accept()).await?;
+        Ok((UnixStream::from_std(stream)?, addr))
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.io.get_ref().local_addr()
+    }
+}
+
+/// Conexión asíncrona sobre un socket Unix
+///
+/// Equivalente local de `TcpStream`: implementa `AsyncRead` y `AsyncWrite`
+/// y ofrece los mismos métodos de conveniencia.
+///
+/// # Ejemplo
+/// ```no_run
+/// use async_runtime::net::UnixStream;
+///
+/// async_runtime::block_on(async {
+///     let mut stream = UnixStream::connect("/tmp/sidecar.sock").await?;
+///     stream.write_all(b"hola").await?;
+///     let mut response = Vec::new();
+///     stream.read_to_end(&mut response).await?;
+///     Ok::<_, std::io::Error>(())
+/// })
+/// .unwrap();
+/// ```
+pub struct UnixStream {
+    io: AsyncFd<net::UnixStream>,
+}
+
+impl UnixStream {
+    /// Conecta con el socket en `path` sin bloquear el hilo
+    pub async fn connect(path: impl AsRef<Path>) -> io::Result<UnixStream> {
+        let mut backoff = CONNECT_BACKOFF_MIN;
+        let stream = loop {
+            match sys::connect_unix(path.as_ref()) {
+                // Cola del listener llena: se reintenta cuando acepte a alguien
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(CONNECT_BACKOFF_MAX);
+                }
+                result => break UnixStream::from_std(result?)?,
+            }
+        };
+
+        // Igual que en TCP: la conexión termina cuando el socket es escribible
+        stream.io.writable().await;
+        if let Some(e) = stream.io.get_ref().take_error()? {
+            return Err(e);
+        }
+        Ok(stream)
+    }
+
+    /// Crea un par de sockets conectados entre sí
+    pub fn pair() -> io::Result<(UnixStream, UnixStream)> {
+        let (a, b) = net::UnixStream::pair()?;
+        Ok((UnixStream::from_std(a)?, UnixStream::from_std(b)?))
+    }
+
+    /// Adopta un socket de std, pasándolo a modo no bloqueante
+    ///
+    /// # Pánico
+    /// Si se llama fuera de un ejecutor de `async_runtime`
+    pub fn from_std(stream: net::UnixStream) -> io::Result<UnixStream> {
+        Ok(UnixStream {
+            io: AsyncFd::new(stream)?,
+        })
+    }
+
+    /// Ver `TcpStream::read`
+    pub async fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        AsyncReadExt::read(self, buf).await
+    }
+
+    /// Ver `TcpStream::read_exact`
+    pub async fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        AsyncReadExt::read_exact(self, buf).await
+    }
+
+    /// Ver `TcpStream::read_to_end`
+    pub async fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        AsyncReadExt::read_to_end(self, buf).await
+    }
+
+    /// Ver `TcpStream::write`
+    pub async fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        AsyncWriteExt::write(self, buf).await
+    }
+
+    /// Ver `TcpStream::write_all`
+    pub async fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        AsyncWriteExt::write_all(self, buf).await
+    }
+
+    /// Ver `TcpStream::shutdown`
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        AsyncWriteExt::shutdown(self).await
+    }
+
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.io.get_ref().peer_addr()
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.io.get_ref().local_addr()
+    }
+}
+
+impl AsyncRead for UnixStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        self.io.poll_read_with(cx, |mut stream| stream.read(buf))
+    }
+}
+
+impl AsyncWrite for UnixStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.io.poll_write_with(cx, |mut stream| stream.write(buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(self.io.get_ref().shutdown(Shutdown::Write))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UnixListener, UnixStream};
+    use crate::{block_on, join, reactor::sys};
+    use std::{env, fs, io, os::fd::AsRawFd, process};
+
+    #[test]
+    fn stream_connects_to_listener_by_path() {
+        let path = env::temp_dir().join(format!("async_runtime-{}.sock", process::id()));
+        let _ = fs::remove_file(&path);
+
+        let response = block_on(async {
+            let listener = UnixListener::bind(&path).unwrap();
+
+            let server = async {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = [0; 4];
+                stream.read_exact(&mut buf).await.unwrap();
+                stream.write_all(&buf).await.unwrap();
+            };
+            let client = async {
+                let mut stream = UnixStream::connect(&path).await.unwrap();
+                stream.write_all(b"ping").await.unwrap();
+                let mut response = Vec::new();
+                stream.read_to_end(&mut response).await.unwrap();
+                response
+            };
+
+            join!(server, client).1
+        });
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(response, b"ping");
+    }
+
+    #[test]
+    fn connect_retries_while_the_backlog_is_full() {
+        let path = env::temp_dir().join(format!("async_runtime-full-{}.sock", process::id()));
+        let _ = fs::remove_file(&path);
+
+        block_on(async {
+            let listener = UnixListener::bind(&path).unwrap();
+            // Cola mínima: se llena con una o dos conexiones
+            assert_eq!(
+                unsafe { libc::listen(listener.io.get_ref().as_raw_fd(), 0) },
+                0
+            );
+
+            let mut queued = Vec::new();
+            loop {
+                match sys::connect_unix(&path) {
+                    Ok(stream) => queued.push(stream),
+                    Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                    Err(e) => panic!("{e}"),
+                }
+            }
+
+            // El cliente se sondea primero y encuentra la cola llena
+            let client = async { UnixStream::connect(&path).await.unwrap() };
+            let server = async {
+                for _ in 0..=queued.len() {
+                    listener.accept().await.unwrap();
+                }
+            };
+            join!(client, server);
+        });
+
+        fs::remove_file(&path).unwrap();
+    }
+}
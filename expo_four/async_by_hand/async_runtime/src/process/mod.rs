@@ -0,0 +1,340 @@
+mod pipe;
+
+use std::{
+    ffi::OsStr,
+    io,
+    os::fd::OwnedFd,
+    path::Path,
+    process::{self, ExitStatus, Output, Stdio},
+};
+
+use crate::{
+    io::{AsyncRead, AsyncReadExt},
+    join,
+    reactor::{AsyncFd, sys},
+    task::spawn_blocking,
+};
+
+pub use pipe::{ChildStderr, ChildStdin, ChildStdout};
+
+/// Construye y lanza procesos hijos que se esperan sin bloquear el hilo
+///
+/// Envuelve `std::process::Command`: los métodos de configuración son los
+/// mismos. Las tuberías pedidas con `Stdio::piped()` se registran en el
+/// reactor, y el fin del proceso se detecta con un pidfd en lugar de con
+/// `waitpid` bloqueante.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::process::Command;
+///
+/// async_runtime::block_on(async {
+///     let output = Command::new("echo").arg("hola").output().await.unwrap();
+///     assert!(output.status.success());
+///     assert_eq!(output.stdout, b"hola\n");
+/// });
+/// ```
+pub struct Command {
+    std: process::Command,
+    kill_on_drop: bool,
+    /// Qué flujos configuró quien llama: `output` solo cambia los demás
+    stdin_set: bool,
+    stdout_set: bool,
+    stderr_set: bool,
+}
+
+impl Command {
+    pub fn new(program: impl AsRef<OsStr>) -> Self {
+        Command {
+            std: process::Command::new(program),
+            kill_on_drop: false,
+            stdin_set: false,
+            stdout_set: false,
+            stderr_set: false,
+        }
+    }
+
+    pub fn arg(&mut self, arg: impl AsRef<OsStr>) -> &mut Self {
+        self.std.arg(arg);
+        self
+    }
+
+    pub fn args<I, S>(&mut self, args: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.std.args(args);
+        self
+    }
+
+    pub fn env(&mut self, key: impl AsRef<OsStr>, value: impl AsRef<OsStr>) -> &mut Self {
+        self.std.env(key, value);
+        self
+    }
+
+    pub fn envs<I, K, V>(&mut self, vars: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<OsStr>,
+        V: AsRef<OsStr>,
+    {
+        self.std.envs(vars);
+        self
+    }
+
+    pub fn env_remove(&mut self, key: impl AsRef<OsStr>) -> &mut Self {
+        self.std.env_remove(key);
+        self
+    }
+
+    pub fn env_clear(&mut self) -> &mut Self {
+        self.std.env_clear();
+        self
+    }
+
+    pub fn current_dir(&mut self, dir: impl AsRef<Path>) -> &mut Self {
+        self.std.current_dir(dir);
+        self
+    }
+
+    pub fn stdin(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        self.std.stdin(cfg);
+        self.stdin_set = true;
+        self
+    }
+
+    pub fn stdout(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        self.std.stdout(cfg);
+        self.stdout_set = true;
+        self
+    }
+
+    pub fn stderr(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        self.std.stderr(cfg);
+        self.stderr_set = true;
+        self
+    }
+
+    /// Si el `Child` se suelta sin haber terminado, se mata con SIGKILL
+    ///
+    /// Por defecto, como en std, el proceso sigue corriendo.
+    pub fn kill_on_drop(&mut self, kill_on_drop: bool) -> &mut Self {
+        self.kill_on_drop = kill_on_drop;
+        self
+    }
+
+    /// Acceso al `Command` de std, para opciones que no se envuelven aquí
+    pub fn as_std_mut(&mut self) -> &mut process::Command {
+        &mut self.std
+    }
+
+    /// Lanza el proceso
+    ///
+    /// # Pánico
+    /// Si se llama fuera de un ejecutor de `async_runtime`
+    pub fn spawn(&mut self) -> io::Result<Child> {
+        let mut child = self.std.spawn()?;
+
+        // Sin pidfd no hay forma de esperar al hijo sin bloquear: se
+        // recoge antes de devolver el error para no dejar un zombi
+        let exit = match sys::pidfd_open(child.id()).and_then(AsyncFd::new) {
+            Ok(exit) => exit,
+            Err(e) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(e);
+            }
+        };
+
+        Ok(Child {
+            stdin: child.stdin.take().map(ChildStdin::from_std).transpose()?,
+            stdout: child.stdout.take().map(ChildStdout::from_std).transpose()?,
+            stderr: child.stderr.take().map(ChildStderr::from_std).transpose()?,
+            inner: child,
+            exit,
+            kill_on_drop: self.kill_on_drop,
+        })
+    }
+
+    /// Lanza el proceso y espera a que termine
+    ///
+    /// Los flujos sin configurar se heredan del proceso actual.
+    pub async fn status(&mut self) -> io::Result<ExitStatus> {
+        self.spawn()?.wait().await
+    }
+
+    /// Lanza el proceso, espera a que termine y recoge su salida
+    ///
+    /// Como en std, salvo que se hayan configurado, stdout y stderr se
+    /// capturan y stdin es `Stdio::null()`.
+    pub async fn output(&mut self) -> io::Result<Output> {
+        if !self.stdin_set {
+            self.std.stdin(Stdio::null());
+        }
+        if !self.stdout_set {
+            self.std.stdout(Stdio::piped());
+        }
+        if !self.stderr_set {
+            self.std.stderr(Stdio::piped());
+        }
+        self.spawn()?.wait_with_output().await
+    }
+}
+
+/// Proceso hijo lanzado con `Command::spawn`
+///
+/// Las tuberías pedidas al lanzarlo están en `stdin`, `stdout` y
+/// `stderr`; pueden tomarse con `take` y moverse a otras tareas.
+pub struct Child {
+    pub stdin: Option<ChildStdin>,
+    pub stdout: Option<ChildStdout>,
+    pub stderr: Option<ChildStderr>,
+    inner: process::Child,
+    /// pidfd del hijo: el reactor avisa cuando se vuelve legible
+    exit: AsyncFd<OwnedFd>,
+    kill_on_drop: bool,
+}
+
+impl Child {
+    /// Identificador del proceso en el sistema
+    pub fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    /// Espera a que el proceso termine
+    ///
+    /// Antes cierra `stdin`, si sigue abierto: un hijo que lee hasta EOF
+    /// nunca terminaría. Puede llamarse varias veces; tras la primera
+    /// devuelve el mismo estado sin esperar.
+    pub async fn wait(&mut self) -> io::Result<ExitStatus> {
+        drop(self.stdin.take());
+
+        let inner = &mut self.inner;
+        self.exit
+            .read_with(|_| match inner.try_wait()? {
+                Some(status) => Ok(status),
+                // Readiness antigua: el proceso sigue vivo
+                None => Err(io::ErrorKind::WouldBlock.into()),
+            })
+            .await
+    }
+
+    /// Devuelve el estado de salida si el proceso ya terminó, sin esperar
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        self.inner.try_wait()
+    }
+
+    /// Envía SIGKILL al proceso sin esperar a que termine
+    pub fn start_kill(&mut self) -> io::Result<()> {
+        self.inner.kill()
+    }
+
+    /// Mata el proceso y espera a que termine
+    pub async fn kill(&mut self) -> io::Result<()> {
+        self.start_kill()?;
+        self.wait().await?;
+        Ok(())
+    }
+
+    /// Espera a que el proceso termine mientras lee stdout y stderr
+    ///
+    /// Las dos salidas se leen a la vez: un hijo que llena una de ellas
+    /// mientras se espera la otra no se queda bloqueado.
+    pub async fn wait_with_output(mut self) -> io::Result<Output> {
+        drop(self.stdin.take());
+
+        async fn read_all(pipe: Option<impl AsyncRead + Unpin>) -> io::Result<Vec<u8>> {
+            let mut buf = Vec::new();
+            if let Some(mut pipe) = pipe {
+                pipe.read_to_end(&mut buf).await?;
+            }
+            Ok(buf)
+        }
+
+        let (stdout, stderr) = (self.stdout.take(), self.stderr.take());
+        let (stdout, stderr) = join!(read_all(stdout), read_all(stderr));
+        let status = self.wait().await?;
+
+        Ok(Output {
+            status,
+            stdout: stdout?,
+            stderr: stderr?,
+        })
+    }
+}
+
+impl Drop for Child {
+    fn drop(&mut self) {
+        if !self.kill_on_drop || !matches!(self.inner.try_wait(), Ok(None)) {
+            return;
+        }
+        if self.inner.kill().is_err() || matches!(self.inner.try_wait(), Ok(Some(_))) {
+            return;
+        }
+
+        // SIGKILL no es instantáneo: se recoge al proceso desde el pool de
+        // bloqueo para no dejar un zombi ni bloquear este hilo. El pid no
+        // puede reutilizarse mientras nadie lo haya recogido.
+        let pid = self.inner.id() as libc::pid_t;
+        spawn_blocking(move || unsafe {
+            libc::waitpid(pid, std::ptr::null_mut(), 0);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Command;
+    use crate::{
+        block_on,
+        io::{AsyncReadExt, AsyncWriteExt},
+    };
+    use std::{process::Stdio, thread, time::Duration};
+
+    #[test]
+    fn pipes_carry_data_and_wait_reports_the_exit_code() {
+        let (status, output) = block_on(async {
+            let mut child = Command::new("sh")
+                .args(["-c", "cat; exit 3"])
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .spawn()
+                .unwrap();
+
+            let mut stdin = child.stdin.take().unwrap();
+            stdin.write_all(b"hola").await.unwrap();
+            // Cerrar la tubería es lo que permite a `cat` terminar
+            drop(stdin);
+
+            let mut output = Vec::new();
+            let mut stdout = child.stdout.take().unwrap();
+            stdout.read_to_end(&mut output).await.unwrap();
+            (child.wait().await.unwrap(), output)
+        });
+
+        assert_eq!(status.code(), Some(3));
+        assert_eq!(output, b"hola");
+    }
+
+    #[test]
+    fn kill_on_drop_kills_the_child() {
+        let pid = block_on(async {
+            let child = Command::new("sleep")
+                .arg("60")
+                .kill_on_drop(true)
+                .spawn()
+                .unwrap();
+            child.id() as libc::pid_t
+        });
+
+        // Una vez recogido, el pid deja de existir
+        for _ in 0..1000 {
+            if unsafe { libc::kill(pid, 0) } == -1 {
+                return;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        panic!("el proceso {} sigue vivo", pid);
+    }
+}
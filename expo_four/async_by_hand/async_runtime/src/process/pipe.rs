@@ -0,0 +1,101 @@
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+    os::fd::OwnedFd,
+    pin::Pin,
+    process,
+    task::{Context, Poll},
+};
+
+use crate::{
+    io::{AsyncRead, AsyncWrite},
+    reactor::AsyncFd,
+};
+
+/// Extremo de escritura de la entrada estándar de un `Child`
+///
+/// Soltarlo cierra la tubería: el proceso hijo lee EOF.
+pub struct ChildStdin {
+    io: AsyncFd<File>,
+}
+
+/// Extremo de lectura de la salida estándar de un `Child`
+pub struct ChildStdout {
+    io: AsyncFd<File>,
+}
+
+/// Extremo de lectura de la salida de errores de un `Child`
+pub struct ChildStderr {
+    io: AsyncFd<File>,
+}
+
+/// Registra el extremo de una tubería en el reactor
+///
+/// Se trata como `File` porque std implementa `Read` y `Write` para
+/// `&File`, que es lo que necesitan `poll_read_with` y `poll_write_with`.
+fn register(fd: impl Into<OwnedFd>) -> io::Result<AsyncFd<File>> {
+    AsyncFd::new(File::from(fd.into()))
+}
+
+impl ChildStdin {
+    pub(super) fn from_std(stdin: process::ChildStdin) -> io::Result<Self> {
+        Ok(ChildStdin {
+            io: register(stdin)?,
+        })
+    }
+}
+
+impl ChildStdout {
+    pub(super) fn from_std(stdout: process::ChildStdout) -> io::Result<Self> {
+        Ok(ChildStdout {
+            io: register(stdout)?,
+        })
+    }
+}
+
+impl ChildStderr {
+    pub(super) fn from_std(stderr: process::ChildStderr) -> io::Result<Self> {
+        Ok(ChildStderr {
+            io: register(stderr)?,
+        })
+    }
+}
+
+impl AsyncWrite for ChildStdin {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.io.poll_write_with(cx, |mut pipe| pipe.write(buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    /// Una tubería no se cierra a medias: para enviar EOF hay que soltarla
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncRead for ChildStdout {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        self.io.poll_read_with(cx, |mut pipe| pipe.read(buf))
+    }
+}
+
+impl AsyncRead for ChildStderr {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        self.io.poll_read_with(cx, |mut pipe| pipe.read(buf))
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    future::poll_fn,
+    io,
+    os::fd::AsRawFd,
+    task::{Context, Poll},
+};
+
+use super::{Direction, Registration, sys};
+
+/// Envuelve un objeto de E/S para esperar su readiness en el reactor
+///
+/// El descriptor pasa a modo no bloqueante al construirlo y queda
+/// registrado en el reactor del ejecutor actual. Las operaciones se
+/// reintentan solo cuando epoll indica que el descriptor está listo, en
+/// lugar de reactivar la tarea en cuanto aparece `WouldBlock`.
+///
+/// # Ejemplo
+/// ```no_run
+/// use async_runtime::reactor::AsyncFd;
+/// use std::{io::Read, net::TcpStream};
+///
+/// async_runtime::block_on(async {
+///     let stream = AsyncFd::new(TcpStream::connect("127.0.0.1:7878")?)?;
+///     let mut buf = [0; 1024];
+///     let len = stream.read_with(|mut s| s.read(&mut buf)).await?;
+///     println!("{:?}", &buf[..len]);
+///     Ok::<_, std::io::Error>(())
+/// })
+/// .unwrap();
+/// ```
+pub struct AsyncFd<T: AsRawFd> {
+    // Orden de campos importante: el registro se suelta antes que el
+    // descriptor, así nunca se desregistra un número de fd ya reutilizado
+    registration: Registration,
+    inner: T,
+}
+
+impl<T: AsRawFd> AsyncFd<T> {
+    /// Pone `inner` en modo no bloqueante y lo registra en el reactor
+    ///
+    /// # Pánico
+    /// Si se llama fuera de un ejecutor de `async_runtime`
+    pub fn new(inner: T) -> io::Result<Self> {
+        let fd = inner.as_raw_fd();
+        sys::set_nonblocking(fd)?;
+        let registration = Registration::new(fd)?;
+        Ok(AsyncFd {
+            registration,
+            inner,
+        })
+    }
+
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Desregistra el descriptor y devuelve el objeto original
+    pub fn into_inner(self) -> T {
+        let AsyncFd {
+            registration,
+            inner,
+        } = self;
+        drop(registration);
+        inner
+    }
+
+    /// Espera a que el descriptor parezca legible
+    ///
+    /// Puede completarse con una readiness antigua: la operación posterior
+    /// aún puede devolver `WouldBlock`. Para esperar y leer en un solo paso
+    /// se usa `read_with`.
+    pub async fn readable(&self) {
+        poll_fn(|cx| {
+            self.registration
+                .poll_ready(cx, Direction::Read)
+                .map(|_| ())
+        })
+        .await
+    }
+
+    /// Espera a que el descriptor parezca escribible (ver `readable`)
+    pub async fn writable(&self) {
+        poll_fn(|cx| {
+            self.registration
+                .poll_ready(cx, Direction::Write)
+                .map(|_| ())
+        })
+        .await
+    }
+
+    /// Ejecuta una lectura no bloqueante en cuanto el descriptor esté listo
+    ///
+    /// `op` se reintenta mientras devuelva `WouldBlock`, esperando entre
+    /// intentos a que epoll avise de nuevos datos.
+    pub async fn read_with<R>(&self, mut op: impl FnMut(&T) -> io::Result<R>) -> io::Result<R> {
+        poll_fn(|cx| self.poll_read_with(cx, &mut op)).await
+    }
+
+    /// Ejecuta una escritura no bloqueante en cuanto el descriptor esté listo
+    pub async fn write_with<R>(&self, mut op: impl FnMut(&T) -> io::Result<R>) -> io::Result<R> {
+        poll_fn(|cx| self.poll_write_with(cx, &mut op)).await
+    }
+
+    /// Versión por sondeo de `read_with`, para implementar otros futuros
+    pub fn poll_read_with<R>(
+        &self,
+        cx: &mut Context<'_>,
+        mut op: impl FnMut(&T) -> io::Result<R>,
+    ) -> Poll<io::Result<R>> {
+        self.registration
+            .poll_io(cx, Direction::Read, || op(&self.inner))
+    }
+
+    /// Versión por sondeo de `write_with`
+    pub fn poll_write_with<R>(
+        &self,
+        cx: &mut Context<'_>,
+        mut op: impl FnMut(&T) -> io::Result<R>,
+    ) -> Poll<io::Result<R>> {
+        self.registration
+            .poll_io(cx, Direction::Write, || op(&self.inner))
+    }
+}
@@ -0,0 +1,394 @@
+mod async_fd;
+pub(crate) mod sys;
+
+use std::{
+    collections::HashMap,
+    io,
+    os::fd::RawFd,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+    },
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+use crate::{context, driver::Handle, task::coop};
+pub use async_fd::AsyncFd;
+use sys::{Event, Selector};
+
+// Bits de readiness de un recurso
+const READABLE: usize = 0b0001;
+const WRITABLE: usize = 0b0010;
+const READ_CLOSED: usize = 0b0100;
+const WRITE_CLOSED: usize = 0b1000;
+
+/// Los bits de cierre nunca se limpian: un extremo cerrado no vuelve a abrirse
+const CLOSED: usize = READ_CLOSED | WRITE_CLOSED;
+
+/// El resto de bits guarda un contador de eventos (tick)
+const TICK_SHIFT: u32 = 8;
+const READINESS_MASK: usize = (1 << TICK_SHIFT) - 1;
+
+/// Token reservado para el eventfd que interrumpe `epoll_wait`
+const WAKE_TOKEN: u64 = u64::MAX;
+
+/// Cuántos eventos se leen como máximo en cada vuelta del reactor
+const EVENTS_CAPACITY: usize = 1024;
+
+/// Sentido de una operación de E/S
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Read,
+    Write,
+}
+
+impl Direction {
+    fn mask(self) -> usize {
+        match self {
+            Direction::Read => READABLE | READ_CLOSED,
+            Direction::Write => WRITABLE | WRITE_CLOSED,
+        }
+    }
+}
+
+/// Readiness observada por una tarea, junto con el tick en que se observó
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReadyEvent {
+    tick: usize,
+    ready: usize,
+}
+
+/// Wakers de las tareas que esperan a un recurso, uno por sentido
+///
+/// Un lector y un escritor pueden esperar a la vez sobre el mismo socket.
+#[derive(Default)]
+struct Waiters {
+    reader: Option<Waker>,
+    writer: Option<Waker>,
+}
+
+/// Estado de un descriptor registrado en el reactor
+struct ScheduledIo {
+    /// `tick << TICK_SHIFT | bits de readiness`
+    readiness: AtomicUsize,
+    waiters: Mutex<Waiters>,
+}
+
+impl ScheduledIo {
+    /// Añade readiness recibida de epoll e incrementa el tick
+    fn set_readiness(&self, ready: usize) {
+        let _ = self
+            .readiness
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+                let tick = (current >> TICK_SHIFT).wrapping_add(1);
+                Some((tick << TICK_SHIFT) | (current & READINESS_MASK) | ready)
+            });
+    }
+
+    /// Despierta a las tareas interesadas en `ready`
+    fn wake(&self, ready: usize) {
+        let (reader, writer) = {
+            let mut waiters = self.waiters.lock().unwrap();
+            let reader = if ready & Direction::Read.mask() != 0 {
+                waiters.reader.take()
+            } else {
+                None
+            };
+            let writer = if ready & Direction::Write.mask() != 0 {
+                waiters.writer.take()
+            } else {
+                None
+            };
+            (reader, writer)
+        };
+
+        // Se despierta fuera del lock
+        if let Some(waker) = reader {
+            waker.wake();
+        }
+        if let Some(waker) = writer {
+            waker.wake();
+        }
+    }
+
+    fn ready_event(&self, direction: Direction) -> Option<ReadyEvent> {
+        let current = self.readiness.load(Ordering::Acquire);
+        let ready = current & direction.mask();
+        (ready != 0).then_some(ReadyEvent {
+            tick: current >> TICK_SHIFT,
+            ready,
+        })
+    }
+
+    /// Comprueba la readiness y, si no hay, deja registrado el waker
+    fn poll_ready(&self, cx: &mut Context<'_>, direction: Direction) -> Poll<ReadyEvent> {
+        if let Some(event) = self.ready_event(direction) {
+            return Poll::Ready(event);
+        }
+
+        let mut waiters = self.waiters.lock().unwrap();
+        let slot = match direction {
+            Direction::Read => &mut waiters.reader,
+            Direction::Write => &mut waiters.writer,
+        };
+        match slot {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            _ => *slot = Some(cx.waker().clone()),
+        }
+
+        // Un evento pudo llegar mientras se registraba el waker
+        match self.ready_event(direction) {
+            Some(event) => Poll::Ready(event),
+            None => Poll::Pending,
+        }
+    }
+
+    /// Limpia la readiness observada tras recibir `WouldBlock`
+    ///
+    /// Solo limpia si no llegó ningún evento nuevo desde que se observó
+    /// (mismo tick): si no, se perdería un aviso de epoll.
+    fn clear_readiness(&self, event: ReadyEvent) {
+        let clear = event.ready & !CLOSED;
+        let _ = self
+            .readiness
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+                if current >> TICK_SHIFT != event.tick {
+                    return None;
+                }
+                Some(current & !clear)
+            });
+    }
+}
+
+/// Reactor de E/S basado en epoll
+///
+/// Guarda la readiness de cada descriptor registrado y los wakers de las
+/// tareas que esperan por él. Al dar una vuelta (`turn`) bloquea en
+/// `epoll_wait` y despierta únicamente a las tareas cuyos descriptores
+/// están realmente listos.
+pub(crate) struct Reactor {
+    selector: Selector,
+    resources: Mutex<HashMap<u64, Arc<ScheduledIo>>>,
+    next_token: AtomicU64,
+    /// Búfer de eventos; solo lo usa el hilo que da la vuelta al reactor
+    events: Mutex<Vec<Event>>,
+}
+
+impl Reactor {
+    pub(crate) fn new() -> io::Result<Self> {
+        Ok(Reactor {
+            selector: Selector::new(WAKE_TOKEN)?,
+            resources: Mutex::new(HashMap::new()),
+            next_token: AtomicU64::new(0),
+            events: Mutex::new(Vec::with_capacity(EVENTS_CAPACITY)),
+        })
+    }
+
+    fn register(&self, fd: RawFd) -> io::Result<(u64, Arc<ScheduledIo>)> {
+        let token = self.next_token.fetch_add(1, Ordering::Relaxed);
+        let io = Arc::new(ScheduledIo {
+            readiness: AtomicUsize::new(0),
+            waiters: Mutex::new(Waiters::default()),
+        });
+
+        self.resources.lock().unwrap().insert(token, io.clone());
+        if let Err(e) = self.selector.register(fd, token) {
+            self.resources.lock().unwrap().remove(&token);
+            return Err(e);
+        }
+        Ok((token, io))
+    }
+
+    fn deregister(&self, fd: RawFd, token: u64) {
+        // El descriptor pudo cerrarse ya, en cuyo caso epoll lo retiró solo
+        let _ = self.selector.deregister(fd);
+        let io = self.resources.lock().unwrap().remove(&token);
+        drop(io);
+    }
+
+    /// Espera eventos hasta `timeout` y despierta a las tareas listas
+    ///
+    /// Una vuelta sin espera (`timeout` cero) no bloquea si otro hilo ya está
+    /// dentro de `epoll_wait`: ese hilo entregará los eventos.
+    ///
+    /// # Retorno
+    /// Cuántos recursos recibieron eventos (sin contar los avisos de `wake`)
+    pub(crate) fn turn(&self, timeout: Option<Duration>) -> usize {
+        let mut events = if timeout == Some(Duration::ZERO) {
+            match self.events.try_lock() {
+                Ok(events) => events,
+                Err(_) => return 0,
+            }
+        } else {
+            self.events.lock().unwrap()
+        };
+        if self.selector.select(&mut events, timeout).is_err() {
+            return 0;
+        }
+
+        let mut woken = 0;
+        for event in events.iter() {
+            let token = event.u64;
+            if token == WAKE_TOKEN {
+                self.selector.reset_waker();
+                continue;
+            }
+
+            let io = match self.resources.lock().unwrap().get(&token) {
+                Some(io) => io.clone(),
+                None => continue, // Ya se desregistró
+            };
+
+            let ready = readiness_of(event.events);
+            io.set_readiness(ready);
+            io.wake(ready);
+            woken += 1;
+        }
+        woken
+    }
+
+    /// Interrumpe la espera en `epoll_wait` desde cualquier hilo
+    pub(crate) fn wake(&self) {
+        let _ = self.selector.wake();
+    }
+
+    /// Suelta los wakers guardados al apagar el ejecutor
+    pub(crate) fn shutdown(&self) {
+        let resources: Vec<_> = self.resources.lock().unwrap().values().cloned().collect();
+        for io in resources {
+            let waiters = std::mem::take(&mut *io.waiters.lock().unwrap());
+            drop(waiters);
+        }
+    }
+}
+
+/// Traduce la máscara de epoll a bits de readiness
+fn readiness_of(events: u32) -> usize {
+    let events = events as libc::c_int;
+    let mut ready = 0;
+
+    if events & libc::EPOLLIN != 0 {
+        ready |= READABLE;
+    }
+    if events & libc::EPOLLOUT != 0 {
+        ready |= WRITABLE;
+    }
+    if events & libc::EPOLLRDHUP != 0 {
+        ready |= READ_CLOSED;
+    }
+    if events & libc::EPOLLHUP != 0 {
+        ready |= READ_CLOSED | WRITE_CLOSED;
+    }
+    // Ante un error se despierta a todos: la operación devolverá el error
+    if events & libc::EPOLLERR != 0 {
+        ready |= READABLE | WRITABLE;
+    }
+    ready
+}
+
+/// Registro de un descriptor en el reactor del ejecutor actual
+///
+/// Se desregistra al soltarse. Debe soltarse antes de cerrar el descriptor.
+pub(crate) struct Registration {
+    handle: Arc<Handle>,
+    fd: RawFd,
+    token: u64,
+    io: Arc<ScheduledIo>,
+}
+
+impl Registration {
+    /// Registra `fd` en el reactor del ejecutor actual
+    ///
+    /// # Pánico
+    /// Si se llama fuera de un ejecutor de `async_runtime`
+    pub(crate) fn new(fd: RawFd) -> io::Result<Self> {
+        let handle = context::current();
+        let (token, io) = handle.io.register(fd)?;
+        Ok(Registration {
+            handle,
+            fd,
+            token,
+            io,
+        })
+    }
+
+    /// Espera a que el descriptor esté listo en el sentido indicado
+    pub(crate) fn poll_ready(
+        &self,
+        cx: &mut Context<'_>,
+        direction: Direction,
+    ) -> Poll<ReadyEvent> {
+        coop::poll_budgeted(cx, |cx| self.io.poll_ready(cx, direction))
+    }
+
+    /// Olvida una readiness que resultó en `WouldBlock`
+    pub(crate) fn clear_readiness(&self, event: ReadyEvent) {
+        self.io.clear_readiness(event);
+    }
+
+    /// Ejecuta una operación no bloqueante cuando el descriptor esté listo
+    ///
+    /// # Comportamiento
+    /// 1. Espera readiness en el sentido indicado
+    /// 2. Ejecuta `op`
+    /// 3. Si devuelve `WouldBlock`, limpia la readiness y vuelve a esperar
+    pub(crate) fn poll_io<R>(
+        &self,
+        cx: &mut Context<'_>,
+        direction: Direction,
+        mut op: impl FnMut() -> io::Result<R>,
+    ) -> Poll<io::Result<R>> {
+        loop {
+            let event = match self.poll_ready(cx, direction) {
+                Poll::Ready(event) => event,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            match op() {
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => self.clear_readiness(event),
+                result => return Poll::Ready(result),
+            }
+        }
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.handle.io.deregister(self.fd, self.token);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AsyncFd;
+    use crate::block_on;
+    use std::{
+        io::{Read, Write},
+        os::unix::net::UnixStream,
+        thread,
+        time::{Duration, Instant},
+    };
+
+    #[test]
+    fn read_waits_for_readiness() {
+        let (reader, mut writer) = UnixStream::pair().unwrap();
+        let start = Instant::now();
+
+        let writer_thread = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            writer.write_all(b"hola").unwrap();
+        });
+
+        let received = block_on(async {
+            let reader = AsyncFd::new(reader).unwrap();
+            let mut buf = [0; 16];
+            let len = reader.read_with(|mut s| s.read(&mut buf)).await.unwrap();
+            buf[..len].to_vec()
+        });
+
+        writer_thread.join().unwrap();
+        assert_eq!(received, b"hola");
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+}
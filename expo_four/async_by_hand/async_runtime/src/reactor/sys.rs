@@ -0,0 +1,298 @@
+use std::{
+    io, mem,
+    net::{SocketAddr, TcpStream},
+    os::{
+        fd::{AsRawFd, FromRawFd, OwnedFd, RawFd},
+        unix::{ffi::OsStrExt, net::UnixStream},
+    },
+    path::Path,
+    ptr,
+    time::Duration,
+};
+
+/// Evento de readiness devuelto por `epoll_wait`
+pub(crate) type Event = libc::epoll_event;
+
+/// Convierte el resultado de una llamada al sistema en `io::Result`
+fn cvt(result: libc::c_int) -> io::Result<libc::c_int> {
+    if result == -1 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(result)
+    }
+}
+
+/// Instancia de epoll más un eventfd para interrumpir la espera desde otro hilo
+pub(crate) struct Selector {
+    epoll: OwnedFd,
+    waker: OwnedFd,
+}
+
+impl Selector {
+    /// Crea la instancia de epoll y registra el eventfd con el token `wake_token`
+    pub(crate) fn new(wake_token: u64) -> io::Result<Self> {
+        let epoll = cvt(unsafe { libc::epoll_create1(libc::EPOLL_CLOEXEC) })?;
+        // Seguro: el descriptor acaba de crearse y nadie más lo posee
+        let epoll = unsafe { OwnedFd::from_raw_fd(epoll) };
+
+        let waker = cvt(unsafe { libc::eventfd(0, libc::EFD_NONBLOCK | libc::EFD_CLOEXEC) })?;
+        let waker = unsafe { OwnedFd::from_raw_fd(waker) };
+
+        let selector = Selector { epoll, waker };
+        // El eventfd se registra por nivel: sigue notificando hasta vaciarlo
+        selector.ctl(
+            libc::EPOLL_CTL_ADD,
+            selector.waker.as_raw_fd(),
+            libc::EPOLLIN as u32,
+            wake_token,
+        )?;
+        Ok(selector)
+    }
+
+    fn ctl(&self, op: libc::c_int, fd: RawFd, events: u32, token: u64) -> io::Result<()> {
+        let mut event = libc::epoll_event { events, u64: token };
+        cvt(unsafe { libc::epoll_ctl(self.epoll.as_raw_fd(), op, fd, &mut event) })?;
+        Ok(())
+    }
+
+    /// Registra `fd` para lectura y escritura en modo edge-triggered
+    ///
+    /// Por flanco: epoll solo avisa cuando el descriptor pasa a estar listo,
+    /// así un socket listo que nadie atiende no despierta al hilo una y otra vez.
+    pub(crate) fn register(&self, fd: RawFd, token: u64) -> io::Result<()> {
+        let events = libc::EPOLLIN | libc::EPOLLOUT | libc::EPOLLRDHUP | libc::EPOLLET;
+        self.ctl(libc::EPOLL_CTL_ADD, fd, events as u32, token)
+    }
+
+    pub(crate) fn deregister(&self, fd: RawFd) -> io::Result<()> {
+        self.ctl(libc::EPOLL_CTL_DEL, fd, 0, 0)
+    }
+
+    /// Espera eventos hasta `timeout` (`None` = sin límite)
+    ///
+    /// Deja en `events` los eventos recibidos. Una interrupción por señal
+    /// (`EINTR`) se trata como una espera sin eventos.
+    pub(crate) fn select(
+        &self,
+        events: &mut Vec<Event>,
+        timeout: Option<Duration>,
+    ) -> io::Result<()> {
+        // epoll trabaja en milisegundos: se redondea hacia arriba para no despertar antes de tiempo
+        let timeout = match timeout {
+            Some(timeout) => timeout
+                .as_nanos()
+                .div_ceil(1_000_000)
+                .min(libc::c_int::MAX as u128) as libc::c_int,
+            None => -1,
+        };
+
+        events.clear();
+        let result = unsafe {
+            libc::epoll_wait(
+                self.epoll.as_raw_fd(),
+                events.as_mut_ptr(),
+                events.capacity() as libc::c_int,
+                timeout,
+            )
+        };
+
+        match cvt(result) {
+            Ok(count) => {
+                // Seguro: epoll_wait inicializó `count` eventos
+                unsafe { events.set_len(count as usize) };
+                Ok(())
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Interrumpe un `select` en curso (o el siguiente) desde cualquier hilo
+    pub(crate) fn wake(&self) -> io::Result<()> {
+        let one: u64 = 1;
+        let result = unsafe {
+            libc::write(
+                self.waker.as_raw_fd(),
+                &one as *const u64 as *const libc::c_void,
+                8,
+            )
+        };
+        match result {
+            // El contador está saturado: ya hay un aviso pendiente
+            -1 if io::Error::last_os_error().kind() == io::ErrorKind::WouldBlock => Ok(()),
+            -1 => Err(io::Error::last_os_error()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Vacía el eventfd tras recibir su evento
+    pub(crate) fn reset_waker(&self) {
+        let mut buf: u64 = 0;
+        unsafe {
+            libc::read(
+                self.waker.as_raw_fd(),
+                &mut buf as *mut u64 as *mut libc::c_void,
+                8,
+            );
+        }
+    }
+}
+
+/// Pone `fd` en modo no bloqueante
+pub(crate) fn set_nonblocking(fd: RawFd) -> io::Result<()> {
+    let flags = cvt(unsafe { libc::fcntl(fd, libc::F_GETFL) })?;
+    if flags & libc::O_NONBLOCK == 0 {
+        cvt(unsafe { libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) })?;
+    }
+    Ok(())
+}
+
+/// Crea una tubería con ambos extremos no bloqueantes
+///
+/// # Retorno
+/// `(lectura, escritura)`
+pub(crate) fn pipe() -> io::Result<(OwnedFd, OwnedFd)> {
+    let mut fds = [0; 2];
+    cvt(unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_NONBLOCK | libc::O_CLOEXEC) })?;
+    // Seguro: los descriptores acaban de crearse y nadie más los posee
+    Ok(unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) })
+}
+
+/// Abre un pidfd del proceso `pid`: se vuelve legible cuando el proceso termina
+///
+/// Necesita Linux 5.3 o posterior.
+pub(crate) fn pidfd_open(pid: u32) -> io::Result<OwnedFd> {
+    let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid as libc::pid_t, 0) };
+    let fd = cvt(fd as libc::c_int)?;
+    // Seguro: el descriptor acaba de crearse y nadie más lo posee
+    Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+}
+
+/// Inicia una conexión TCP no bloqueante hacia `addr`
+///
+/// Devuelve el socket con la conexión en curso (`EINPROGRESS`): quien llama
+/// debe esperar a que sea escribible y comprobar `take_error`.
+pub(crate) fn connect(addr: &SocketAddr) -> io::Result<TcpStream> {
+    let domain = match addr {
+        SocketAddr::V4(_) => libc::AF_INET,
+        SocketAddr::V6(_) => libc::AF_INET6,
+    };
+    let fd = cvt(unsafe {
+        libc::socket(
+            domain,
+            libc::SOCK_STREAM | libc::SOCK_NONBLOCK | libc::SOCK_CLOEXEC,
+            0,
+        )
+    })?;
+    // Seguro: el descriptor acaba de crearse y nadie más lo posee
+    let stream = unsafe { TcpStream::from_raw_fd(fd) };
+
+    let (storage, len) = socket_addr(addr);
+    let result = unsafe {
+        libc::connect(
+            fd,
+            &storage as *const libc::sockaddr_storage as *const libc::sockaddr,
+            len,
+        )
+    };
+    match cvt(result) {
+        Ok(_) => Ok(stream),
+        Err(e) if e.raw_os_error() == Some(libc::EINPROGRESS) => Ok(stream),
+        Err(e) if e.kind() == io::ErrorKind::Interrupted => Ok(stream),
+        Err(e) => Err(e),
+    }
+}
+
+/// Inicia una conexión no bloqueante al socket Unix en `path`
+///
+/// Igual que `connect`: si devuelve la conexión en curso, quien llama
+/// espera a que sea escribible y comprueba `take_error`.
+///
+/// A diferencia de TCP, `EAGAIN` indica que la cola del listener está
+/// llena y que no hay conexión en curso: se devuelve como `WouldBlock` y
+/// quien llama debe reintentar con un socket nuevo.
+pub(crate) fn connect_unix(path: &Path) -> io::Result<UnixStream> {
+    // Seguro: una sockaddr_un a ceros es un valor válido
+    let mut addr: libc::sockaddr_un = unsafe { mem::zeroed() };
+    addr.sun_family = libc::AF_UNIX as libc::sa_family_t;
+
+    // La ruta debe caber en `sun_path` con su terminador nulo
+    let bytes = path.as_os_str().as_bytes();
+    if bytes.len() >= addr.sun_path.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "ruta demasiado larga para un socket Unix",
+        ));
+    }
+    if bytes.contains(&0) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "la ruta de un socket Unix no puede contener bytes nulos",
+        ));
+    }
+    for (dst, &src) in addr.sun_path.iter_mut().zip(bytes) {
+        *dst = src as libc::c_char;
+    }
+    let len = mem::offset_of!(libc::sockaddr_un, sun_path) + bytes.len() + 1;
+
+    let fd = cvt(unsafe {
+        libc::socket(
+            libc::AF_UNIX,
+            libc::SOCK_STREAM | libc::SOCK_NONBLOCK | libc::SOCK_CLOEXEC,
+            0,
+        )
+    })?;
+    // Seguro: el descriptor acaba de crearse y nadie más lo posee
+    let stream = unsafe { UnixStream::from_raw_fd(fd) };
+
+    let result = unsafe {
+        libc::connect(
+            fd,
+            &addr as *const libc::sockaddr_un as *const libc::sockaddr,
+            len as libc::socklen_t,
+        )
+    };
+    match cvt(result) {
+        Ok(_) => Ok(stream),
+        Err(e) if e.raw_os_error() == Some(libc::EINPROGRESS) => Ok(stream),
+        Err(e) if e.kind() == io::ErrorKind::Interrupted => Ok(stream),
+        Err(e) => Err(e),
+    }
+}
+
+/// Convierte una dirección de std a su representación en C
+fn socket_addr(addr: &SocketAddr) -> (libc::sockaddr_storage, libc::socklen_t) {
+    // Seguro: una sockaddr_storage a ceros es un valor válido
+    let mut storage: libc::sockaddr_storage = unsafe { mem::zeroed() };
+
+    let len = match addr {
+        SocketAddr::V4(addr) => {
+            let sin = libc::sockaddr_in {
+                sin_family: libc::AF_INET as libc::sa_family_t,
+                sin_port: addr.port().to_be(),
+                sin_addr: libc::in_addr {
+                    s_addr: u32::from_ne_bytes(addr.ip().octets()),
+                },
+                sin_zero: [0; 8],
+            };
+            // Seguro: sockaddr_storage tiene tamaño y alineación suficientes
+            unsafe { ptr::write(&mut storage as *mut _ as *mut libc::sockaddr_in, sin) };
+            mem::size_of::<libc::sockaddr_in>()
+        }
+        SocketAddr::V6(addr) => {
+            let sin6 = libc::sockaddr_in6 {
+                sin6_family: libc::AF_INET6 as libc::sa_family_t,
+                sin6_port: addr.port().to_be(),
+                sin6_flowinfo: addr.flowinfo(),
+                sin6_addr: libc::in6_addr {
+                    s6_addr: addr.ip().octets(),
+                },
+                sin6_scope_id: addr.scope_id(),
+            };
+            unsafe { ptr::write(&mut storage as *mut _ as *mut libc::sockaddr_in6, sin6) };
+            mem::size_of::<libc::sockaddr_in6>()
+        }
+    };
+
+    (storage, len as libc::socklen_t)
+}
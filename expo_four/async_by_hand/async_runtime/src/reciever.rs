@@ -2,25 +2,56 @@ use std::{
     future::Future,
     io::{self, Read},
     net::TcpStream,
+    os::fd::AsRawFd,
     pin::Pin,
     sync::{Arc, Mutex},
     task::{Context, Poll},
 };
 
+use crate::reactor::{Direction, Registration};
+
 /// Futuro asíncrono para recibir datos de un TcpStream
 ///
 /// Características principales:
 /// - Lee datos de forma no bloqueante
 /// - Acumula datos en un buffer interno
+/// - Espera en el reactor a que lleguen datos en lugar de reintentar en bucle
 /// - Implementa la interfaz Future para integración con ejecutores asíncronos
 ///
 /// Nota: Utiliza un `Arc<Mutex<TcpStream>>` para compartir el socket de forma segura entre tareas.
+/// El mutex solo se toma en el primer poll, para duplicar el descriptor: la E/S
+/// se hace sobre la copia, sin bloquear a otros hilos que usen el mismo socket.
+/// El socket se registra en el reactor mientras el futuro vive, así que no debe
+/// haber otro `TcpReceiver` o `TcpSender` activo a la vez sobre el mismo socket.
 pub struct TcpReceiver {
     /// Socket TCP protegido por mutex para acceso concurrente
     pub stream: Arc<Mutex<TcpStream>>,
 
     /// Buffer acumulador para datos recibidos
     pub buffer: Vec<u8>,
+
+    /// Registro en el reactor y copia del socket; se crean en el primer poll
+    ///
+    /// El registro va primero para retirarse del reactor antes de cerrar la copia.
+    io: Option<(Registration, TcpStream)>,
+}
+
+impl TcpReceiver {
+    /// Crea un receptor con el buffer vacío
+    pub fn new(stream: Arc<Mutex<TcpStream>>) -> Self {
+        TcpReceiver {
+            stream,
+            buffer: Vec::new(),
+            io: None,
+        }
+    }
+}
+
+/// Duplica el socket en modo no bloqueante y registra la copia en el reactor
+fn register(stream: &Mutex<TcpStream>) -> io::Result<(Registration, TcpStream)> {
+    let stream = stream.lock().unwrap().try_clone()?;
+    stream.set_nonblocking(true)?;
+    Ok((Registration::new(stream.as_raw_fd())?, stream))
 }
 
 impl Future for TcpReceiver {
@@ -30,58 +61,40 @@ impl Future for TcpReceiver {
     /// Avanza el estado del futuro en cada poll
     ///
     /// Comportamiento:
-    /// 1. Intenta adquirir el lock del socket
-    /// 2. Configura modo no bloqueante
-    /// 3. Lee datos al buffer local
-    /// 4. Maneja diferentes casos de lectura
+    /// 1. En el primer poll duplica el socket, lo pone en modo no bloqueante y lo registra
+    /// 2. Lee datos al buffer hasta que el socket se vacíe o se cierre
+    /// 3. Maneja diferentes casos de lectura
     ///
     /// Estrategias de reactivación:
-    /// - Siempre reactiva el waker en casos de bloqueo
+    /// - Ante `WouldBlock` el reactor despierta la tarea cuando haya más datos
     /// - Propaga errores inmediatamente
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        // Intenta adquirir el lock del socket (operación no bloqueante)
-        let mut stream = match self.stream.try_lock() {
-            Ok(stream) => stream,
-            Err(_) => {
-                // Si no se puede adquirir el lock, programa nueva reactivación
-                cx.waker().wake_by_ref();
-                return Poll::Pending;
-            }
+        let this = &mut *self;
+        // Configura el socket una sola vez, al registrarlo en el reactor
+        let (registration, stream) = match &mut this.io {
+            Some(io) => io,
+            slot => match register(&this.stream) {
+                Ok(io) => slot.insert(io),
+                Err(e) => return Poll::Ready(Err(e)),
+            },
         };
 
-        // Configura el socket en modo no bloqueante
-        // Nota: Esto podría moverse a la creación para evitar llamadas repetidas
-        if let Err(e) = stream.set_nonblocking(true) {
-            return Poll::Ready(Err(e));
-        }
-
         let mut local_buf = [0; 1024]; // Buffer de lectura temporal
 
-        match stream.read(&mut local_buf) {
-            // Conexión cerrada: Devuelve datos acumulados
-            Ok(0) => Poll::Ready(Ok(self.buffer.to_vec())),
+        loop {
+            match registration.poll_io(cx, Direction::Read, || stream.read(&mut local_buf)) {
+                // Conexión cerrada: Devuelve datos acumulados
+                Poll::Ready(Ok(0)) => return Poll::Ready(Ok(std::mem::take(&mut this.buffer))),
 
-            // Datos recibidos: Acumula y programa nueva reactivación
-            Ok(n) => {
-                // Libera explícitamente el lock antes de modificar el estado
-                std::mem::drop(stream);
+                // Datos recibidos: Acumula y sigue leyendo
+                Poll::Ready(Ok(n)) => this.buffer.extend_from_slice(&local_buf[..n]),
 
-                // Acumula datos en el buffer principal
-                self.buffer.extend_from_slice(&local_buf[..n]);
+                // Error fatal: Termina el futuro
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
 
-                // Programa nueva ejecución para más datos
-                cx.waker().wake_by_ref();
-                Poll::Pending
+                // Sin datos: el reactor despertará la tarea
+                Poll::Pending => return Poll::Pending,
             }
-
-            // Bloqueo temporal: Programa nueva reactivación
-            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
-                cx.waker().wake_by_ref();
-                Poll::Pending
-            }
-
-            // Error fatal: Termina el futuro
-            Err(e) => Poll::Ready(Err(e)),
         }
     }
 }
@@ -0,0 +1,255 @@
+use std::{
+    fmt, io,
+    sync::Arc,
+    thread::{self, available_parallelism},
+    time::Duration,
+};
+
+use super::Runtime;
+use crate::{blocking::BlockingPool, executor::DEFAULT_EVENT_INTERVAL};
+
+/// Función que el runtime llama en los eventos de sus hilos
+pub(crate) type Callback = Arc<dyn Fn() + Send + Sync>;
+
+/// Valor por defecto de `global_queue_interval`
+///
+/// Evita que las tareas inyectadas desde fuera esperen indefinidamente
+/// mientras los workers se alimentan solo de sus colas locales.
+const DEFAULT_GLOBAL_QUEUE_INTERVAL: u32 = 61;
+
+/// Valor por defecto de `max_blocking_threads`
+pub(crate) const DEFAULT_MAX_BLOCKING_THREADS: usize = 512;
+
+/// Valor por defecto de `thread_keep_alive`
+pub(crate) const DEFAULT_KEEP_ALIVE: Duration = Duration::from_secs(10);
+
+/// Configuración de un `Runtime`
+///
+/// Ofrece las mismas opciones que el `Builder` de tokio, de modo que un
+/// servicio se configura igual con uno u otro runtime.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::Builder;
+///
+/// let runtime = Builder::new_multi_thread()
+///     .worker_threads(2)
+///     .thread_name("mi-servicio")
+///     .thread_stack_size(3 * 1024 * 1024)
+///     .max_blocking_threads(4)
+///     .global_queue_interval(31)
+///     .on_thread_start(|| println!("worker arrancando"))
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(runtime.block_on(async { 40 + 2 }), 42);
+/// ```
+pub struct Builder {
+    worker_threads: Option<usize>,
+    max_blocking_threads: usize,
+    thread_keep_alive: Duration,
+    thread: ThreadSpec,
+    global_queue_interval: u32,
+    event_interval: u32,
+    on_thread_park: Option<Callback>,
+    on_thread_unpark: Option<Callback>,
+}
+
+/// Cómo se crean los hilos del runtime, workers y bloqueantes por igual
+#[derive(Clone, Default)]
+pub(crate) struct ThreadSpec {
+    name: Option<String>,
+    stack_size: Option<usize>,
+    on_start: Option<Callback>,
+    on_stop: Option<Callback>,
+}
+
+/// Opciones que los workers consultan mientras el runtime está en marcha
+pub(crate) struct Config {
+    pub(crate) global_queue_interval: u32,
+    pub(crate) event_interval: u32,
+    pub(crate) on_thread_park: Option<Callback>,
+    pub(crate) on_thread_unpark: Option<Callback>,
+}
+
+impl Builder {
+    /// Configuración de un runtime multihilo con los valores por defecto
+    ///
+    /// - Un worker por núcleo disponible
+    /// - Hilos llamados `async_runtime-worker-{índice}`
+    /// - Tamaño de pila por defecto de la plataforma
+    pub fn new_multi_thread() -> Self {
+        Builder {
+            worker_threads: None,
+            max_blocking_threads: DEFAULT_MAX_BLOCKING_THREADS,
+            thread_keep_alive: DEFAULT_KEEP_ALIVE,
+            thread: ThreadSpec::default(),
+            global_queue_interval: DEFAULT_GLOBAL_QUEUE_INTERVAL,
+            event_interval: DEFAULT_EVENT_INTERVAL,
+            on_thread_park: None,
+            on_thread_unpark: None,
+        }
+    }
+
+    /// Número de hilos worker
+    ///
+    /// # Pánico
+    /// Si `count` es cero
+    pub fn worker_threads(&mut self, count: usize) -> &mut Self {
+        assert!(count > 0, "worker_threads debe ser mayor que cero");
+        self.worker_threads = Some(count);
+        self
+    }
+
+    /// Máximo de hilos dedicados a `spawn_blocking`
+    ///
+    /// Los hilos se crean a demanda hasta este límite; por encima, las
+    /// tareas bloqueantes esperan en cola a que uno quede libre.
+    ///
+    /// # Pánico
+    /// Si `count` es cero
+    pub fn max_blocking_threads(&mut self, count: usize) -> &mut Self {
+        assert!(count > 0, "max_blocking_threads debe ser mayor que cero");
+        self.max_blocking_threads = count;
+        self
+    }
+
+    /// Tiempo que un hilo bloqueante sin trabajo espera antes de terminar
+    pub fn thread_keep_alive(&mut self, duration: Duration) -> &mut Self {
+        self.thread_keep_alive = duration;
+        self
+    }
+
+    /// Nombre de todos los hilos del runtime
+    pub fn thread_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.thread.name = Some(name.into());
+        self
+    }
+
+    /// Tamaño de pila, en bytes, de los hilos del runtime
+    pub fn thread_stack_size(&mut self, size: usize) -> &mut Self {
+        self.thread.stack_size = Some(size);
+        self
+    }
+
+    /// Cada cuántos ticks un worker revisa primero la cola global
+    ///
+    /// Valores bajos reparten antes las tareas que llegan desde fuera, a
+    /// cambio de más competencia por el lock de la cola global.
+    ///
+    /// # Pánico
+    /// Si `interval` es cero
+    pub fn global_queue_interval(&mut self, interval: u32) -> &mut Self {
+        assert!(
+            interval > 0,
+            "global_queue_interval debe ser mayor que cero"
+        );
+        self.global_queue_interval = interval;
+        self
+    }
+
+    /// Cada cuántos sondeos un worker ocupado revisa la E/S y los
+    /// temporizadores
+    ///
+    /// Con valores altos los eventos externos esperan más mientras haya
+    /// tareas listas; con valores bajos se hacen más llamadas al sistema.
+    ///
+    /// # Pánico
+    /// Si `polls` es cero
+    pub fn event_interval(&mut self, polls: u32) -> &mut Self {
+        assert!(polls > 0, "event_interval debe ser mayor que cero");
+        self.event_interval = polls;
+        self
+    }
+
+    /// Función que cada hilo ejecuta al arrancar, antes de tomar tareas
+    pub fn on_thread_start<F: Fn() + Send + Sync + 'static>(&mut self, f: F) -> &mut Self {
+        self.thread.on_start = Some(Arc::new(f));
+        self
+    }
+
+    /// Función que cada hilo ejecuta justo antes de terminar
+    pub fn on_thread_stop<F: Fn() + Send + Sync + 'static>(&mut self, f: F) -> &mut Self {
+        self.thread.on_stop = Some(Arc::new(f));
+        self
+    }
+
+    /// Función que un worker ejecuta antes de dormirse por falta de trabajo
+    pub fn on_thread_park<F: Fn() + Send + Sync + 'static>(&mut self, f: F) -> &mut Self {
+        self.on_thread_park = Some(Arc::new(f));
+        self
+    }
+
+    /// Función que un worker ejecuta al despertar
+    pub fn on_thread_unpark<F: Fn() + Send + Sync + 'static>(&mut self, f: F) -> &mut Self {
+        self.on_thread_unpark = Some(Arc::new(f));
+        self
+    }
+
+    /// Crea el runtime y arranca sus workers
+    ///
+    /// # Errores
+    /// Si el sistema no permite crear alguno de los hilos; los que ya
+    /// habían arrancado se detienen antes de devolver el error.
+    pub fn build(&mut self) -> io::Result<Runtime> {
+        let worker_threads = match self.worker_threads {
+            Some(count) => count,
+            None => available_parallelism().map_or(1, |count| count.get()),
+        };
+        let config = Config {
+            global_queue_interval: self.global_queue_interval,
+            event_interval: self.event_interval,
+            on_thread_park: self.on_thread_park.clone(),
+            on_thread_unpark: self.on_thread_unpark.clone(),
+        };
+        let blocking = BlockingPool::new(
+            self.max_blocking_threads,
+            self.thread_keep_alive,
+            self.thread.clone(),
+        );
+        Runtime::start(&self.thread, worker_threads, config, blocking)
+    }
+}
+
+impl ThreadSpec {
+    /// Crea un hilo con el nombre y la pila configurados
+    ///
+    /// Sin nombre configurado usa `default_name`. El hilo ejecuta
+    /// `on_thread_start` antes de `f` y `on_thread_stop` después.
+    pub(crate) fn spawn<F>(&self, default_name: String, f: F) -> io::Result<thread::JoinHandle<()>>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let name = self.name.clone().unwrap_or(default_name);
+        let mut builder = thread::Builder::new().name(name);
+        if let Some(size) = self.stack_size {
+            builder = builder.stack_size(size);
+        }
+
+        let on_start = self.on_start.clone();
+        let on_stop = self.on_stop.clone();
+        builder.spawn(move || {
+            if let Some(on_start) = on_start {
+                on_start();
+            }
+            f();
+            if let Some(on_stop) = on_stop {
+                on_stop();
+            }
+        })
+    }
+}
+
+impl fmt::Debug for Builder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Builder")
+            .field("worker_threads", &self.worker_threads)
+            .field("max_blocking_threads", &self.max_blocking_threads)
+            .field("thread_keep_alive", &self.thread_keep_alive)
+            .field("thread_name", &self.thread.name)
+            .field("thread_stack_size", &self.thread.stack_size)
+            .field("global_queue_interval", &self.global_queue_interval)
+            .field("event_interval", &self.event_interval)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,635 @@
+pub(crate) mod builder;
+mod queue;
+mod task;
+
+use std::{
+    cell::Cell,
+    collections::{HashMap, VecDeque},
+    future::Future,
+    io,
+    pin::pin,
+    sync::{
+        Arc, Condvar, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, Poll},
+    thread::{self, Thread},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    blocking::{self, BlockingPool},
+    context,
+    driver::Handle,
+    metrics::{Dump, RuntimeMetrics},
+    task::{Id, JoinHandle, coop, joinable},
+    waker::{ArcWake, create_waker},
+};
+pub use builder::Builder;
+use builder::{Config, ThreadSpec};
+use queue::LocalQueue;
+use task::Task;
+
+thread_local! {
+    /// Runtime (por dirección de su estado compartido) y worker que ejecuta este hilo
+    static WORKER: Cell<Option<(usize, usize)>> = const { Cell::new(None) };
+}
+
+/// Worker dormido y cómo despertarlo
+struct Sleeper {
+    index: usize,
+    thread: Thread,
+    /// Duerme en el driver (con timeout) en lugar de en `thread::park`
+    on_driver: bool,
+}
+
+/// Estado compartido entre los workers, las tareas y el `Runtime`
+pub(crate) struct Shared {
+    /// Cola global: recibe las tareas creadas o despertadas fuera de los workers
+    injector: Mutex<VecDeque<Arc<Task>>>,
+    /// Una cola local por worker
+    queues: Vec<LocalQueue>,
+    /// Workers dormidos
+    sleepers: Mutex<Vec<Sleeper>>,
+    /// Temporizadores compartidos por todas las tareas del runtime
+    driver: Arc<Handle>,
+    /// Solo un worker a la vez duerme en el driver; el resto usa `thread::park`
+    driver_lock: Mutex<()>,
+    shutdown: AtomicBool,
+    /// Tareas vivas, para descartarlas al apagar el runtime
+    owned: Mutex<HashMap<Id, Arc<Task>>>,
+    /// Workers que ya salieron de su bucle, para `shutdown_timeout`
+    exited: Mutex<usize>,
+    exited_cond: Condvar,
+    config: Config,
+}
+
+impl Shared {
+    /// Encola una tarea lista para ejecutarse
+    ///
+    /// Si se llama desde un worker de este runtime va a su cola local;
+    /// en otro caso va a la cola global. Después despierta a un worker
+    /// dormido para que la tome (o la robe).
+    pub(crate) fn schedule(&self, task: Arc<Task>) {
+        match self.current_worker() {
+            Some(index) => self.queues[index].push(task),
+            None => {
+                let mut injector = self.injector.lock().unwrap();
+                // Tras el apagado ya nadie vaciará la cola: se descarta
+                if self.shutdown.load(Ordering::Acquire) {
+                    return;
+                }
+                injector.push_back(task);
+            }
+        }
+
+        self.notify_one();
+    }
+
+    /// Registra una tarea nueva como viva
+    ///
+    /// # Retorno
+    /// `false` si el runtime ya se apagó: la tarea debe descartarse
+    fn bind(&self, task: &Arc<Task>) -> bool {
+        let mut owned = self.owned.lock().unwrap();
+        if self.shutdown.load(Ordering::Acquire) {
+            return false;
+        }
+        owned.insert(task.id(), task.clone());
+        true
+    }
+
+    /// Olvida una tarea terminada
+    pub(crate) fn release(&self, id: Id) {
+        self.owned.lock().unwrap().remove(&id);
+    }
+
+    /// Índice del worker actual si el hilo pertenece a este runtime
+    fn current_worker(&self) -> Option<usize> {
+        let me = self as *const Shared as usize;
+        match WORKER.with(|worker| worker.get()) {
+            Some((shared, index)) if shared == me => Some(index),
+            _ => None,
+        }
+    }
+
+    /// Despierta a un worker dormido, si lo hay
+    fn notify_one(&self) {
+        let sleeper = self.sleepers.lock().unwrap().pop();
+        match sleeper {
+            Some(sleeper) if sleeper.on_driver => self.driver.unpark(),
+            Some(sleeper) => sleeper.thread.unpark(),
+            None => {}
+        }
+    }
+
+    /// Busca la siguiente tarea para el worker `index`
+    ///
+    /// # Orden de búsqueda
+    /// 1. Cada `global_queue_interval` ticks, la cola global primero
+    /// 2. La cola local
+    /// 3. La cola global
+    /// 4. Robar la mitad de la cola de otro worker
+    fn next_task(&self, index: usize, tick: u32) -> Option<Arc<Task>> {
+        if tick.is_multiple_of(self.config.global_queue_interval)
+            && let Some(task) = self.pop_injector()
+        {
+            return Some(task);
+        }
+
+        self.queues[index]
+            .pop()
+            .or_else(|| self.pop_injector())
+            .or_else(|| self.steal(index))
+    }
+
+    fn pop_injector(&self) -> Option<Arc<Task>> {
+        self.injector.lock().unwrap().pop_front()
+    }
+
+    /// Recorre a los demás workers empezando por el siguiente al actual
+    fn steal(&self, index: usize) -> Option<Arc<Task>> {
+        let workers = self.queues.len();
+        (1..workers)
+            .map(|offset| (index + offset) % workers)
+            .find_map(|victim| self.queues[victim].steal_into(&self.queues[index]))
+    }
+
+    fn has_work(&self) -> bool {
+        !self.injector.lock().unwrap().is_empty() || self.queues.iter().any(|q| !q.is_empty())
+    }
+
+    /// Duerme al worker `index` hasta que llegue trabajo
+    ///
+    /// El primer worker que se duerme toma el driver y duerme en él hasta
+    /// el próximo temporizador; los demás duermen sin límite de tiempo.
+    ///
+    /// El worker se registra como dormido antes de volver a revisar las
+    /// colas: si una tarea llega entre la revisión y `park`, el `unpark`
+    /// correspondiente hace que `park` regrese de inmediato.
+    fn park(&self, index: usize) {
+        let driver = self.driver_lock.try_lock().ok();
+
+        self.sleepers.lock().unwrap().push(Sleeper {
+            index,
+            thread: thread::current(),
+            on_driver: driver.is_some(),
+        });
+
+        if !self.has_work() && !self.shutdown.load(Ordering::Acquire) {
+            if let Some(on_park) = &self.config.on_thread_park {
+                on_park();
+            }
+            match driver {
+                Some(_) => self.driver.park(),
+                None => thread::park(),
+            }
+            if let Some(on_unpark) = &self.config.on_thread_unpark {
+                on_unpark();
+            }
+        }
+
+        self.sleepers
+            .lock()
+            .unwrap()
+            .retain(|sleeper| sleeper.index != index);
+    }
+
+    /// Procesa los temporizadores vencidos si ningún otro worker lo hace
+    fn maintain(&self) {
+        if let Ok(_driver) = self.driver_lock.try_lock() {
+            self.driver.poll_events();
+        }
+    }
+}
+
+/// Bucle principal de cada worker
+fn run_worker(shared: Arc<Shared>, index: usize, blocking: BlockingPool) {
+    let me = Arc::as_ptr(&shared) as usize;
+    WORKER.with(|worker| worker.set(Some((me, index))));
+    let _enter = context::enter(shared.driver.clone());
+    let _blocking = blocking::enter(blocking);
+
+    let mut tick: u32 = 0;
+    while !shared.shutdown.load(Ordering::Acquire) {
+        tick = tick.wrapping_add(1);
+
+        // Sin esta revisión la E/S y los temporizadores solo se procesarían
+        // cuando algún worker se quedara sin trabajo
+        if tick.is_multiple_of(shared.config.event_interval) {
+            shared.maintain();
+        }
+
+        match shared.next_task(index, tick) {
+            Some(task) => task.run(),
+            None => shared.park(index),
+        }
+    }
+
+    WORKER.with(|worker| worker.set(None));
+    *shared.exited.lock().unwrap() += 1;
+    shared.exited_cond.notify_all();
+}
+
+/// Waker del futuro de `Runtime::block_on`: despierta al hilo que lo espera
+struct ThreadWaker {
+    thread: Thread,
+    woken: AtomicBool,
+}
+
+impl ArcWake for ThreadWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.woken.store(true, Ordering::Release);
+        arc_self.thread.unpark();
+    }
+}
+
+/// Ejecutor multihilo con robo de trabajo
+///
+/// Arquitectura:
+/// - `worker_threads` hilos, cada uno con su propia cola local
+/// - Una cola global para las tareas que llegan desde fuera de los workers
+/// - Un worker sin trabajo roba la mitad de la cola de otro y, si no
+///   encuentra nada, se duerme hasta que se encole una tarea nueva
+/// - Un driver compartido con los temporizadores de todas las tareas
+///
+/// Se crea con `Runtime::new` o, para ajustar más opciones, con `Builder`.
+pub struct Runtime {
+    shared: Arc<Shared>,
+    workers: Vec<thread::JoinHandle<()>>,
+    /// Hilos de `spawn_blocking` de las tareas de este runtime
+    blocking: BlockingPool,
+}
+
+impl Runtime {
+    /// Crea el runtime con los valores por defecto de `Builder`
+    ///
+    /// # Parámetros
+    /// - `worker_threads`: Número de hilos worker (al menos uno)
+    ///
+    /// # Errores
+    /// Si el sistema no permite crear alguno de los hilos (ver `Builder::build`)
+    ///
+    /// # Pánico
+    /// Si `worker_threads` es cero
+    pub fn new(worker_threads: usize) -> io::Result<Self> {
+        Builder::new_multi_thread()
+            .worker_threads(worker_threads)
+            .build()
+    }
+
+    /// Arranca `worker_threads` workers creados según `spec`
+    fn start(
+        spec: &ThreadSpec,
+        worker_threads: usize,
+        config: Config,
+        blocking: BlockingPool,
+    ) -> io::Result<Self> {
+        let shared = Arc::new(Shared {
+            injector: Mutex::new(VecDeque::new()),
+            queues: (0..worker_threads).map(|_| LocalQueue::new()).collect(),
+            sleepers: Mutex::new(Vec::new()),
+            driver: Arc::new(Handle::new()),
+            driver_lock: Mutex::new(()),
+            shutdown: AtomicBool::new(false),
+            owned: Mutex::new(HashMap::new()),
+            exited: Mutex::new(0),
+            exited_cond: Condvar::new(),
+            config,
+        });
+
+        // Si un hilo no arranca, soltar `runtime` detiene a los anteriores
+        let mut runtime = Runtime {
+            shared,
+            workers: Vec::with_capacity(worker_threads),
+            blocking,
+        };
+        for index in 0..worker_threads {
+            let shared = runtime.shared.clone();
+            let blocking = runtime.blocking.clone();
+            let worker = spec.spawn(format!("async_runtime-worker-{}", index), move || {
+                run_worker(shared, index, blocking)
+            })?;
+            runtime.workers.push(worker);
+        }
+
+        Ok(runtime)
+    }
+
+    /// Añade una nueva tarea al runtime
+    ///
+    /// # Parámetros
+    /// - `future`: Futuro a ejecutar que produce un resultado de tipo `T`
+    ///
+    /// # Retorno
+    /// `JoinHandle<T>`: Manejador para recuperar el resultado asíncrono
+    pub fn spawn<F, T>(&self, future: F) -> JoinHandle<T>
+    where
+        F: Future<Output = T> + 'static + Send,
+        T: Send + 'static,
+    {
+        self.spawn_inner(future, None)
+    }
+
+    /// Como `spawn`, pero la tarea lleva un nombre
+    pub fn spawn_named<F, T>(&self, name: impl Into<String>, future: F) -> JoinHandle<T>
+    where
+        F: Future<Output = T> + 'static + Send,
+        T: Send + 'static,
+    {
+        self.spawn_inner(future, Some(name.into()))
+    }
+
+    pub(crate) fn spawn_inner<F, T>(&self, future: F, name: Option<String>) -> JoinHandle<T>
+    where
+        F: Future<Output = T> + 'static + Send,
+        T: Send + 'static,
+    {
+        let (wrapped_future, handle) = joinable(future, name);
+        let task = Task::new(handle.info().clone(), wrapped_future, self.shared.clone());
+        // Tras el apagado la tarea se suelta y su handle recibe `Cancelled`
+        if self.shared.bind(&task) {
+            self.shared.schedule(task);
+        }
+        handle
+    }
+
+    /// Ejecuta `future` en el hilo actual hasta completarse
+    ///
+    /// Las tareas lanzadas con `spawn` siguen corriendo en los workers
+    /// mientras tanto; el futuro puede usar temporizadores y sockets del
+    /// runtime, cuyos eventos procesan los workers.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        let mut future = pin!(future);
+        let _enter = context::enter(self.shared.driver.clone());
+        let _blocking = blocking::enter(self.blocking.clone());
+
+        let root = Arc::new(ThreadWaker {
+            thread: thread::current(),
+            woken: AtomicBool::new(true),
+        });
+        let waker = create_waker(root.clone());
+        let context = &mut Context::from_waker(&waker);
+
+        loop {
+            if root.woken.swap(false, Ordering::AcqRel)
+                && let Poll::Ready(output) = coop::budget(|| future.as_mut().poll(context))
+            {
+                return output;
+            }
+            if !root.woken.load(Ordering::Acquire) {
+                thread::park();
+            }
+        }
+    }
+
+    /// Recuento de las tareas vivas según su estado
+    pub fn metrics(&self) -> RuntimeMetrics {
+        RuntimeMetrics::new(self.shared.queues.len(), self.dump().tasks())
+    }
+
+    /// Lista las tareas vivas con su nombre, estado y contadores
+    ///
+    /// Sirve para encontrar la tarea que acapara un worker: la que tiene
+    /// un `max_poll` alto o lleva mucho tiempo `Running`. Las tareas de
+    /// `spawn_blocking` no aparecen.
+    pub fn dump(&self) -> Dump {
+        let owned = self.shared.owned.lock().unwrap();
+        Dump::new(owned.values().filter_map(|task| task.snapshot()).collect())
+    }
+
+    /// Detiene el runtime esperando como mucho `timeout` a sus hilos
+    ///
+    /// Un worker ocupado en un sondeo que no termina a tiempo se abandona:
+    /// su hilo sigue vivo hasta acabar ese sondeo, pero ya no toma tareas.
+    /// Lo mismo vale para las tareas bloqueantes en curso. Las tareas
+    /// pendientes se descartan.
+    pub fn shutdown_timeout(mut self, timeout: Duration) {
+        self.close(Some(Instant::now() + timeout));
+    }
+
+    /// Detiene el runtime de forma ordenada
+    ///
+    /// 1. Deja de aceptar tareas: las que se lancen desde fuera a partir
+    ///    de ahora se descartan
+    /// 2. Cada worker termina el sondeo en curso y sale de su bucle
+    /// 3. Se descartan las tareas en cola y, al vaciar el driver, las que
+    ///    esperaban E/S o temporizadores; sus `JoinHandle` reciben
+    ///    `JoinError::Cancelled`
+    /// 4. Espera a que terminen las tareas de `spawn_blocking` en curso
+    ///
+    /// Es lo mismo que ocurre al soltar el runtime, pero explícito. Para
+    /// dar un margen a las tareas antes de descartarlas, espéralas primero
+    /// (por ejemplo con `time::timeout`) o usa `shutdown_timeout`.
+    pub fn shutdown(mut self) {
+        self.close(None);
+    }
+
+    /// Señala el apagado, espera a los workers y libera las tareas
+    ///
+    /// Sin `deadline` espera a todos los hilos el tiempo que haga falta.
+    fn close(&mut self, deadline: Option<Instant>) {
+        {
+            let _injector = self.shared.injector.lock().unwrap();
+            // `shutdown_timeout` ya lo detuvo: el `drop` posterior no espera más
+            if self.shared.shutdown.swap(true, Ordering::AcqRel) {
+                return;
+            }
+        }
+
+        self.shared.driver.unpark();
+        for worker in &self.workers {
+            worker.thread().unpark();
+        }
+
+        if let Some(deadline) = deadline {
+            let mut exited = self.shared.exited.lock().unwrap();
+            while *exited < self.workers.len() {
+                let now = Instant::now();
+                if now >= deadline {
+                    break;
+                }
+                exited = self
+                    .shared
+                    .exited_cond
+                    .wait_timeout(exited, deadline - now)
+                    .unwrap()
+                    .0;
+            }
+        }
+        for worker in self.workers.drain(..) {
+            // Tras el plazo solo se recogen los hilos que ya terminaron
+            if deadline.is_none() || worker.is_finished() {
+                let _ = worker.join();
+            }
+        }
+
+        // Las tareas encoladas referencian a `Shared`: se liberan para romper el ciclo
+        let injected = std::mem::take(&mut *self.shared.injector.lock().unwrap());
+        drop(injected);
+        for queue in &self.shared.queues {
+            queue.clear();
+        }
+        // Las que esperaban un waker se descartan desde el registro
+        let owned = std::mem::take(&mut *self.shared.owned.lock().unwrap());
+        for task in owned.values() {
+            task.shutdown();
+        }
+        drop(owned);
+        self.shared.driver.shutdown();
+        self.blocking.shutdown(deadline);
+    }
+}
+
+impl Drop for Runtime {
+    /// Detiene los workers y descarta las tareas que seguían encoladas
+    fn drop(&mut self) {
+        self.close(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Builder, Runtime, Task};
+    use crate::{task::TaskInfo, time::sleep, waker::create_waker};
+    use std::{
+        future::Future,
+        pin::Pin,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+            mpsc,
+        },
+        task::{Context, Poll},
+        thread,
+        time::Duration,
+    };
+
+    /// Futuro que se despierta a sí mismo `remaining` veces antes de terminar
+    struct YieldTimes {
+        remaining: u32,
+    }
+
+    impl Future for YieldTimes {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.remaining == 0 {
+                return Poll::Ready(());
+            }
+            self.remaining -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn runs_tasks_on_all_workers() {
+        let runtime = Runtime::new(4).unwrap();
+
+        let handles: Vec<_> = (0..200u32)
+            .map(|i| {
+                runtime.spawn(async move {
+                    YieldTimes { remaining: i % 7 }.await;
+                    i * 2
+                })
+            })
+            .collect();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.block_on().unwrap(), i as u32 * 2);
+        }
+    }
+
+    #[test]
+    fn builder_applies_thread_options() {
+        let started = Arc::new(AtomicUsize::new(0));
+        let stopped = Arc::new(AtomicUsize::new(0));
+        let parked = Arc::new(AtomicUsize::new(0));
+
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(2)
+            .thread_name("prueba")
+            .on_thread_start({
+                let started = started.clone();
+                move || {
+                    started.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+            .on_thread_stop({
+                let stopped = stopped.clone();
+                move || {
+                    stopped.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+            .on_thread_park({
+                let parked = parked.clone();
+                move || {
+                    parked.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+            .build()
+            .unwrap();
+
+        let name = runtime
+            .spawn(async { thread::current().name().map(String::from) })
+            .block_on()
+            .unwrap();
+        assert_eq!(name.as_deref(), Some("prueba"));
+
+        // `block_on` corre en este hilo, con los temporizadores del runtime
+        runtime.block_on(sleep(Duration::from_millis(20)));
+        assert!(parked.load(Ordering::SeqCst) > 0);
+
+        drop(runtime);
+        assert_eq!(started.load(Ordering::SeqCst), 2);
+        assert_eq!(stopped.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn shutdown_timeout_abandons_stuck_workers() {
+        let runtime = Runtime::new(1).unwrap();
+        let (entered_tx, entered_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        runtime.spawn(async move {
+            entered_tx.send(()).unwrap();
+            let _ = release_rx.recv();
+        });
+        // El worker ya está bloqueado dentro del sondeo
+        entered_rx.recv().unwrap();
+
+        // Regresa aunque el worker no puede terminar hasta que se le libere
+        runtime.shutdown_timeout(Duration::from_millis(50));
+        release_tx.send(()).unwrap();
+    }
+
+    #[test]
+    fn shutdown_cancels_pending_tasks() {
+        let runtime = Runtime::new(2).unwrap();
+        let sleeping = runtime.spawn(sleep(Duration::from_secs(60)));
+        let done = runtime.spawn(async { 1 });
+        assert_eq!(done.block_on().unwrap(), 1);
+
+        runtime.shutdown();
+        assert!(sleeping.block_on().unwrap_err().is_cancelled());
+    }
+
+    #[test]
+    fn task_run_after_shutdown_is_not_left_running() {
+        let runtime = Runtime::new(1).unwrap();
+        let task = Task::new(
+            TaskInfo::new(None),
+            Box::pin(async {}),
+            runtime.shared.clone(),
+        );
+
+        // Un worker la sacó de la cola justo cuando el apagado soltó el futuro
+        task.shutdown();
+        task.clone().run();
+        assert!(task.snapshot().is_none());
+
+        // Un wake posterior tampoco la resucita
+        create_waker(task.clone()).wake_by_ref();
+        assert!(task.snapshot().is_none());
+    }
+}
@@ -0,0 +1,65 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use super::task::Task;
+
+/// Cola local de un worker
+///
+/// El dueño encola por detrás y desencola por delante (FIFO). Los demás
+/// workers roban por detrás, así el dueño y los ladrones compiten lo menos
+/// posible por las mismas tareas.
+pub(crate) struct LocalQueue {
+    tasks: Mutex<VecDeque<Arc<Task>>>,
+}
+
+impl LocalQueue {
+    pub(crate) fn new() -> Self {
+        LocalQueue {
+            tasks: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub(crate) fn push(&self, task: Arc<Task>) {
+        self.tasks.lock().unwrap().push_back(task);
+    }
+
+    pub(crate) fn pop(&self) -> Option<Arc<Task>> {
+        self.tasks.lock().unwrap().pop_front()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.tasks.lock().unwrap().is_empty()
+    }
+
+    /// Descarta todas las tareas encoladas
+    pub(crate) fn clear(&self) {
+        // Se sacan antes de liberarlas: soltar un futuro puede despertar otras tareas
+        let tasks = std::mem::take(&mut *self.tasks.lock().unwrap());
+        drop(tasks);
+    }
+
+    /// Roba la mitad de las tareas de esta cola y las mueve a `dest`
+    ///
+    /// # Retorno
+    /// Una de las tareas robadas para ejecutarla de inmediato, o `None` si
+    /// la cola estaba vacía u ocupada por otro hilo.
+    pub(crate) fn steal_into(&self, dest: &LocalQueue) -> Option<Arc<Task>> {
+        // Si la cola está ocupada se prueba con otra víctima
+        let mut src = self.tasks.try_lock().ok()?;
+
+        // Mitad redondeada hacia arriba, para poder robar la última tarea
+        let count = src.len() - src.len() / 2;
+        if count == 0 {
+            return None;
+        }
+        let split = src.len() - count;
+        let mut stolen = src.split_off(split);
+        drop(src);
+
+        let first = stolen.pop_front();
+        dest.tasks.lock().unwrap().extend(stolen);
+        first
+    }
+}
@@ -0,0 +1,157 @@
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU8, Ordering},
+    },
+    task::{Context, Poll},
+};
+
+use super::Shared;
+use crate::{
+    executor::BoxFuture,
+    metrics::{TaskSnapshot, TaskState},
+    task::{Id, TaskInfo, coop},
+    waker::{ArcWake, create_waker},
+};
+
+// Estados por los que pasa una tarea del runtime multihilo
+/// En espera: nadie la tiene encolada, solo la referencian sus wakers
+const IDLE: u8 = 0;
+/// Encolada en alguna cola (local o global)
+const SCHEDULED: u8 = 1;
+/// Un worker la está sondeando
+const RUNNING: u8 = 2;
+/// Despertada mientras se sondeaba: hay que reencolarla al terminar
+const NOTIFIED: u8 = 3;
+/// Terminada: el futuro ya fue liberado
+const COMPLETE: u8 = 4;
+
+/// Tarea del runtime multihilo
+///
+/// A diferencia de las tareas de `Executor`, que viven en un único hilo,
+/// estas se mueven entre workers, por eso se comparten mediante `Arc` y el
+/// futuro queda protegido por un `Mutex`. El propio `Arc<Task>` hace de
+/// estado del waker.
+pub(crate) struct Task {
+    /// Identificador (clave en el registro de tareas vivas), nombre y contadores
+    info: TaskInfo,
+    future: Mutex<Option<BoxFuture>>,
+    state: AtomicU8,
+    shared: Arc<Shared>,
+}
+
+impl Task {
+    /// Crea una tarea lista para su primer sondeo
+    pub(crate) fn new(info: TaskInfo, future: BoxFuture, shared: Arc<Shared>) -> Arc<Self> {
+        Arc::new(Task {
+            info,
+            future: Mutex::new(Some(future)),
+            state: AtomicU8::new(SCHEDULED),
+            shared,
+        })
+    }
+
+    pub(crate) fn id(&self) -> Id {
+        self.info.id()
+    }
+
+    /// Foto de la tarea para `Runtime::dump`; `None` si ya terminó
+    pub(crate) fn snapshot(&self) -> Option<TaskSnapshot> {
+        let state = match self.state.load(Ordering::Acquire) {
+            IDLE => TaskState::Idle,
+            SCHEDULED => TaskState::Ready,
+            RUNNING | NOTIFIED => TaskState::Running,
+            _ => return None,
+        };
+        Some(TaskSnapshot::new(&self.info, state))
+    }
+
+    /// Descarta el futuro de una tarea que no llegó a terminar
+    ///
+    /// Se usa al apagar el runtime. Soltar el futuro publica
+    /// `JoinError::Cancelled` y rompe el ciclo entre la tarea y los wakers
+    /// que guarda. Si un worker abandonado aún la está sondeando, se deja.
+    pub(crate) fn shutdown(&self) {
+        let future = match self.future.try_lock() {
+            Ok(mut slot) => slot.take(),
+            Err(_) => return,
+        };
+        self.state.store(COMPLETE, Ordering::Release);
+        drop(future);
+    }
+
+    /// Sondea la tarea una vez
+    ///
+    /// # Comportamiento
+    /// 1. Si el apagado ya descartó el futuro, la deja como terminada
+    /// 2. Marca la tarea como en ejecución
+    /// 3. Sondea el futuro con un waker que apunta a la propia tarea
+    /// 4. Si termina, libera el futuro
+    /// 5. Si queda pendiente pasa a espera, salvo que la hayan despertado
+    ///    durante el sondeo, en cuyo caso se vuelve a encolar
+    pub(crate) fn run(self: Arc<Self>) {
+        let mut slot = self.future.lock().unwrap();
+        let future = match slot.as_mut() {
+            Some(future) => future,
+            None => {
+                self.state.store(COMPLETE, Ordering::Release);
+                return;
+            }
+        };
+        self.state.store(RUNNING, Ordering::Release);
+
+        let waker = create_waker(self.clone());
+        let context = &mut Context::from_waker(&waker);
+
+        match coop::budget(|| future.as_mut().poll(context)) {
+            Poll::Ready(()) => {
+                *slot = None;
+                self.state.store(COMPLETE, Ordering::Release);
+                drop(slot);
+                self.shared.release(self.id());
+            }
+            Poll::Pending => {
+                drop(slot);
+                if self
+                    .state
+                    .compare_exchange(RUNNING, IDLE, Ordering::AcqRel, Ordering::Acquire)
+                    .is_err()
+                {
+                    // Fue despertada durante el sondeo
+                    self.state.store(SCHEDULED, Ordering::Release);
+                    self.shared.schedule(self.clone());
+                }
+            }
+        }
+    }
+}
+
+impl ArcWake for Task {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.info.stats().record_wake();
+        let mut current = arc_self.state.load(Ordering::Acquire);
+        loop {
+            let next = match current {
+                IDLE => SCHEDULED,
+                RUNNING => NOTIFIED,
+                // Ya encolada, ya notificada o terminada: nada que hacer
+                _ => return,
+            };
+
+            match arc_self.state.compare_exchange(
+                current,
+                next,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    if next == SCHEDULED {
+                        arc_self.shared.schedule(arc_self.clone());
+                    }
+                    return;
+                }
+                Err(actual) => current = actual,
+            }
+        }
+    }
+}
@@ -2,25 +2,60 @@ use std::{
     future::Future,
     io::{self, Write},
     net::TcpStream,
+    os::fd::AsRawFd,
     pin::Pin,
     sync::{Arc, Mutex},
     task::{Context, Poll},
 };
 
+use crate::reactor::{Direction, Registration};
+
 /// Futuro asíncrono para enviar datos a través de un TcpStream
 ///
 /// Características:
 /// - Envía datos de forma no bloqueante
-/// - Maneja automáticamente situaciones de bloqueo
+/// - Lleva la cuenta de lo ya enviado y reanuda desde ahí tras una escritura parcial
+/// - Espera en el reactor a que el socket admita más datos
 /// - Implementa la interfaz Future para integración con ejecutores asíncronos
 ///
 /// Nota: Utiliza un `Arc<Mutex<TcpStream>>` para compartir el socket de forma segura entre tareas.
+/// El mutex solo se toma en el primer poll, para duplicar el descriptor: la E/S
+/// se hace sobre la copia, sin bloquear a otros hilos que usen el mismo socket.
+/// El socket se registra en el reactor mientras el futuro vive, así que no debe
+/// haber otro `TcpReceiver` o `TcpSender` activo a la vez sobre el mismo socket.
 pub struct TcpSender {
     /// Socket TCP protegido por mutex para acceso concurrente
     pub stream: Arc<Mutex<TcpStream>>,
 
     /// Datos a enviar (buffer completo)
     pub buffer: Vec<u8>,
+
+    /// Bytes de `buffer` ya entregados al socket
+    written: usize,
+
+    /// Registro en el reactor y copia del socket; se crean en el primer poll
+    ///
+    /// El registro va primero para retirarse del reactor antes de cerrar la copia.
+    io: Option<(Registration, TcpStream)>,
+}
+
+impl TcpSender {
+    /// Crea un emisor que enviará `buffer` completo
+    pub fn new(stream: Arc<Mutex<TcpStream>>, buffer: Vec<u8>) -> Self {
+        TcpSender {
+            stream,
+            buffer,
+            written: 0,
+            io: None,
+        }
+    }
+}
+
+/// Duplica el socket en modo no bloqueante y registra la copia en el reactor
+fn register(stream: &Mutex<TcpStream>) -> io::Result<(Registration, TcpStream)> {
+    let stream = stream.lock().unwrap().try_clone()?;
+    stream.set_nonblocking(true)?;
+    Ok((Registration::new(stream.as_raw_fd())?, stream))
 }
 
 impl Future for TcpSender {
@@ -30,44 +65,105 @@ impl Future for TcpSender {
     /// Avanza el estado del futuro en cada poll
     ///
     /// Comportamiento:
-    /// 1. Intenta adquirir el lock del socket
-    /// 2. Configura modo no bloqueante
-    /// 3. Intenta enviar todos los datos
-    /// 4. Maneja diferentes casos de escritura
+    /// 1. En el primer poll duplica el socket, lo pone en modo no bloqueante y lo registra
+    /// 2. Escribe desde el cursor `written` hasta vaciar el buffer
+    /// 3. Maneja diferentes casos de escritura
     ///
     /// Estrategias de reactivación:
-    /// - Reactiva el waker en casos de bloqueo temporal
+    /// - Ante `WouldBlock` el reactor despierta la tarea cuando el socket sea escribible,
+    ///   y el siguiente poll continúa donde se quedó sin reenviar bytes
+    /// - Reintenta de inmediato ante `Interrupted`
     /// - Finaliza inmediatamente en éxito o error fatal
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        // Intenta adquirir el lock del socket (operación no bloqueante)
-        let mut stream = match self.stream.try_lock() {
-            Ok(stream) => stream,
-            Err(_) => {
-                // Si el lock está ocupado, programa nueva reactivación
-                cx.waker().wake_by_ref();
-                return Poll::Pending;
-            }
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        // Configura el socket una sola vez, al registrarlo en el reactor
+        let (registration, stream) = match &mut this.io {
+            Some(io) => io,
+            slot => match register(&this.stream) {
+                Ok(io) => slot.insert(io),
+                Err(e) => return Poll::Ready(Err(e)),
+            },
         };
 
-        // Configura el socket en modo no bloqueante
-        // Nota: Esto podría optimizarse haciéndolo una sola vez
-        if let Err(e) = stream.set_nonblocking(true) {
-            return Poll::Ready(Err(e));
+        while this.written < this.buffer.len() {
+            let remaining = &this.buffer[this.written..];
+
+            match registration.poll_io(cx, Direction::Write, || stream.write(remaining)) {
+                // El socket no acepta más datos: no se avanzaría nunca
+                Poll::Ready(Ok(0)) => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+
+                // Escritura (posiblemente parcial): avanza el cursor
+                Poll::Ready(Ok(n)) => this.written += n,
+
+                // Interrumpida por una señal antes de escribir nada: reintenta
+                Poll::Ready(Err(e)) if e.kind() == io::ErrorKind::Interrupted => {}
+
+                // Error fatal: termina el futuro
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+
+                // Socket lleno: el reactor despertará la tarea
+                Poll::Pending => return Poll::Pending,
+            }
         }
 
-        // Intenta enviar TODO el buffer en una operación
-        match stream.write_all(&self.buffer) {
-            // Éxito: todos los datos enviados
-            Ok(_) => Poll::Ready(Ok(())),
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TcpSender;
+    use crate::block_on;
+    use std::{
+        io::Read,
+        net::{TcpListener, TcpStream},
+        os::fd::AsRawFd,
+        sync::{Arc, Mutex},
+        thread,
+        time::Duration,
+    };
 
-            // Bloqueo temporal: programa nueva reactivación
-            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
-                cx.waker().wake_by_ref();
-                Poll::Pending
+    /// Reduce el buffer de envío para forzar escrituras parciales
+    fn set_send_buffer(stream: &TcpStream, size: libc::c_int) {
+        let result = unsafe {
+            libc::setsockopt(
+                stream.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_SNDBUF,
+                &size as *const libc::c_int as *const libc::c_void,
+                std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+            )
+        };
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn resumes_after_partial_writes() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let data: Vec<u8> = (0..256 * 1024).map(|i| (i % 251) as u8).collect();
+
+        // Lector lento: el emisor se topará con `WouldBlock` muchas veces
+        let peer = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut received = Vec::new();
+            let mut buf = [0; 4096];
+            loop {
+                match stream.read(&mut buf).unwrap() {
+                    0 => return received,
+                    n => received.extend_from_slice(&buf[..n]),
+                }
+                thread::sleep(Duration::from_micros(200));
             }
+        });
 
-            // Error fatal: termina el futuro
-            Err(e) => Poll::Ready(Err(e)),
-        }
+        let stream = TcpStream::connect(addr).unwrap();
+        set_send_buffer(&stream, 4096);
+        let stream = Arc::new(Mutex::new(stream));
+
+        block_on(TcpSender::new(stream.clone(), data.clone())).unwrap();
+        drop(stream);
+
+        assert!(peer.join().unwrap() == data);
     }
 }
@@ -0,0 +1,381 @@
+use std::{
+    fmt, io, mem,
+    os::fd::{AsRawFd, IntoRawFd, OwnedFd, RawFd},
+    pin::Pin,
+    ptr,
+    sync::{
+        Mutex, OnceLock,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::{Context, Poll},
+};
+
+use crate::{
+    reactor::{AsyncFd, sys},
+    stream::Stream,
+    sync::watch,
+};
+
+/// Número de señales que se pueden escuchar, incluidas las de tiempo real
+const MAX_SIGNALS: usize = 65;
+
+/// Tipo de señal Unix que se puede escuchar con `signal`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignalKind(libc::c_int);
+
+impl SignalKind {
+    /// SIGINT: Ctrl+C en la terminal
+    pub const fn interrupt() -> Self {
+        SignalKind(libc::SIGINT)
+    }
+
+    /// SIGTERM: petición de terminar, la que envían `kill` y los gestores de servicios
+    pub const fn terminate() -> Self {
+        SignalKind(libc::SIGTERM)
+    }
+
+    /// SIGHUP: se cerró la terminal; por convención, también "recarga la configuración"
+    pub const fn hangup() -> Self {
+        SignalKind(libc::SIGHUP)
+    }
+
+    /// SIGQUIT: Ctrl+\ en la terminal
+    pub const fn quit() -> Self {
+        SignalKind(libc::SIGQUIT)
+    }
+
+    pub const fn user_defined1() -> Self {
+        SignalKind(libc::SIGUSR1)
+    }
+
+    pub const fn user_defined2() -> Self {
+        SignalKind(libc::SIGUSR2)
+    }
+
+    /// SIGCHLD: un proceso hijo terminó o se detuvo
+    pub const fn child() -> Self {
+        SignalKind(libc::SIGCHLD)
+    }
+
+    /// SIGWINCH: cambió el tamaño de la terminal
+    pub const fn window_change() -> Self {
+        SignalKind(libc::SIGWINCH)
+    }
+
+    /// Cualquier otra señal, por su número
+    pub const fn from_raw(signum: libc::c_int) -> Self {
+        SignalKind(signum)
+    }
+
+    pub const fn as_raw(&self) -> libc::c_int {
+        self.0
+    }
+}
+
+impl fmt::Display for SignalKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self.0 {
+            libc::SIGINT => "SIGINT",
+            libc::SIGTERM => "SIGTERM",
+            libc::SIGHUP => "SIGHUP",
+            libc::SIGQUIT => "SIGQUIT",
+            libc::SIGUSR1 => "SIGUSR1",
+            libc::SIGUSR2 => "SIGUSR2",
+            libc::SIGCHLD => "SIGCHLD",
+            libc::SIGWINCH => "SIGWINCH",
+            signum => return write!(f, "señal {}", signum),
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Estado compartido con el manejador de señales
+///
+/// El manejador solo puede hacer operaciones seguras en un contexto de
+/// señal: incrementa un contador atómico y escribe un byte en la tubería.
+/// El contador es lo que cuenta; el byte solo sirve para que algún reactor
+/// despierte y reparta los contadores nuevos (ver `broadcast`).
+struct Globals {
+    /// Entregas de cada señal desde que se instaló su manejador
+    counters: [AtomicU64; MAX_SIGNALS],
+    /// Último valor repartido de cada contador a los `Signal`
+    watches: [watch::Sender<u64>; MAX_SIGNALS],
+    /// Extremo de lectura; cada `Signal` registra una copia en su reactor
+    receiver: OwnedFd,
+    /// Extremo de escritura, para el manejador; nunca se cierra
+    sender: RawFd,
+    /// Señales con el manejador ya instalado
+    installed: Mutex<[bool; MAX_SIGNALS]>,
+}
+
+static GLOBALS: OnceLock<io::Result<Globals>> = OnceLock::new();
+
+fn globals() -> io::Result<&'static Globals> {
+    let globals = GLOBALS.get_or_init(|| {
+        let (receiver, sender) = sys::pipe()?;
+        Ok(Globals {
+            counters: [const { AtomicU64::new(0) }; MAX_SIGNALS],
+            watches: std::array::from_fn(|_| watch::channel(0).0),
+            receiver,
+            sender: sender.into_raw_fd(),
+            installed: Mutex::new([false; MAX_SIGNALS]),
+        })
+    });
+    globals
+        .as_ref()
+        .map_err(|e| io::Error::new(e.kind(), e.to_string()))
+}
+
+impl Globals {
+    /// Vacía la tubería y avisa a quienes escuchan las señales entregadas
+    ///
+    /// Lo hace el primer reactor que ve la tubería lista, sea cual sea: el
+    /// aviso llega a todos los `Signal` por su canal, también a los de
+    /// otros reactores que ya no encontrarán bytes que leer.
+    fn broadcast(&self) {
+        let mut buf = [0u8; 64];
+        while unsafe {
+            libc::read(
+                self.receiver.as_raw_fd(),
+                buf.as_mut_ptr() as *mut libc::c_void,
+                buf.len(),
+            )
+        } > 0
+        {}
+
+        for (counter, watch) in self.counters.iter().zip(&self.watches) {
+            let delivered = counter.load(Ordering::SeqCst);
+            if *watch.borrow() != delivered {
+                watch.send_replace(delivered);
+            }
+        }
+    }
+}
+
+extern "C" fn handler(signum: libc::c_int) {
+    let Some(Ok(globals)) = GLOBALS.get() else {
+        return;
+    };
+    globals.counters[signum as usize].fetch_add(1, Ordering::SeqCst);
+
+    // `write` puede cambiar errno, que pertenece al código interrumpido.
+    // Si la tubería está llena ya hay un aviso pendiente: no importa fallar.
+    unsafe {
+        let errno = *libc::__errno_location();
+        libc::write(globals.sender, [1u8].as_ptr() as *const libc::c_void, 1);
+        *libc::__errno_location() = errno;
+    }
+}
+
+/// Instala el manejador de `signum` la primera vez que alguien la escucha
+///
+/// Nunca se desinstala: a partir de aquí la acción por defecto de la
+/// señal (terminar el proceso, en la mayoría) deja de aplicarse.
+fn install(globals: &Globals, signum: libc::c_int) -> io::Result<()> {
+    let forbidden = [
+        libc::SIGKILL,
+        libc::SIGSTOP,
+        libc::SIGSEGV,
+        libc::SIGBUS,
+        libc::SIGILL,
+        libc::SIGFPE,
+    ];
+    if signum <= 0 || signum as usize >= MAX_SIGNALS || forbidden.contains(&signum) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("no se puede escuchar la señal {}", signum),
+        ));
+    }
+
+    let mut installed = globals.installed.lock().unwrap();
+    if installed[signum as usize] {
+        return Ok(());
+    }
+
+    // Seguro: `action` se inicializa por completo antes de pasarla a sigaction
+    let rc = unsafe {
+        let mut action: libc::sigaction = mem::zeroed();
+        action.sa_sigaction = handler as extern "C" fn(libc::c_int) as libc::sighandler_t;
+        action.sa_flags = libc::SA_RESTART;
+        libc::sigemptyset(&mut action.sa_mask);
+        libc::sigaction(signum, &action, ptr::null_mut())
+    };
+    if rc == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    installed[signum as usize] = true;
+    Ok(())
+}
+
+/// Flujo de entregas de una señal, creado con `signal`
+///
+/// Varias entregas seguidas antes de que la tarea las vea se agrupan en
+/// una sola, como hace el propio kernel con las señales pendientes. Las
+/// entregas anteriores a su creación no se ven.
+pub struct Signal {
+    kind: SignalKind,
+    globals: &'static Globals,
+    /// Contador de entregas repartido por `Globals::broadcast`
+    delivered: watch::Receiver<u64>,
+    /// Valor del contador en la última entrega vista
+    seen: u64,
+    /// Copia del extremo de lectura de la tubería, para que el reactor
+    /// actual también la vigile y la vacíe
+    io: AsyncFd<OwnedFd>,
+}
+
+/// Empieza a escuchar la señal `kind`
+///
+/// No lanza hilos: todas las señales comparten una tubería que escribe el
+/// manejador y vigilan los reactores de los ejecutores con algún `Signal`.
+///
+/// # Errores
+/// `InvalidInput` para las señales que no pueden capturarse (SIGKILL,
+/// SIGSTOP) o que indican un fallo del propio proceso (SIGSEGV, ...)
+///
+/// # Pánico
+/// Si se llama fuera de un ejecutor de `async_runtime`
+///
+/// # Ejemplo
+/// ```no_run
+/// use async_runtime::signal::{SignalKind, signal};
+///
+/// async_runtime::block_on(async {
+///     let mut hangup = signal(SignalKind::hangup())?;
+///     while hangup.recv().await.is_some() {
+///         println!("recargando la configuración");
+///     }
+///     Ok::<_, std::io::Error>(())
+/// })
+/// .unwrap();
+/// ```
+pub fn signal(kind: SignalKind) -> io::Result<Signal> {
+    let globals = globals()?;
+    install(globals, kind.0)?;
+
+    // Se registra antes de leer el contador: una entrega posterior a la
+    // lectura llega con la tubería ya vigilada por este reactor
+    let io = AsyncFd::new(globals.receiver.try_clone()?)?;
+    let delivered = globals.watches[kind.0 as usize].subscribe();
+    Ok(Signal {
+        kind,
+        globals,
+        delivered,
+        seen: globals.counters[kind.0 as usize].load(Ordering::SeqCst),
+        io,
+    })
+}
+
+/// Espera a la siguiente SIGINT (Ctrl+C)
+///
+/// # Ejemplo
+/// ```no_run
+/// async_runtime::block_on(async {
+///     async_runtime::signal::ctrl_c().await?;
+///     println!("apagando");
+///     Ok::<_, std::io::Error>(())
+/// })
+/// .unwrap();
+/// ```
+pub async fn ctrl_c() -> io::Result<()> {
+    signal(SignalKind::interrupt())?.recv().await;
+    Ok(())
+}
+
+impl Signal {
+    pub fn kind(&self) -> SignalKind {
+        self.kind
+    }
+
+    /// Espera a la siguiente entrega de la señal
+    ///
+    /// Nunca devuelve `None`; el `Option` permite usarlo en un `while let`.
+    pub async fn recv(&mut self) -> Option<()> {
+        std::future::poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        // Si la tubería está lista la vacía y reparte; siempre queda a la
+        // espera del siguiente byte
+        let globals = self.globals;
+        let _ = self.io.poll_read_with(cx, |_| {
+            globals.broadcast();
+            Err::<(), _>(io::ErrorKind::WouldBlock.into())
+        });
+
+        // Los emisores viven en `GLOBALS` y nunca se sueltan
+        while let Poll::Ready(Ok(())) = self.delivered.poll_changed(cx) {
+            let delivered = *self.delivered.borrow_and_update();
+            if delivered != self.seen {
+                self.seen = delivered;
+                return Poll::Ready(Some(()));
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl Stream for Signal {
+    type Item = ();
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        self.get_mut().poll_recv(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SignalKind, signal};
+    use crate::block_on;
+    use std::{
+        io,
+        sync::{Arc, Barrier},
+        thread,
+    };
+
+    #[test]
+    fn raised_signal_reaches_every_listener() {
+        block_on(async {
+            let mut first = signal(SignalKind::user_defined1()).unwrap();
+            let mut second = signal(SignalKind::user_defined1()).unwrap();
+
+            // Dos entregas antes de mirar se ven como una
+            unsafe {
+                libc::raise(libc::SIGUSR1);
+                libc::raise(libc::SIGUSR1);
+            }
+            assert_eq!(first.recv().await, Some(()));
+            assert_eq!(second.recv().await, Some(()));
+        });
+    }
+
+    #[test]
+    fn every_reactor_sees_a_signal_drained_by_another() {
+        let barrier = Arc::new(Barrier::new(3));
+
+        let listeners: Vec<_> = (0..2)
+            .map(|_| {
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    block_on(async {
+                        let mut signal = signal(SignalKind::user_defined2()).unwrap();
+                        barrier.wait();
+                        signal.recv().await
+                    })
+                })
+            })
+            .collect();
+
+        barrier.wait();
+        unsafe { libc::kill(libc::getpid(), libc::SIGUSR2) };
+        for listener in listeners {
+            assert_eq!(listener.join().unwrap(), Some(()));
+        }
+    }
+
+    #[test]
+    fn uncatchable_signals_are_rejected() {
+        let result = block_on(async { signal(SignalKind::from_raw(libc::SIGKILL)).map(|_| ()) });
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+}
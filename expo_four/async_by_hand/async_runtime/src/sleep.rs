@@ -1,16 +1,31 @@
 use std::{
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
     time::{Duration, Instant},
 };
 
+use crate::{
+    context,
+    driver::Handle,
+    task::coop,
+    time::{self, driver::TimerKey},
+};
+
 /// Futuro asíncrono que simula una espera temporal
 ///
 /// Este futuro se completa después de un intervalo de tiempo especificado.
 /// Útil para implementar timeouts, retrasos programados o limitar frecuencia de operaciones.
+///
+/// En su primer sondeo registra su fecha de vencimiento en el temporizador
+/// del ejecutor actual, que despierta la tarea cuando llega el momento.
+/// Fuera de un ejecutor del crate lo hace un hilo de temporizadores propio.
 pub struct Sleep {
     /// El momento exacto en el que el futuro debe completarse
     when: Instant,
+
+    /// Registro en el temporizador del ejecutor, tras el primer sondeo
+    entry: Option<(Arc<Handle>, TimerKey)>,
 }
 
 impl Sleep {
@@ -22,13 +37,55 @@ impl Sleep {
     /// # Ejemplo
     /// ```
     /// use std::time::Duration;
-    /// use async_timer::Sleep;
+    /// use async_runtime::sleep::Sleep;
     ///
     /// let sleep_future = Sleep::new(Duration::from_secs(2));
     /// ```
     pub fn new(duration: Duration) -> Self {
+        Sleep::until(time::now() + duration)
+    }
+
+    /// Crea una instancia de Sleep que se completa en el instante `deadline`
+    pub fn until(deadline: Instant) -> Self {
         Sleep {
-            when: Instant::now() + duration,
+            when: deadline,
+            entry: None,
+        }
+    }
+
+    /// Momento en el que el futuro se completa
+    pub fn deadline(&self) -> Instant {
+        self.when
+    }
+
+    /// Indica si ya se alcanzó la fecha de vencimiento
+    pub fn is_elapsed(&self) -> bool {
+        time::now() >= self.when
+    }
+
+    /// Cambia la fecha de vencimiento, aunque ya se haya completado
+    ///
+    /// Si el futuro ya estaba registrado, el temporizador conserva el
+    /// waker de la tarea: no hace falta volver a sondearlo para que la
+    /// nueva fecha tenga efecto.
+    pub fn reset(&mut self, deadline: Instant) {
+        self.when = deadline;
+
+        if let Some((handle, key)) = &self.entry {
+            match handle.time.reset(*key, deadline) {
+                // Si pasó a ser el más cercano el hilo dormido debe recalcular
+                Some(true) => handle.unpark(),
+                Some(false) => {}
+                // Ya venció: el próximo sondeo lo registrará de nuevo
+                None => self.entry = None,
+            }
+        }
+    }
+
+    /// Retira el registro del temporizador, si lo hay
+    fn deregister(&mut self) {
+        if let Some((handle, key)) = self.entry.take() {
+            handle.time.cancel(key);
         }
     }
 }
@@ -40,24 +97,136 @@ impl Future for Sleep {
     ///
     /// Comportamiento:
     /// - Devuelve `Poll::Ready(())` si ha pasado el tiempo especificado
-    /// - Devuelve `Poll::Pending` y reactiva la tarea si aún no ha finalizado
-    ///
-    /// # Estrategia de reactivación
-    /// Siempre reactiva la tarea inmediatamente cuando el tiempo no ha expirado.
-    /// Esto puede ser ineficiente en producción ya que causa ejecución continua.
-    /// En entornos reales, se recomienda integrar con un sistema de tiempo del sistema operativo.
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let now = Instant::now();
+    /// - Devuelve `Poll::Pending` tras registrar (o actualizar) el waker en
+    ///   el temporizador del ejecutor, o en el del hilo de respaldo si se
+    ///   sondea fuera de un ejecutor de `async_runtime`
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        coop::poll_budgeted(cx, |cx| self.poll_elapsed(cx))
+    }
+}
 
+impl Sleep {
+    /// Sondeo de `Sleep` sin contar el presupuesto de la tarea
+    fn poll_elapsed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
         // Comprueba si ha alcanzado el tiempo objetivo
-        if now >= self.when {
-            // Tiempo completado
-            Poll::Ready(())
-        } else {
-            // Reactiva inmediatamente para volver a comprobar
-            // NOTA: En implementaciones reales usar un timer del sistema
-            cx.waker().wake_by_ref();
-            Poll::Pending
+        if time::now() >= self.when {
+            self.deregister();
+            return Poll::Ready(());
+        }
+
+        // Ya registrado: basta con mantener el waker al día
+        if let Some((handle, key)) = &self.entry
+            && handle.time.set_waker(*key, cx.waker())
+        {
+            return Poll::Pending;
         }
+
+        let handle = context::current_or_fallback();
+        let (key, earliest) = handle.time.register(self.when, cx.waker().clone());
+        if earliest {
+            handle.unpark();
+        }
+        self.entry = Some((handle, key));
+
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        self.deregister();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sleep;
+    use crate::{block_on, runtime::Runtime};
+    use std::{
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+        thread::{self, Thread},
+        time::{Duration, Instant},
+    };
+
+    /// Waker de un ejecutor ajeno: despierta al hilo que espera
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    #[test]
+    fn sleeps_without_busy_polling() {
+        let start = Instant::now();
+        let mut polls = 0;
+
+        block_on(async {
+            let mut sleep = Sleep::new(Duration::from_millis(50));
+            std::future::poll_fn(|cx| {
+                polls += 1;
+                std::pin::Pin::new(&mut sleep).poll(cx)
+            })
+            .await;
+        });
+
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        assert!(polls <= 3, "demasiados sondeos: {}", polls);
+    }
+
+    #[test]
+    fn reset_moves_the_deadline_earlier() {
+        let start = Instant::now();
+
+        block_on(async {
+            let mut sleep = Sleep::new(Duration::from_secs(10));
+            // Primer sondeo: registra el temporizador de 10 segundos
+            std::future::poll_fn(|cx| {
+                assert!(std::pin::Pin::new(&mut sleep).poll(cx).is_pending());
+                std::task::Poll::Ready(())
+            })
+            .await;
+
+            sleep.reset(Instant::now() + Duration::from_millis(20));
+            sleep.await;
+        });
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn runtime_wakes_sleeping_tasks() {
+        let runtime = Runtime::new(2).unwrap();
+        let start = Instant::now();
+
+        let handles: Vec<_> = (1..=4u64)
+            .map(|i| {
+                runtime.spawn(async move {
+                    Sleep::new(Duration::from_millis(20 * i)).await;
+                    i
+                })
+            })
+            .collect();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.block_on().unwrap(), i as u64 + 1);
+        }
+        assert!(start.elapsed() >= Duration::from_millis(80));
+    }
+
+    #[test]
+    fn sleeps_outside_the_runtime() {
+        let start = Instant::now();
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut sleep = std::pin::pin!(Sleep::new(Duration::from_millis(20)));
+        while sleep.as_mut().poll(&mut cx) == Poll::Pending {
+            thread::park();
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(20));
     }
 }
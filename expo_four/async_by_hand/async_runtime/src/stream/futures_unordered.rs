@@ -0,0 +1,190 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Waker},
+};
+
+use super::Stream;
+use crate::future::{FanOut, child_waker};
+
+/// Hueco del conjunto: un futuro en curso (o vacío) y el waker de su índice
+struct Slot<F> {
+    future: Option<Pin<Box<F>>>,
+    waker: Waker,
+}
+
+/// Conjunto de futuros que entrega sus resultados según van terminando
+///
+/// Funciona como un `Stream`: cada `next().await` devuelve el resultado del
+/// siguiente futuro en completarse, sin importar el orden de inserción, y
+/// `None` cuando el conjunto queda vacío. Cada futuro tiene su propio
+/// waker, así que un sondeo solo toca a los futuros que despertaron.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::stream::{FuturesUnordered, StreamExt};
+/// use async_runtime::time::sleep;
+/// use std::time::Duration;
+///
+/// async_runtime::block_on(async {
+///     let mut set = FuturesUnordered::new();
+///     for ms in [30, 10, 20] {
+///         set.push(async move {
+///             sleep(Duration::from_millis(ms)).await;
+///             ms
+///         });
+///     }
+///
+///     let mut order = Vec::new();
+///     while let Some(ms) = set.next().await {
+///         order.push(ms);
+///     }
+///     assert_eq!(order, vec![10, 20, 30]);
+/// });
+/// ```
+pub struct FuturesUnordered<F> {
+    slots: Vec<Slot<F>>,
+    /// Huecos libres para reutilizar
+    free: Vec<usize>,
+    fan_out: Arc<FanOut>,
+    len: usize,
+}
+
+impl<F> FuturesUnordered<F> {
+    pub fn new() -> Self {
+        FuturesUnordered {
+            slots: Vec::new(),
+            free: Vec::new(),
+            fan_out: FanOut::new(),
+            len: 0,
+        }
+    }
+
+    /// Número de futuros en curso
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Añade un futuro; se sondeará en el siguiente `poll_next`
+    pub fn push(&mut self, future: F) {
+        let future = Some(Box::pin(future));
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.slots[index].future = future;
+                index
+            }
+            None => {
+                let index = self.slots.len();
+                self.slots.push(Slot {
+                    future,
+                    waker: child_waker(&self.fan_out, index),
+                });
+                index
+            }
+        };
+        self.len += 1;
+        self.fan_out.schedule(index);
+    }
+}
+
+impl<F> Default for FuturesUnordered<F> {
+    fn default() -> Self {
+        FuturesUnordered::new()
+    }
+}
+
+impl<F> FromIterator<F> for FuturesUnordered<F> {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        let mut set = FuturesUnordered::new();
+        for future in iter {
+            set.push(future);
+        }
+        set
+    }
+}
+
+impl<F: Future> Stream for FuturesUnordered<F> {
+    type Item = F::Output;
+
+    /// Sondea los futuros despertados hasta que uno termine
+    ///
+    /// Para no acaparar el hilo, un turno sondea como mucho tantos futuros
+    /// como haya en el conjunto; si aún quedan despertados se vuelve a
+    /// despertar a la tarea.
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<F::Output>> {
+        let this = &mut *self;
+        if this.len == 0 {
+            return Poll::Ready(None);
+        }
+        this.fan_out.register(cx);
+
+        for _ in 0..this.len {
+            let Some(index) = this.fan_out.pop() else {
+                return Poll::Pending;
+            };
+            let slot = &mut this.slots[index];
+            // El hueco pudo vaciarse tras un despertar tardío
+            let Some(future) = slot.future.as_mut() else {
+                continue;
+            };
+            let mut child_cx = Context::from_waker(&slot.waker);
+            if let Poll::Ready(output) = future.as_mut().poll(&mut child_cx) {
+                slot.future = None;
+                this.free.push(index);
+                this.len -= 1;
+                return Poll::Ready(Some(output));
+            }
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FuturesUnordered;
+    use crate::{stream::StreamExt, sync::oneshot};
+
+    #[test]
+    fn yields_in_completion_order_and_reuses_slots() {
+        crate::block_on(async {
+            let mut set = FuturesUnordered::new();
+            let mut senders = Vec::new();
+            for _ in 0..3 {
+                let (tx, rx) = oneshot::channel();
+                senders.push(tx);
+                set.push(rx);
+            }
+
+            senders.pop().unwrap().send(2).unwrap();
+            assert_eq!(set.next().await, Some(Ok(2)));
+            assert_eq!(set.len(), 2);
+
+            // El hueco liberado se reutiliza para el siguiente futuro
+            let (tx, rx) = oneshot::channel();
+            set.push(rx);
+            tx.send(3).unwrap();
+            senders.remove(0).send(0).unwrap();
+            drop(senders);
+
+            // El emisor soltado sin enviar termina con error
+            let mut values = Vec::new();
+            let mut errors = 0;
+            while let Some(result) = set.next().await {
+                match result {
+                    Ok(value) => values.push(value),
+                    Err(_) => errors += 1,
+                }
+            }
+            values.sort();
+            assert_eq!((values, errors), (vec![0, 3], 1));
+            assert!(set.is_empty());
+        });
+    }
+}
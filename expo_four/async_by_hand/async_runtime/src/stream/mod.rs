@@ -0,0 +1,54 @@
+mod futures_unordered;
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pub use futures_unordered::FuturesUnordered;
+
+/// Secuencia de valores que llegan de forma asíncrona
+///
+/// Es el equivalente asíncrono de `Iterator`: `poll_next` devuelve
+/// `Ready(Some(item))` por cada valor, `Ready(None)` al terminar y
+/// `Pending` si el siguiente valor aún no está disponible.
+pub trait Stream {
+    type Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+}
+
+impl<S: Stream + Unpin + ?Sized> Stream for &mut S {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        Pin::new(&mut **self).poll_next(cx)
+    }
+}
+
+/// Métodos de conveniencia para cualquier `Stream`
+pub trait StreamExt: Stream {
+    /// Espera el siguiente valor; `None` si el stream terminó
+    fn next(&mut self) -> Next<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Next { stream: self }
+    }
+}
+
+impl<S: Stream + ?Sized> StreamExt for S {}
+
+/// Futuro devuelto por `StreamExt::next`
+pub struct Next<'a, S: ?Sized> {
+    stream: &'a mut S,
+}
+
+impl<S: Stream + Unpin + ?Sized> Future for Next<'_, S> {
+    type Output = Option<S::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.stream).poll_next(cx)
+    }
+}
@@ -0,0 +1,343 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    fmt,
+    future::poll_fn,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use crate::task::coop;
+
+/// Error al enviar: no queda ningún receptor; devuelve el valor
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct SendError<T>(pub T);
+
+/// Error de recepción
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RecvError {
+    /// Todos los emisores se soltaron y no quedan mensajes por leer
+    Closed,
+    /// El receptor se quedó atrás: se perdieron los `n` mensajes más antiguos
+    Lagged(u64),
+}
+
+/// Error de `try_recv`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TryRecvError {
+    /// No hay mensajes nuevos
+    Empty,
+    /// Ver `RecvError::Closed`
+    Closed,
+    /// Ver `RecvError::Lagged`
+    Lagged(u64),
+}
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SendError(..)")
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no quedan receptores")
+    }
+}
+
+impl<T> Error for SendError<T> {}
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecvError::Closed => write!(f, "el canal está cerrado"),
+            RecvError::Lagged(n) => write!(f, "el receptor perdió {} mensajes", n),
+        }
+    }
+}
+
+impl Error for RecvError {}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRecvError::Empty => write!(f, "el canal está vacío"),
+            TryRecvError::Closed => write!(f, "el canal está cerrado"),
+            TryRecvError::Lagged(n) => write!(f, "el receptor perdió {} mensajes", n),
+        }
+    }
+}
+
+impl Error for TryRecvError {}
+
+struct State<T> {
+    /// Últimos `capacity` mensajes; `buffer[0]` tiene la posición `head`
+    buffer: VecDeque<T>,
+    capacity: usize,
+    head: u64,
+    senders: usize,
+    receivers: usize,
+    /// Receptores esperando un mensaje, por identificador de receptor
+    waiters: HashMap<u64, Waker>,
+    next_receiver: u64,
+}
+
+impl<T> State<T> {
+    /// Posición que tendrá el próximo mensaje
+    fn tail(&self) -> u64 {
+        self.head + self.buffer.len() as u64
+    }
+
+    fn new_receiver(&mut self) -> (u64, u64) {
+        let id = self.next_receiver;
+        self.next_receiver += 1;
+        self.receivers += 1;
+        (id, self.tail())
+    }
+}
+
+type Shared<T> = Arc<Mutex<State<T>>>;
+
+/// Crea un canal de difusión: cada receptor recibe todos los mensajes
+///
+/// Se guardan los últimos `capacity` mensajes; un receptor que se quede
+/// más atrás recibe `RecvError::Lagged` y continúa desde el más antiguo
+/// que aún se conserva.
+///
+/// # Pánico
+/// Si `capacity` es cero
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::sync::broadcast;
+///
+/// async_runtime::block_on(async {
+///     let (tx, mut rx1) = broadcast::channel(16);
+///     let mut rx2 = tx.subscribe();
+///     tx.send(10).unwrap();
+///     assert_eq!(rx1.recv().await, Ok(10));
+///     assert_eq!(rx2.recv().await, Ok(10));
+/// });
+/// ```
+pub fn channel<T: Clone>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(
+        capacity > 0,
+        "la capacidad del canal debe ser mayor que cero"
+    );
+    let mut state = State {
+        buffer: VecDeque::with_capacity(capacity),
+        capacity,
+        head: 0,
+        senders: 1,
+        receivers: 0,
+        waiters: HashMap::new(),
+        next_receiver: 0,
+    };
+    let (id, next) = state.new_receiver();
+    let shared = Arc::new(Mutex::new(state));
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared, id, next },
+    )
+}
+
+/// Emisor de un canal de difusión
+pub struct Sender<T> {
+    shared: Shared<T>,
+}
+
+impl<T: Clone> Sender<T> {
+    /// Envía `value` a todos los receptores actuales
+    ///
+    /// # Retorno
+    /// Cuántos receptores lo recibirán, o `SendError` si no queda ninguno
+    pub fn send(&self, value: T) -> Result<usize, SendError<T>> {
+        let (receivers, waiters, evicted) = {
+            let mut state = self.shared.lock().unwrap();
+            if state.receivers == 0 {
+                return Err(SendError(value));
+            }
+
+            state.buffer.push_back(value);
+            let evicted = if state.buffer.len() > state.capacity {
+                state.head += 1;
+                state.buffer.pop_front()
+            } else {
+                None
+            };
+            let waiters: Vec<_> = state.waiters.drain().map(|(_, waker)| waker).collect();
+            (state.receivers, waiters, evicted)
+        };
+
+        drop(evicted);
+        for waker in waiters {
+            waker.wake();
+        }
+        Ok(receivers)
+    }
+
+    /// Crea un receptor que recibirá los mensajes enviados a partir de ahora
+    pub fn subscribe(&self) -> Receiver<T> {
+        let (id, next) = self.shared.lock().unwrap().new_receiver();
+        Receiver {
+            shared: self.shared.clone(),
+            id,
+            next,
+        }
+    }
+
+    pub fn receiver_count(&self) -> usize {
+        self.shared.lock().unwrap().receivers
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().unwrap().senders += 1;
+        Sender {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let waiters: Vec<_> = {
+            let mut state = self.shared.lock().unwrap();
+            state.senders -= 1;
+            if state.senders > 0 {
+                return;
+            }
+            state.waiters.drain().map(|(_, waker)| waker).collect()
+        };
+        for waker in waiters {
+            waker.wake();
+        }
+    }
+}
+
+/// Receptor de un canal de difusión
+pub struct Receiver<T> {
+    shared: Shared<T>,
+    id: u64,
+    /// Posición del próximo mensaje a leer
+    next: u64,
+}
+
+impl<T: Clone> Receiver<T> {
+    /// Espera al siguiente mensaje
+    pub async fn recv(&mut self) -> Result<T, RecvError> {
+        poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Versión por sondeo de `recv`
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Result<T, RecvError>> {
+        coop::poll_budgeted(cx, |cx| match self.recv_or_register(Some(cx.waker())) {
+            Ok(value) => Poll::Ready(Ok(value)),
+            Err(TryRecvError::Empty) => Poll::Pending,
+            Err(TryRecvError::Closed) => Poll::Ready(Err(RecvError::Closed)),
+            Err(TryRecvError::Lagged(n)) => Poll::Ready(Err(RecvError::Lagged(n))),
+        })
+    }
+
+    /// Recibe sin esperar
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        self.recv_or_register(None)
+    }
+
+    fn recv_or_register(&mut self, waker: Option<&Waker>) -> Result<T, TryRecvError> {
+        let mut state = self.shared.lock().unwrap();
+
+        if self.next < state.head {
+            let lagged = state.head - self.next;
+            self.next = state.head;
+            return Err(TryRecvError::Lagged(lagged));
+        }
+
+        if self.next < state.tail() {
+            let value = state.buffer[(self.next - state.head) as usize].clone();
+            self.next += 1;
+            return Ok(value);
+        }
+
+        if state.senders == 0 {
+            return Err(TryRecvError::Closed);
+        }
+        if let Some(waker) = waker {
+            state.waiters.insert(self.id, waker.clone());
+        }
+        Err(TryRecvError::Empty)
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock().unwrap();
+        state.receivers -= 1;
+        let waker = state.waiters.remove(&self.id);
+        drop(state);
+        drop(waker);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RecvError, TryRecvError, channel};
+    use crate::executor::Executor;
+
+    #[test]
+    fn every_receiver_gets_every_message() {
+        let mut executor = Executor::new();
+        let (tx, rx) = channel(8);
+
+        let handles: Vec<_> = [rx, tx.subscribe(), tx.subscribe()]
+            .into_iter()
+            .map(|mut rx| {
+                executor.spawn(async move {
+                    let mut received = Vec::new();
+                    while let Ok(value) = rx.recv().await {
+                        received.push(value);
+                    }
+                    received
+                })
+            })
+            .collect();
+
+        executor.spawn(async move {
+            for i in 0..5 {
+                tx.send(i).unwrap();
+            }
+        });
+
+        let results = executor.block_on(async move {
+            let mut results = Vec::new();
+            for handle in handles {
+                results.push(handle.await.unwrap());
+            }
+            results
+        });
+
+        for received in results {
+            assert_eq!(received, vec![0, 1, 2, 3, 4]);
+        }
+    }
+
+    #[test]
+    fn slow_receiver_lags() {
+        let (tx, mut rx) = channel(2);
+        for i in 0..5 {
+            tx.send(i).unwrap();
+        }
+
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Lagged(3)));
+        assert_eq!(rx.try_recv(), Ok(3));
+        assert_eq!(rx.try_recv(), Ok(4));
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+
+        drop(tx);
+        let closed = crate::block_on(rx.recv());
+        assert_eq!(closed, Err(RecvError::Closed));
+    }
+}
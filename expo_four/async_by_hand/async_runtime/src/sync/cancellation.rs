@@ -0,0 +1,224 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll},
+};
+
+use super::wait_queue::{WaitQueue, WaiterKey};
+
+struct State {
+    cancelled: bool,
+    waiters: WaitQueue,
+    /// Tokens hijos, que se cancelan junto con este
+    children: Vec<Weak<Node>>,
+}
+
+struct Node {
+    state: Mutex<State>,
+}
+
+impl Node {
+    fn new(cancelled: bool) -> Arc<Self> {
+        Arc::new(Node {
+            state: Mutex::new(State {
+                cancelled,
+                waiters: WaitQueue::default(),
+                children: Vec::new(),
+            }),
+        })
+    }
+
+    /// Marca el nodo como cancelado y propaga la cancelación a sus hijos
+    fn cancel(&self) {
+        let (wakers, children) = {
+            let mut state = self.state.lock().unwrap();
+            if state.cancelled {
+                return;
+            }
+            state.cancelled = true;
+            (
+                state.waiters.wake_all(),
+                std::mem::take(&mut state.children),
+            )
+        };
+
+        for waker in wakers {
+            waker.wake();
+        }
+        for child in children.iter().filter_map(Weak::upgrade) {
+            child.cancel();
+        }
+    }
+}
+
+/// Señal de cancelación compartida entre tareas
+///
+/// Todas las copias de un token comparten el mismo estado: al llamar a
+/// `cancel` en cualquiera de ellas, las tareas que esperan en `cancelled`
+/// se despiertan y `is_cancelled` pasa a devolver `true`. Cancelar es
+/// definitivo.
+///
+/// Con `child_token` se crean tokens que se cancelan cuando lo hace su
+/// padre, pero que pueden cancelarse por separado sin afectarlo.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::sync::CancellationToken;
+///
+/// async_runtime::block_on(async {
+///     let token = CancellationToken::new();
+///     let child = token.child_token();
+///
+///     token.cancel();
+///     // El hijo se cancela con su padre
+///     child.cancelled().await;
+///     assert!(child.is_cancelled());
+/// });
+/// ```
+#[derive(Clone)]
+pub struct CancellationToken {
+    node: Arc<Node>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        CancellationToken {
+            node: Node::new(false),
+        }
+    }
+
+    /// Crea un token que se cancela cuando se cancela este
+    ///
+    /// Cancelar el hijo no cancela al padre.
+    pub fn child_token(&self) -> CancellationToken {
+        let mut state = self.node.state.lock().unwrap();
+        let child = Node::new(state.cancelled);
+        if !state.cancelled {
+            // Aprovecha para olvidar a los hijos que ya no existen
+            state.children.retain(|child| child.strong_count() > 0);
+            state.children.push(Arc::downgrade(&child));
+        }
+        CancellationToken { node: child }
+    }
+
+    /// Cancela el token, sus copias y sus hijos
+    pub fn cancel(&self) {
+        self.node.cancel();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.node.state.lock().unwrap().cancelled
+    }
+
+    /// Espera a que el token se cancele
+    pub fn cancelled(&self) -> WaitForCancellation<'_> {
+        WaitForCancellation {
+            token: self,
+            key: None,
+        }
+    }
+
+    /// Devuelve un guard que cancela el token al soltarse
+    ///
+    /// Útil para cancelar el trabajo asociado si la tarea que lo creó
+    /// termina por cualquier camino, incluido un error o un pánico.
+    pub fn drop_guard(self) -> DropGuard {
+        DropGuard { token: Some(self) }
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        CancellationToken::new()
+    }
+}
+
+/// Futuro devuelto por `CancellationToken::cancelled`
+pub struct WaitForCancellation<'a> {
+    token: &'a CancellationToken,
+    key: Option<WaiterKey>,
+}
+
+impl Future for WaitForCancellation<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = &mut *self;
+        let mut state = this.token.node.state.lock().unwrap();
+        if state.cancelled {
+            this.key = None;
+            return Poll::Ready(());
+        }
+        state.waiters.register(&mut this.key, cx.waker());
+        Poll::Pending
+    }
+}
+
+impl Drop for WaitForCancellation<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.token.node.state.lock().unwrap().waiters.remove(key);
+        }
+    }
+}
+
+/// Cancela su token al soltarse, salvo que se desarme con `disarm`
+pub struct DropGuard {
+    token: Option<CancellationToken>,
+}
+
+impl DropGuard {
+    /// Recupera el token sin cancelarlo
+    pub fn disarm(mut self) -> CancellationToken {
+        self.token.take().unwrap()
+    }
+}
+
+impl Drop for DropGuard {
+    fn drop(&mut self) {
+        if let Some(token) = self.token.take() {
+            token.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CancellationToken;
+    use crate::executor::Executor;
+
+    #[test]
+    fn cancel_wakes_waiters_and_children_but_not_parents() {
+        let mut executor = Executor::new();
+        let parent = CancellationToken::new();
+        let child = parent.child_token();
+        let grandchild = child.child_token();
+
+        let waiter = executor.spawn({
+            let grandchild = grandchild.clone();
+            async move { grandchild.cancelled().await }
+        });
+        while executor.has_ready_tasks() {
+            executor.poll();
+        }
+        assert!(!waiter.is_finished());
+
+        // Cancelar un hijo no sube hacia el padre
+        let sibling = parent.child_token();
+        sibling.cancel();
+        assert!(!parent.is_cancelled());
+        assert!(!grandchild.is_cancelled());
+
+        parent.cancel();
+        executor.block_on(waiter).unwrap();
+        assert!(child.is_cancelled());
+
+        // Un hijo creado después nace cancelado
+        assert!(parent.child_token().is_cancelled());
+
+        let guard = CancellationToken::new().drop_guard();
+        let kept = guard.disarm();
+        assert!(!kept.is_cancelled());
+    }
+}
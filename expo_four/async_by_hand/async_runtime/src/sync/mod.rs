@@ -0,0 +1,18 @@
+pub mod broadcast;
+mod cancellation;
+pub mod mpsc;
+mod mutex;
+mod notify;
+pub mod oneshot;
+mod rwlock;
+mod semaphore;
+mod wait_queue;
+pub mod watch;
+
+pub use cancellation::{CancellationToken, DropGuard, WaitForCancellation};
+pub use mutex::{Mutex, MutexGuard, TryLockError};
+pub use notify::{Notified, Notify};
+pub use rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+pub use semaphore::{
+    AcquireError, OwnedSemaphorePermit, Semaphore, SemaphorePermit, TryAcquireError,
+};
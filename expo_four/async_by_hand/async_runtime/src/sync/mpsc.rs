@@ -0,0 +1,528 @@
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt,
+    future::{Future, poll_fn},
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+};
+
+use super::wait_queue::{WaitQueue, WaiterKey};
+use crate::task::coop;
+
+/// Error al enviar: el receptor ya no existe; devuelve el valor
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct SendError<T>(pub T);
+
+/// Error de `try_send`
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum TrySendError<T> {
+    /// El canal está lleno
+    Full(T),
+    /// El receptor ya no existe
+    Closed(T),
+}
+
+/// Error de `try_recv`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TryRecvError {
+    /// No hay mensajes, pero aún quedan emisores
+    Empty,
+    /// No hay mensajes y todos los emisores se soltaron
+    Disconnected,
+}
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SendError(..)")
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "el canal está cerrado")
+    }
+}
+
+impl<T> Error for SendError<T> {}
+
+impl<T> fmt::Debug for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrySendError::Full(_) => f.write_str("Full(..)"),
+            TrySendError::Closed(_) => f.write_str("Closed(..)"),
+        }
+    }
+}
+
+impl<T> fmt::Display for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrySendError::Full(_) => write!(f, "el canal está lleno"),
+            TrySendError::Closed(_) => write!(f, "el canal está cerrado"),
+        }
+    }
+}
+
+impl<T> Error for TrySendError<T> {}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRecvError::Empty => write!(f, "el canal está vacío"),
+            TryRecvError::Disconnected => write!(f, "el canal está cerrado"),
+        }
+    }
+}
+
+impl Error for TryRecvError {}
+
+struct State<T> {
+    queue: VecDeque<T>,
+    /// `None` para los canales sin límite
+    capacity: Option<usize>,
+    senders: usize,
+    rx_closed: bool,
+    rx_waker: Option<Waker>,
+    /// Emisores esperando hueco, en orden de llegada
+    send_waiters: WaitQueue,
+}
+
+impl<T> State<T> {
+    fn is_full(&self) -> bool {
+        self.capacity
+            .is_some_and(|capacity| self.queue.len() >= capacity)
+    }
+}
+
+/// Estado compartido entre los emisores y el receptor
+struct Chan<T> {
+    state: Mutex<State<T>>,
+}
+
+impl<T> Chan<T> {
+    fn new(capacity: Option<usize>) -> Arc<Self> {
+        Arc::new(Chan {
+            state: Mutex::new(State {
+                queue: VecDeque::new(),
+                capacity,
+                senders: 1,
+                rx_closed: false,
+                rx_waker: None,
+                send_waiters: WaitQueue::default(),
+            }),
+        })
+    }
+
+    /// Encola sin esperar
+    fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        let state = self.state.lock().unwrap();
+        if state.rx_closed {
+            return Err(TrySendError::Closed(value));
+        }
+        if state.is_full() {
+            return Err(TrySendError::Full(value));
+        }
+        Self::push(state, value);
+        Ok(())
+    }
+
+    /// Encola si hay hueco; si no, deja el waker en la cola de emisores
+    ///
+    /// Comprobar el hueco y registrarse ocurre bajo el mismo lock, así el
+    /// aviso de un hueco recién liberado no puede perderse.
+    fn poll_send(
+        &self,
+        cx: &mut Context<'_>,
+        value: &mut Option<T>,
+        waiter: &mut Option<WaiterKey>,
+    ) -> Poll<Result<(), SendError<T>>> {
+        let mut state = self.state.lock().unwrap();
+        if !state.rx_closed && state.is_full() {
+            state.send_waiters.register(waiter, cx.waker());
+            return Poll::Pending;
+        }
+
+        if let Some(key) = waiter.take() {
+            state.send_waiters.remove(key);
+        }
+        let value = value
+            .take()
+            .expect("`Send` sondeado después de completarse");
+        if state.rx_closed {
+            return Poll::Ready(Err(SendError(value)));
+        }
+        Self::push(state, value);
+        Poll::Ready(Ok(()))
+    }
+
+    /// Encola el mensaje y despierta al receptor fuera del lock
+    fn push(mut state: MutexGuard<'_, State<T>>, value: T) {
+        state.queue.push_back(value);
+        let waker = state.rx_waker.take();
+        drop(state);
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    /// Abandona una espera de envío, pasando el aviso si ya lo había recibido
+    fn cancel_send(&self, key: WaiterKey) {
+        let mut state = self.state.lock().unwrap();
+        if !state.send_waiters.remove(key) {
+            let next = state.send_waiters.wake_one();
+            drop(state);
+            if let Some(waker) = next {
+                waker.wake();
+            }
+        }
+    }
+
+    fn try_recv(&self) -> Result<T, TryRecvError> {
+        match self.recv_or_register(None) {
+            Poll::Ready(Some(value)) => Ok(value),
+            Poll::Ready(None) => Err(TryRecvError::Disconnected),
+            Poll::Pending => Err(TryRecvError::Empty),
+        }
+    }
+
+    fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        coop::poll_budgeted(cx, |cx| self.recv_or_register(Some(cx.waker())))
+    }
+
+    /// Saca un mensaje o, si no hay, deja `waker` para el próximo envío
+    fn recv_or_register(&self, waker: Option<&Waker>) -> Poll<Option<T>> {
+        let mut state = self.state.lock().unwrap();
+        match state.queue.pop_front() {
+            Some(value) => {
+                // Se liberó un hueco: avisa al primer emisor en espera
+                let sender = state.send_waiters.wake_one();
+                drop(state);
+                if let Some(sender) = sender {
+                    sender.wake();
+                }
+                Poll::Ready(Some(value))
+            }
+            None if state.senders == 0 => Poll::Ready(None),
+            None => {
+                if let Some(waker) = waker {
+                    state.rx_waker = Some(waker.clone());
+                }
+                Poll::Pending
+            }
+        }
+    }
+
+    /// Cierra el canal desde el receptor; los mensajes en cola siguen disponibles
+    fn close(&self) {
+        let waiters = {
+            let mut state = self.state.lock().unwrap();
+            state.rx_closed = true;
+            state.send_waiters.wake_all()
+        };
+        for waker in waiters {
+            waker.wake();
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        self.state.lock().unwrap().rx_closed
+    }
+
+    fn add_sender(&self) {
+        self.state.lock().unwrap().senders += 1;
+    }
+
+    fn drop_sender(&self) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            state.senders -= 1;
+            if state.senders == 0 {
+                state.rx_waker.take()
+            } else {
+                None
+            }
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    fn drop_receiver(&self) {
+        self.close();
+        // Los mensajes pendientes se sueltan fuera del lock
+        let pending = std::mem::take(&mut self.state.lock().unwrap().queue);
+        drop(pending);
+    }
+}
+
+/// Crea un canal mpsc con capacidad limitada
+///
+/// `send().await` espera mientras el canal tiene `capacity` mensajes sin leer.
+///
+/// # Pánico
+/// Si `capacity` es cero
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::sync::mpsc;
+///
+/// async_runtime::block_on(async {
+///     let (tx, mut rx) = mpsc::channel(32);
+///     tx.send(1).await.unwrap();
+///     drop(tx);
+///     assert_eq!(rx.recv().await, Some(1));
+///     assert_eq!(rx.recv().await, None);
+/// });
+/// ```
+pub fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(
+        capacity > 0,
+        "la capacidad del canal debe ser mayor que cero"
+    );
+    let chan = Chan::new(Some(capacity));
+    (Sender { chan: chan.clone() }, Receiver { chan })
+}
+
+/// Crea un canal mpsc sin límite de capacidad
+pub fn unbounded_channel<T>() -> (UnboundedSender<T>, UnboundedReceiver<T>) {
+    let chan = Chan::new(None);
+    (
+        UnboundedSender { chan: chan.clone() },
+        UnboundedReceiver { chan },
+    )
+}
+
+/// Emisor de un canal con capacidad limitada
+pub struct Sender<T> {
+    chan: Arc<Chan<T>>,
+}
+
+/// Futuro devuelto por `Sender::send`
+struct Send<'a, T> {
+    chan: &'a Chan<T>,
+    value: Option<T>,
+    waiter: Option<WaiterKey>,
+}
+
+impl<T> Unpin for Send<'_, T> {}
+
+impl<T> Future for Send<'_, T> {
+    type Output = Result<(), SendError<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        this.chan.poll_send(cx, &mut this.value, &mut this.waiter)
+    }
+}
+
+impl<T> Drop for Send<'_, T> {
+    fn drop(&mut self) {
+        if let Some(key) = self.waiter.take() {
+            self.chan.cancel_send(key);
+        }
+    }
+}
+
+impl<T> Sender<T> {
+    /// Envía `value`, esperando a que haya hueco en el canal
+    ///
+    /// Los emisores que esperan reciben hueco en orden de llegada.
+    pub async fn send(&self, value: T) -> Result<(), SendError<T>> {
+        Send {
+            chan: &self.chan,
+            value: Some(value),
+            waiter: None,
+        }
+        .await
+    }
+
+    /// Envía sin esperar
+    pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        self.chan.try_send(value)
+    }
+
+    /// Indica si el receptor se soltó o cerró el canal
+    pub fn is_closed(&self) -> bool {
+        self.chan.is_closed()
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.chan.add_sender();
+        Sender {
+            chan: self.chan.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.chan.drop_sender();
+    }
+}
+
+/// Receptor de un canal con capacidad limitada
+pub struct Receiver<T> {
+    chan: Arc<Chan<T>>,
+}
+
+impl<T> Receiver<T> {
+    /// Espera al siguiente mensaje
+    ///
+    /// # Retorno
+    /// `None` cuando todos los emisores se soltaron y la cola está vacía
+    pub async fn recv(&mut self) -> Option<T> {
+        poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Versión por sondeo de `recv`
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.chan.poll_recv(cx)
+    }
+
+    /// Recibe sin esperar
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        self.chan.try_recv()
+    }
+
+    /// Impide nuevos envíos; los mensajes ya encolados pueden seguir leyéndose
+    pub fn close(&mut self) {
+        self.chan.close();
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.chan.drop_receiver();
+    }
+}
+
+/// Emisor de un canal sin límite
+pub struct UnboundedSender<T> {
+    chan: Arc<Chan<T>>,
+}
+
+impl<T> UnboundedSender<T> {
+    /// Envía `value` sin esperar nunca
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        self.chan.try_send(value).map_err(|e| match e {
+            TrySendError::Full(value) | TrySendError::Closed(value) => SendError(value),
+        })
+    }
+
+    /// Indica si el receptor se soltó o cerró el canal
+    pub fn is_closed(&self) -> bool {
+        self.chan.is_closed()
+    }
+}
+
+impl<T> Clone for UnboundedSender<T> {
+    fn clone(&self) -> Self {
+        self.chan.add_sender();
+        UnboundedSender {
+            chan: self.chan.clone(),
+        }
+    }
+}
+
+impl<T> Drop for UnboundedSender<T> {
+    fn drop(&mut self) {
+        self.chan.drop_sender();
+    }
+}
+
+/// Receptor de un canal sin límite
+pub struct UnboundedReceiver<T> {
+    chan: Arc<Chan<T>>,
+}
+
+impl<T> UnboundedReceiver<T> {
+    /// Ver `Receiver::recv`
+    pub async fn recv(&mut self) -> Option<T> {
+        poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Versión por sondeo de `recv`
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.chan.poll_recv(cx)
+    }
+
+    /// Recibe sin esperar
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        self.chan.try_recv()
+    }
+
+    /// Ver `Receiver::close`
+    pub fn close(&mut self) {
+        self.chan.close();
+    }
+}
+
+impl<T> Drop for UnboundedReceiver<T> {
+    fn drop(&mut self) {
+        self.chan.drop_receiver();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TryRecvError, TrySendError, channel, unbounded_channel};
+    use crate::executor::Executor;
+
+    #[test]
+    fn bounded_senders_wait_for_room() {
+        let mut executor = Executor::new();
+        let (tx, mut rx) = channel(2);
+
+        let producers: Vec<_> = (0..3)
+            .map(|p| {
+                let tx = tx.clone();
+                executor.spawn(async move {
+                    for i in 0..5 {
+                        tx.send(p * 10 + i).await.unwrap();
+                    }
+                })
+            })
+            .collect();
+        drop(tx);
+
+        let mut received = executor.block_on(async move {
+            let mut received = Vec::new();
+            while let Some(value) = rx.recv().await {
+                received.push(value);
+            }
+            for producer in producers {
+                producer.await.unwrap();
+            }
+            received
+        });
+
+        received.sort();
+        let expected: Vec<_> = (0..3)
+            .flat_map(|p| (0..5).map(move |i| p * 10 + i))
+            .collect();
+        assert_eq!(received, expected);
+    }
+
+    #[test]
+    fn try_operations_report_state() {
+        let (tx, mut rx) = channel(1);
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+        tx.try_send(1).unwrap();
+        assert!(matches!(tx.try_send(2), Err(TrySendError::Full(2))));
+        assert_eq!(rx.try_recv(), Ok(1));
+
+        rx.close();
+        assert!(matches!(tx.try_send(3), Err(TrySendError::Closed(3))));
+        drop(tx);
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Disconnected));
+
+        let (tx, rx) = unbounded_channel();
+        drop(rx);
+        assert!(tx.send(1).is_err());
+    }
+}
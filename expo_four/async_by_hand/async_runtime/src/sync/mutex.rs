@@ -0,0 +1,150 @@
+use std::{
+    cell::UnsafeCell,
+    error::Error,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use super::semaphore::{Semaphore, SemaphorePermit};
+
+/// Error de `try_lock`: el recurso está ocupado
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TryLockError(pub(super) ());
+
+impl fmt::Display for TryLockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "el lock está ocupado")
+    }
+}
+
+impl Error for TryLockError {}
+
+/// Mutex asíncrono y justo
+///
+/// Quien espera el lock suelta el hilo en lugar de bloquearlo, y las tareas
+/// lo obtienen en el orden en que lo pidieron. Puede mantenerse tomado a
+/// través de un `.await`.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::sync::Mutex;
+///
+/// async_runtime::block_on(async {
+///     let counter = Mutex::new(0);
+///     *counter.lock().await += 1;
+///     assert_eq!(*counter.lock().await, 1);
+/// });
+/// ```
+pub struct Mutex<T: ?Sized> {
+    semaphore: Semaphore,
+    data: UnsafeCell<T>,
+}
+
+// Seguro: el acceso a `data` está serializado por el semáforo de un permiso
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+    pub fn new(value: T) -> Self {
+        Mutex {
+            semaphore: Semaphore::new(1),
+            data: UnsafeCell::new(value),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    /// Espera a obtener el lock, en orden de llegada
+    pub async fn lock(&self) -> MutexGuard<'_, T> {
+        let permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("el semáforo de un Mutex nunca se cierra");
+        MutexGuard {
+            mutex: self,
+            _permit: permit,
+        }
+    }
+
+    /// Toma el lock sin esperar
+    pub fn try_lock(&self) -> Result<MutexGuard<'_, T>, TryLockError> {
+        match self.semaphore.try_acquire() {
+            Ok(permit) => Ok(MutexGuard {
+                mutex: self,
+                _permit: permit,
+            }),
+            Err(_) => Err(TryLockError(())),
+        }
+    }
+
+    /// Acceso directo: el préstamo mutable garantiza la exclusión
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T: Default> Default for Mutex<T> {
+    fn default() -> Self {
+        Mutex::new(T::default())
+    }
+}
+
+/// Acceso exclusivo al contenido de un `Mutex`; lo libera al soltarse
+pub struct MutexGuard<'a, T: ?Sized> {
+    mutex: &'a Mutex<T>,
+    _permit: SemaphorePermit<'a>,
+}
+
+// Seguro: el guard solo da acceso compartido a `T` a través de `&self`
+unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // Seguro: el permiso garantiza acceso exclusivo mientras viva el guard
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mutex;
+    use crate::{runtime::Runtime, time::sleep};
+    use std::{sync::Arc, time::Duration};
+
+    #[test]
+    fn lock_is_held_across_await() {
+        let runtime = Runtime::new(4).unwrap();
+        let counter = Arc::new(Mutex::new(0));
+
+        let handles: Vec<_> = (0..20)
+            .map(|_| {
+                let counter = counter.clone();
+                runtime.spawn(async move {
+                    let mut guard = counter.lock().await;
+                    let value = *guard;
+                    // Otra tarea que entrara ahora perdería este incremento
+                    sleep(Duration::from_millis(1)).await;
+                    *guard = value + 1;
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.block_on().unwrap();
+        }
+        assert_eq!(*counter.try_lock().unwrap(), 20);
+    }
+}
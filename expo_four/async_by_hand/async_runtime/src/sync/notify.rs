@@ -0,0 +1,230 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Mutex,
+    task::{Context, Poll, Waker},
+};
+
+use super::wait_queue::{WaitQueue, WaiterKey};
+
+struct State {
+    /// Un `notify_one` sin nadie esperando queda guardado para el siguiente
+    permit: bool,
+    waiters: WaitQueue,
+    /// Esperas sacadas de la cola por `notify_one` que aún no lo consumen
+    ///
+    /// Solo ese aviso debe pasar a otra tarea si la espera se suelta; uno
+    /// de `notify_waiters` se pierde con ella.
+    notified_one: Vec<WaiterKey>,
+}
+
+impl State {
+    /// Saca a la primera espera, recordando que la avisó `notify_one`
+    fn wake_one(&mut self) -> Option<Waker> {
+        let (key, waker) = self.waiters.pop_front()?;
+        self.notified_one.push(key);
+        Some(waker)
+    }
+
+    /// Olvida el aviso de `notify_one` dirigido a `key`, si lo había
+    fn forget_one(&mut self, key: WaiterKey) -> bool {
+        match self.notified_one.iter().position(|other| *other == key) {
+            Some(index) => {
+                self.notified_one.swap_remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Avisa a tareas que esperan un evento, sin transportar datos
+///
+/// `notify_one` despierta a la tarea que lleva más tiempo esperando, o deja
+/// un aviso guardado si no espera ninguna. `notify_waiters` despierta a
+/// todas las que esperan en ese momento.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::sync::Notify;
+///
+/// async_runtime::block_on(async {
+///     let notify = Notify::new();
+///     notify.notify_one();
+///     // El aviso quedó guardado: se completa de inmediato
+///     notify.notified().await;
+/// });
+/// ```
+pub struct Notify {
+    state: Mutex<State>,
+}
+
+impl Notify {
+    pub fn new() -> Self {
+        Notify {
+            state: Mutex::new(State {
+                permit: false,
+                waiters: WaitQueue::default(),
+                notified_one: Vec::new(),
+            }),
+        }
+    }
+
+    /// Espera un aviso
+    ///
+    /// La espera se registra en el primer sondeo; un `notify_waiters`
+    /// anterior no la despierta.
+    pub fn notified(&self) -> Notified<'_> {
+        Notified {
+            notify: self,
+            key: None,
+        }
+    }
+
+    /// Despierta a la primera tarea en espera o guarda el aviso
+    pub fn notify_one(&self) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            let waker = state.wake_one();
+            if waker.is_none() {
+                state.permit = true;
+            }
+            waker
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    /// Despierta a todas las tareas en espera, sin guardar aviso
+    pub fn notify_waiters(&self) {
+        let wakers = self.state.lock().unwrap().waiters.wake_all();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl Default for Notify {
+    fn default() -> Self {
+        Notify::new()
+    }
+}
+
+/// Futuro devuelto por `Notify::notified`
+pub struct Notified<'a> {
+    notify: &'a Notify,
+    key: Option<WaiterKey>,
+}
+
+impl Future for Notified<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = &mut *self;
+        let mut state = this.notify.state.lock().unwrap();
+
+        match this.key {
+            None if state.permit => {
+                state.permit = false;
+                return Poll::Ready(());
+            }
+            // Ya no está en la cola: alguien la avisó
+            Some(key) if !state.waiters.contains(key) => {
+                state.forget_one(key);
+                this.key = None;
+                return Poll::Ready(());
+            }
+            _ => {}
+        }
+
+        state.waiters.register(&mut this.key, cx.waker());
+        Poll::Pending
+    }
+}
+
+impl Drop for Notified<'_> {
+    fn drop(&mut self) {
+        let Some(key) = self.key else { return };
+
+        let mut state = self.notify.state.lock().unwrap();
+        if !state.waiters.remove(key) && state.forget_one(key) {
+            // Recibió un `notify_one` que nunca consumirá: pasa a la siguiente tarea
+            match state.wake_one() {
+                Some(waker) => {
+                    drop(state);
+                    waker.wake();
+                }
+                None => state.permit = true,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Notify;
+    use crate::executor::Executor;
+    use std::{
+        future::Future,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        task::{Context, Waker},
+    };
+
+    #[test]
+    fn notify_one_wakes_in_order_and_notify_waiters_wakes_all() {
+        let mut executor = Executor::new();
+        let notify = Arc::new(Notify::new());
+        let woken = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..3)
+            .map(|_| {
+                let notify = notify.clone();
+                let woken = woken.clone();
+                executor.spawn(async move {
+                    notify.notified().await;
+                    woken.fetch_add(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+
+        // Deja que las tres tareas se registren
+        while executor.has_ready_tasks() {
+            executor.poll();
+        }
+        assert_eq!(woken.load(Ordering::SeqCst), 0);
+
+        notify.notify_one();
+        while executor.has_ready_tasks() {
+            executor.poll();
+        }
+        assert_eq!(woken.load(Ordering::SeqCst), 1);
+
+        notify.notify_waiters();
+        executor.block_on(async move {
+            for handle in handles {
+                handle.await.unwrap();
+            }
+        });
+        assert_eq!(woken.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn dropping_a_waiter_woken_by_notify_waiters_leaves_no_permit() {
+        let notify = Notify::new();
+        let mut notified = Box::pin(notify.notified());
+
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(notified.as_mut().poll(&mut cx).is_pending());
+
+        notify.notify_waiters();
+        drop(notified);
+
+        let state = notify.state.lock().unwrap();
+        assert!(!state.permit);
+        assert!(state.notified_one.is_empty());
+    }
+}
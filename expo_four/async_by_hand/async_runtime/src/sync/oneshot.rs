@@ -0,0 +1,228 @@
+use std::{
+    error::Error,
+    fmt,
+    future::{Future, poll_fn},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use crate::task::coop;
+
+/// Error de recepción: el emisor se soltó sin enviar
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RecvError(());
+
+/// Error de `try_recv`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TryRecvError {
+    /// Aún no se ha enviado el valor
+    Empty,
+    /// El emisor se soltó sin enviar
+    Closed,
+}
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "el emisor se cerró sin enviar")
+    }
+}
+
+impl Error for RecvError {}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRecvError::Empty => write!(f, "el canal está vacío"),
+            TryRecvError::Closed => write!(f, "el emisor se cerró sin enviar"),
+        }
+    }
+}
+
+impl Error for TryRecvError {}
+
+struct State<T> {
+    value: Option<T>,
+    tx_dropped: bool,
+    rx_closed: bool,
+    rx_waker: Option<Waker>,
+    /// Tarea esperando en `Sender::closed`
+    tx_waker: Option<Waker>,
+}
+
+type Shared<T> = Arc<Mutex<State<T>>>;
+
+/// Crea un canal para enviar un único valor
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::sync::oneshot;
+///
+/// async_runtime::block_on(async {
+///     let (tx, rx) = oneshot::channel();
+///     tx.send("listo").unwrap();
+///     assert_eq!(rx.await, Ok("listo"));
+/// });
+/// ```
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Mutex::new(State {
+        value: None,
+        tx_dropped: false,
+        rx_closed: false,
+        rx_waker: None,
+        tx_waker: None,
+    }));
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+/// Extremo emisor: se consume al enviar
+pub struct Sender<T> {
+    shared: Shared<T>,
+}
+
+impl<T> Sender<T> {
+    /// Envía el valor
+    ///
+    /// # Retorno
+    /// `Err(value)` si el receptor ya se soltó o cerró el canal
+    pub fn send(self, value: T) -> Result<(), T> {
+        let waker = {
+            let mut state = self.shared.lock().unwrap();
+            if state.rx_closed {
+                return Err(value);
+            }
+            state.value = Some(value);
+            state.rx_waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        Ok(())
+    }
+
+    /// Indica si el receptor ya no espera el valor
+    pub fn is_closed(&self) -> bool {
+        self.shared.lock().unwrap().rx_closed
+    }
+
+    /// Espera a que el receptor se suelte o cierre el canal
+    ///
+    /// Útil para abandonar un trabajo cuyo resultado ya nadie espera.
+    pub async fn closed(&mut self) {
+        poll_fn(|cx| {
+            let mut state = self.shared.lock().unwrap();
+            if state.rx_closed {
+                Poll::Ready(())
+            } else {
+                state.tx_waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut state = self.shared.lock().unwrap();
+            state.tx_dropped = true;
+            state.rx_waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Extremo receptor: es un futuro que se completa con el valor
+pub struct Receiver<T> {
+    shared: Shared<T>,
+}
+
+impl<T> Receiver<T> {
+    /// Recibe sin esperar
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let mut state = self.shared.lock().unwrap();
+        match state.value.take() {
+            Some(value) => Ok(value),
+            None if state.tx_dropped => Err(TryRecvError::Closed),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Rechaza el valor: los envíos posteriores fallarán
+    pub fn close(&mut self) {
+        let waker = {
+            let mut state = self.shared.lock().unwrap();
+            state.rx_closed = true;
+            state.tx_waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Future for Receiver<T> {
+    type Output = Result<T, RecvError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        coop::poll_budgeted(cx, |cx| {
+            let mut state = self.shared.lock().unwrap();
+            match state.value.take() {
+                Some(value) => Poll::Ready(Ok(value)),
+                None if state.tx_dropped => Poll::Ready(Err(RecvError(()))),
+                None => {
+                    state.rx_waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.close();
+        // Un valor enviado y nunca leído se suelta fuera del lock
+        let value = self.shared.lock().unwrap().value.take();
+        drop(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TryRecvError, channel};
+    use crate::executor::Executor;
+
+    #[test]
+    fn value_crosses_tasks() {
+        let mut executor = Executor::new();
+        let (tx, rx) = channel();
+
+        executor.spawn(async move {
+            tx.send(42).unwrap();
+        });
+
+        assert_eq!(executor.block_on(rx), Ok(42));
+    }
+
+    #[test]
+    fn dropped_ends_are_reported() {
+        let (tx, mut rx) = channel::<u32>();
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+        drop(tx);
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Closed));
+
+        let (tx, rx) = channel();
+        drop(rx);
+        assert!(tx.is_closed());
+        assert_eq!(tx.send(1), Err(1));
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    cell::UnsafeCell,
+    ops::{Deref, DerefMut},
+};
+
+use super::{
+    mutex::TryLockError,
+    semaphore::{Semaphore, SemaphorePermit},
+};
+
+/// Número máximo de lectores simultáneos
+///
+/// Un escritor toma todos los permisos a la vez, así excluye a los lectores.
+const MAX_READS: usize = u32::MAX as usize >> 3;
+
+/// Lock asíncrono de lectores y escritor
+///
+/// Varias tareas pueden leer a la vez; un escritor tiene acceso exclusivo.
+/// Las peticiones se atienden en orden de llegada: un escritor en espera
+/// impide que entren lectores nuevos, así que no puede quedar relegado.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::sync::RwLock;
+///
+/// async_runtime::block_on(async {
+///     let lock = RwLock::new(5);
+///     {
+///         let a = lock.read().await;
+///         let b = lock.read().await;
+///         assert_eq!(*a + *b, 10);
+///     }
+///     *lock.write().await += 1;
+///     assert_eq!(*lock.read().await, 6);
+/// });
+/// ```
+pub struct RwLock<T: ?Sized> {
+    semaphore: Semaphore,
+    data: UnsafeCell<T>,
+}
+
+// Seguro: los lectores comparten `&T` (exige `Sync`) y el escritor es exclusivo
+unsafe impl<T: ?Sized + Send> Send for RwLock<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for RwLock<T> {}
+
+impl<T> RwLock<T> {
+    pub fn new(value: T) -> Self {
+        RwLock {
+            semaphore: Semaphore::new(MAX_READS),
+            data: UnsafeCell::new(value),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    /// Espera acceso de lectura compartido
+    pub async fn read(&self) -> RwLockReadGuard<'_, T> {
+        let permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("el semáforo de un RwLock nunca se cierra");
+        RwLockReadGuard {
+            lock: self,
+            _permit: permit,
+        }
+    }
+
+    /// Espera acceso de escritura exclusivo
+    pub async fn write(&self) -> RwLockWriteGuard<'_, T> {
+        let permit = self
+            .semaphore
+            .acquire_many(MAX_READS)
+            .await
+            .expect("el semáforo de un RwLock nunca se cierra");
+        RwLockWriteGuard {
+            lock: self,
+            _permit: permit,
+        }
+    }
+
+    /// Toma acceso de lectura sin esperar
+    pub fn try_read(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        match self.semaphore.try_acquire() {
+            Ok(permit) => Ok(RwLockReadGuard {
+                lock: self,
+                _permit: permit,
+            }),
+            Err(_) => Err(TryLockError(())),
+        }
+    }
+
+    /// Toma acceso de escritura sin esperar
+    pub fn try_write(&self) -> Result<RwLockWriteGuard<'_, T>, TryLockError> {
+        match self.semaphore.try_acquire_many(MAX_READS) {
+            Ok(permit) => Ok(RwLockWriteGuard {
+                lock: self,
+                _permit: permit,
+            }),
+            Err(_) => Err(TryLockError(())),
+        }
+    }
+
+    /// Acceso directo: el préstamo mutable garantiza la exclusión
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T: Default> Default for RwLock<T> {
+    fn default() -> Self {
+        RwLock::new(T::default())
+    }
+}
+
+/// Acceso de lectura a un `RwLock`; lo libera al soltarse
+pub struct RwLockReadGuard<'a, T: ?Sized> {
+    lock: &'a RwLock<T>,
+    _permit: SemaphorePermit<'a>,
+}
+
+// Seguro: el guard solo da acceso compartido a `T`
+unsafe impl<T: ?Sized + Sync> Sync for RwLockReadGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for RwLockReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // Seguro: mientras haya lectores ningún escritor tiene todos los permisos
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+/// Acceso de escritura a un `RwLock`; lo libera al soltarse
+pub struct RwLockWriteGuard<'a, T: ?Sized> {
+    lock: &'a RwLock<T>,
+    _permit: SemaphorePermit<'a>,
+}
+
+// Seguro: el guard solo da acceso compartido a `T` a través de `&self`
+unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // Seguro: el escritor tiene todos los permisos, es decir, acceso exclusivo
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RwLock;
+    use crate::executor::Executor;
+    use std::sync::Arc;
+
+    #[test]
+    fn waiting_writer_blocks_new_readers() {
+        let mut executor = Executor::new();
+        let lock = Arc::new(RwLock::new(0));
+
+        let reader = lock.try_read().unwrap();
+        assert!(lock.try_write().is_err());
+        assert!(lock.try_read().is_ok());
+
+        let writer = executor.spawn({
+            let lock = lock.clone();
+            async move { *lock.write().await += 1 }
+        });
+        // El escritor queda en cola detrás del lector
+        while executor.has_ready_tasks() {
+            executor.poll();
+        }
+
+        // Un lector nuevo no puede adelantar al escritor en espera
+        assert!(lock.try_read().is_err());
+        drop(reader);
+
+        executor.block_on(writer).unwrap();
+        assert_eq!(*lock.try_read().unwrap(), 1);
+    }
+}
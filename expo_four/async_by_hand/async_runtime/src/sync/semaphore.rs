@@ -0,0 +1,450 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    error::Error,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use super::wait_queue::WaiterKey;
+use crate::task::coop;
+
+/// Error de `acquire`: el semáforo se cerró
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct AcquireError(());
+
+/// Error de `try_acquire`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TryAcquireError {
+    /// El semáforo se cerró
+    Closed,
+    /// No hay permisos suficientes (o hay tareas esperando antes)
+    NoPermits,
+}
+
+impl fmt::Display for AcquireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "el semáforo está cerrado")
+    }
+}
+
+impl Error for AcquireError {}
+
+impl fmt::Display for TryAcquireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryAcquireError::Closed => write!(f, "el semáforo está cerrado"),
+            TryAcquireError::NoPermits => write!(f, "no hay permisos disponibles"),
+        }
+    }
+}
+
+impl Error for TryAcquireError {}
+
+/// Tarea esperando permisos
+struct Waiter {
+    key: WaiterKey,
+    needed: usize,
+    waker: Waker,
+}
+
+struct State {
+    permits: usize,
+    closed: bool,
+    /// Esperas en orden de llegada
+    waiters: VecDeque<Waiter>,
+    /// Esperas que ya recibieron sus permisos y aún no lo han visto
+    granted: HashSet<WaiterKey>,
+    next_key: WaiterKey,
+}
+
+impl State {
+    /// Entrega permisos a las esperas del frente mientras alcancen
+    ///
+    /// Se detiene en la primera que no cabe: así ninguna tarea adelanta a
+    /// otra que llegó antes, aunque pida menos permisos.
+    fn assign(&mut self) -> Vec<Waker> {
+        let mut wakers = Vec::new();
+        while let Some(front) = self.waiters.front() {
+            if front.needed > self.permits {
+                break;
+            }
+            let waiter = self.waiters.pop_front().unwrap();
+            self.permits -= waiter.needed;
+            self.granted.insert(waiter.key);
+            wakers.push(waiter.waker);
+        }
+        wakers
+    }
+}
+
+/// Semáforo contador con cola FIFO de esperas
+///
+/// Los permisos se entregan en orden de llegada: cuando se liberan, pasan
+/// directamente a la primera tarea en espera, que no puede ser adelantada
+/// por otra que llegue después.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::sync::Semaphore;
+///
+/// async_runtime::block_on(async {
+///     let semaphore = Semaphore::new(2);
+///     let _a = semaphore.acquire().await.unwrap();
+///     let _b = semaphore.acquire().await.unwrap();
+///     assert_eq!(semaphore.available_permits(), 0);
+///     assert!(semaphore.try_acquire().is_err());
+/// });
+/// ```
+pub struct Semaphore {
+    state: Mutex<State>,
+}
+
+impl Semaphore {
+    /// Máximo de permisos que admite un semáforo
+    pub const MAX_PERMITS: usize = usize::MAX >> 3;
+
+    /// Crea un semáforo con `permits` permisos
+    pub fn new(permits: usize) -> Self {
+        assert!(
+            permits <= Self::MAX_PERMITS,
+            "un semáforo admite como máximo {} permisos",
+            Self::MAX_PERMITS
+        );
+        Semaphore {
+            state: Mutex::new(State {
+                permits,
+                closed: false,
+                waiters: VecDeque::new(),
+                granted: HashSet::new(),
+                next_key: 0,
+            }),
+        }
+    }
+
+    pub fn available_permits(&self) -> usize {
+        self.state.lock().unwrap().permits
+    }
+
+    /// Añade `n` permisos, despertando a las esperas que ya quepan
+    ///
+    /// # Pánico
+    /// Si el total de permisos superaría `MAX_PERMITS`
+    pub fn add_permits(&self, n: usize) {
+        let wakers = {
+            let mut state = self.state.lock().unwrap();
+            if n > Self::MAX_PERMITS - state.permits {
+                // Suelta el lock antes del pánico para no envenenarlo
+                drop(state);
+                panic!(
+                    "un semáforo admite como máximo {} permisos",
+                    Self::MAX_PERMITS
+                );
+            }
+            state.permits += n;
+            state.assign()
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Espera un permiso
+    pub async fn acquire(&self) -> Result<SemaphorePermit<'_>, AcquireError> {
+        self.acquire_many(1).await
+    }
+
+    /// Espera `n` permisos a la vez
+    ///
+    /// # Pánico
+    /// Si `n` supera `MAX_PERMITS`: la espera nunca podría completarse
+    pub async fn acquire_many(&self, n: usize) -> Result<SemaphorePermit<'_>, AcquireError> {
+        Acquire::new(self, n).await?;
+        Ok(SemaphorePermit {
+            semaphore: self,
+            permits: n,
+        })
+    }
+
+    /// Versión de `acquire` que no toma prestado el semáforo
+    pub async fn acquire_owned(self: Arc<Self>) -> Result<OwnedSemaphorePermit, AcquireError> {
+        self.acquire_many_owned(1).await
+    }
+
+    /// Versión de `acquire_many` que no toma prestado el semáforo
+    ///
+    /// # Pánico
+    /// Si `n` supera `MAX_PERMITS`
+    pub async fn acquire_many_owned(
+        self: Arc<Self>,
+        n: usize,
+    ) -> Result<OwnedSemaphorePermit, AcquireError> {
+        Acquire::new(&self, n).await?;
+        Ok(OwnedSemaphorePermit {
+            semaphore: self,
+            permits: n,
+        })
+    }
+
+    /// Toma un permiso sin esperar
+    pub fn try_acquire(&self) -> Result<SemaphorePermit<'_>, TryAcquireError> {
+        self.try_acquire_many(1)
+    }
+
+    /// Toma `n` permisos sin esperar
+    ///
+    /// Falla si hay tareas esperando, aunque haya permisos: no se adelanta a nadie.
+    pub fn try_acquire_many(&self, n: usize) -> Result<SemaphorePermit<'_>, TryAcquireError> {
+        self.try_take(n)?;
+        Ok(SemaphorePermit {
+            semaphore: self,
+            permits: n,
+        })
+    }
+
+    fn try_take(&self, n: usize) -> Result<(), TryAcquireError> {
+        let mut state = self.state.lock().unwrap();
+        if state.closed {
+            Err(TryAcquireError::Closed)
+        } else if state.waiters.is_empty() && state.permits >= n {
+            state.permits -= n;
+            Ok(())
+        } else {
+            Err(TryAcquireError::NoPermits)
+        }
+    }
+
+    /// Cierra el semáforo: las esperas actuales y futuras fallan
+    ///
+    /// Los permisos ya entregados siguen siendo válidos.
+    pub fn close(&self) {
+        let wakers: Vec<_> = {
+            let mut state = self.state.lock().unwrap();
+            state.closed = true;
+            state.waiters.drain(..).map(|waiter| waiter.waker).collect()
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.state.lock().unwrap().closed
+    }
+
+    fn release(&self, n: usize) {
+        self.add_permits(n);
+    }
+}
+
+/// Futuro que espera `needed` permisos
+struct Acquire<'a> {
+    semaphore: &'a Semaphore,
+    needed: usize,
+    key: Option<WaiterKey>,
+}
+
+impl<'a> Acquire<'a> {
+    fn new(semaphore: &'a Semaphore, needed: usize) -> Self {
+        assert!(
+            needed <= Semaphore::MAX_PERMITS,
+            "no se pueden pedir más de {} permisos",
+            Semaphore::MAX_PERMITS
+        );
+        Acquire {
+            semaphore,
+            needed,
+            key: None,
+        }
+    }
+}
+
+impl Future for Acquire<'_> {
+    type Output = Result<(), AcquireError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        coop::poll_budgeted(cx, |cx| self.poll_acquire(cx))
+    }
+}
+
+impl Acquire<'_> {
+    fn poll_acquire(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), AcquireError>> {
+        let this = self;
+        let mut state = this.semaphore.state.lock().unwrap();
+
+        if let Some(key) = this.key {
+            if state.granted.remove(&key) {
+                this.key = None;
+                return Poll::Ready(Ok(()));
+            }
+            if state.closed {
+                this.key = None;
+                return Poll::Ready(Err(AcquireError(())));
+            }
+            if let Some(waiter) = state.waiters.iter_mut().find(|waiter| waiter.key == key)
+                && !waiter.waker.will_wake(cx.waker())
+            {
+                waiter.waker = cx.waker().clone();
+            }
+            return Poll::Pending;
+        }
+
+        if state.closed {
+            return Poll::Ready(Err(AcquireError(())));
+        }
+        if state.waiters.is_empty() && state.permits >= this.needed {
+            state.permits -= this.needed;
+            return Poll::Ready(Ok(()));
+        }
+
+        let key = state.next_key;
+        state.next_key = state.next_key.wrapping_add(1);
+        state.waiters.push_back(Waiter {
+            key,
+            needed: this.needed,
+            waker: cx.waker().clone(),
+        });
+        this.key = Some(key);
+        Poll::Pending
+    }
+}
+
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        let Some(key) = self.key else { return };
+
+        let wakers = {
+            let mut state = self.semaphore.state.lock().unwrap();
+            if state.granted.remove(&key) {
+                // Recibió permisos que ya no usará: se devuelven
+                state.permits += self.needed;
+            } else {
+                state.waiters.retain(|waiter| waiter.key != key);
+            }
+            // Salir de la cola puede desbloquear a quien esperaba detrás
+            state.assign()
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+/// Permisos tomados de un `Semaphore`; se devuelven al soltarse
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+    permits: usize,
+}
+
+impl SemaphorePermit<'_> {
+    /// Consume los permisos sin devolverlos al semáforo
+    pub fn forget(mut self) {
+        self.permits = 0;
+    }
+}
+
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) {
+        if self.permits > 0 {
+            self.semaphore.release(self.permits);
+        }
+    }
+}
+
+/// Permisos tomados de un `Arc<Semaphore>`; se devuelven al soltarse
+pub struct OwnedSemaphorePermit {
+    semaphore: Arc<Semaphore>,
+    permits: usize,
+}
+
+impl OwnedSemaphorePermit {
+    /// Consume los permisos sin devolverlos al semáforo
+    pub fn forget(mut self) {
+        self.permits = 0;
+    }
+}
+
+impl Drop for OwnedSemaphorePermit {
+    fn drop(&mut self) {
+        if self.permits > 0 {
+            self.semaphore.release(self.permits);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Semaphore, TryAcquireError};
+    use crate::{executor::Executor, sync::mpsc};
+    use std::sync::Arc;
+
+    #[test]
+    fn waiters_are_served_in_order() {
+        let mut executor = Executor::new();
+        let semaphore = Arc::new(Semaphore::new(0));
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        // La primera tarea pide 2 permisos: las siguientes, aunque pidan
+        // 1, no pueden adelantarla
+        for (i, needed) in [(0, 2), (1, 1), (2, 1)] {
+            let semaphore = semaphore.clone();
+            let tx = tx.clone();
+            executor.spawn(async move {
+                let _permit = semaphore.acquire_many(needed).await.unwrap();
+                tx.send(i).unwrap();
+            });
+        }
+        drop(tx);
+
+        let order = executor.block_on(async move {
+            // Deja que las tres tareas se encolen antes de liberar permisos
+            crate::time::sleep(std::time::Duration::from_millis(10)).await;
+            semaphore.add_permits(1);
+            crate::time::sleep(std::time::Duration::from_millis(10)).await;
+            semaphore.add_permits(1);
+
+            let mut order = Vec::new();
+            while let Some(i) = rx.recv().await {
+                order.push(i);
+            }
+            order
+        });
+
+        assert_eq!(order, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn try_acquire_and_close() {
+        let semaphore = Semaphore::new(1);
+        let permit = semaphore.try_acquire().unwrap();
+        assert_eq!(
+            semaphore.try_acquire().err(),
+            Some(TryAcquireError::NoPermits)
+        );
+        drop(permit);
+        assert_eq!(semaphore.available_permits(), 1);
+
+        semaphore.try_acquire().unwrap().forget();
+        assert_eq!(semaphore.available_permits(), 0);
+
+        semaphore.close();
+        assert!(crate::block_on(semaphore.acquire()).is_err());
+    }
+
+    #[test]
+    fn permits_beyond_the_maximum_are_rejected() {
+        let semaphore = Semaphore::new(Semaphore::MAX_PERMITS - 1);
+        semaphore.add_permits(1);
+
+        let add = std::panic::catch_unwind(|| semaphore.add_permits(1));
+        assert!(add.is_err());
+        assert_eq!(semaphore.available_permits(), Semaphore::MAX_PERMITS);
+
+        let acquire = std::panic::catch_unwind(|| {
+            crate::block_on(semaphore.acquire_many(Semaphore::MAX_PERMITS + 1))
+        });
+        assert!(acquire.is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use std::{collections::VecDeque, task::Waker};
+
+/// Identificador de una espera dentro de una `WaitQueue`
+pub(crate) type WaiterKey = u64;
+
+/// Cola FIFO de tareas esperando a un recurso
+///
+/// Vive dentro del mutex del recurso. Cada futuro que espera guarda su
+/// clave para actualizar el waker en cada poll y para retirarse al
+/// soltarse. Si un futuro ya avisado se suelta sin consumir el aviso, debe
+/// pasarlo al siguiente (`remove` devuelve `false` en ese caso) para que
+/// no se pierda.
+#[derive(Default)]
+pub(crate) struct WaitQueue {
+    waiters: VecDeque<(WaiterKey, Waker)>,
+    next_key: WaiterKey,
+}
+
+impl WaitQueue {
+    /// Encola el waker, o lo actualiza si `key` ya está en la cola
+    ///
+    /// Un futuro avisado que vuelve a esperar se encola al final.
+    pub(crate) fn register(&mut self, key: &mut Option<WaiterKey>, waker: &Waker) {
+        if let Some(k) = *key
+            && let Some((_, current)) = self.waiters.iter_mut().find(|(other, _)| *other == k)
+        {
+            if !current.will_wake(waker) {
+                *current = waker.clone();
+            }
+            return;
+        }
+
+        let k = self.next_key;
+        self.next_key = self.next_key.wrapping_add(1);
+        self.waiters.push_back((k, waker.clone()));
+        *key = Some(k);
+    }
+
+    /// Retira una espera
+    ///
+    /// # Retorno
+    /// `true` si seguía en la cola; `false` si ya había sido avisada
+    pub(crate) fn remove(&mut self, key: WaiterKey) -> bool {
+        match self.waiters.iter().position(|(other, _)| *other == key) {
+            Some(index) => {
+                self.waiters.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Indica si la espera sigue en la cola (no ha sido avisada)
+    pub(crate) fn contains(&self, key: WaiterKey) -> bool {
+        self.waiters.iter().any(|(other, _)| *other == key)
+    }
+
+    /// Saca a la primera tarea de la cola; se despierta fuera del lock
+    pub(crate) fn wake_one(&mut self) -> Option<Waker> {
+        self.pop_front().map(|(_, waker)| waker)
+    }
+
+    /// Como `wake_one`, pero también devuelve la clave de la espera
+    pub(crate) fn pop_front(&mut self) -> Option<(WaiterKey, Waker)> {
+        self.waiters.pop_front()
+    }
+
+    /// Saca a todas las tareas de la cola; se despiertan fuera del lock
+    pub(crate) fn wake_all(&mut self) -> Vec<Waker> {
+        self.waiters.drain(..).map(|(_, waker)| waker).collect()
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt,
+    future::poll_fn,
+    ops::Deref,
+    sync::{Arc, Mutex, RwLock, RwLockReadGuard},
+    task::{Context, Poll, Waker},
+};
+
+/// Error al enviar: no queda ningún receptor; devuelve el valor
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct SendError<T>(pub T);
+
+/// Error de `changed`: el emisor se soltó
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RecvError(());
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SendError(..)")
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no quedan receptores")
+    }
+}
+
+impl<T> Error for SendError<T> {}
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "el emisor se cerró")
+    }
+}
+
+impl Error for RecvError {}
+
+struct State {
+    /// Se incrementa con cada valor nuevo
+    version: u64,
+    sender_dropped: bool,
+    receivers: usize,
+    /// Receptores esperando en `changed`, por identificador de receptor
+    waiters: HashMap<u64, Waker>,
+    next_receiver: u64,
+}
+
+struct Shared<T> {
+    value: RwLock<T>,
+    state: Mutex<State>,
+}
+
+impl<T> Shared<T> {
+    fn new_receiver(self: &Arc<Self>) -> Receiver<T> {
+        let mut state = self.state.lock().unwrap();
+        let id = state.next_receiver;
+        state.next_receiver += 1;
+        state.receivers += 1;
+        Receiver {
+            shared: self.clone(),
+            id,
+            seen: state.version,
+        }
+    }
+}
+
+/// Referencia al valor actual; mientras vive, el emisor no puede reemplazarlo
+pub struct Ref<'a, T> {
+    guard: RwLockReadGuard<'a, T>,
+}
+
+impl<T> Deref for Ref<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+/// Crea un canal que solo conserva el último valor enviado
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::sync::watch;
+///
+/// async_runtime::block_on(async {
+///     let (tx, mut rx) = watch::channel("inicio");
+///     tx.send("listo").unwrap();
+///     rx.changed().await.unwrap();
+///     assert_eq!(*rx.borrow_and_update(), "listo");
+/// });
+/// ```
+pub fn channel<T>(init: T) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        value: RwLock::new(init),
+        state: Mutex::new(State {
+            version: 0,
+            sender_dropped: false,
+            receivers: 0,
+            waiters: HashMap::new(),
+            next_receiver: 0,
+        }),
+    });
+    let receiver = shared.new_receiver();
+    (Sender { shared }, receiver)
+}
+
+/// Emisor de un canal watch
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Reemplaza el valor y avisa a los receptores
+    ///
+    /// # Retorno
+    /// `SendError` si no queda ningún receptor; el valor no se guarda
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        if self.shared.state.lock().unwrap().receivers == 0 {
+            return Err(SendError(value));
+        }
+        self.send_replace(value);
+        Ok(())
+    }
+
+    /// Reemplaza el valor aunque no haya receptores y devuelve el anterior
+    pub fn send_replace(&self, value: T) -> T {
+        let old = std::mem::replace(&mut *self.shared.value.write().unwrap(), value);
+
+        let waiters: Vec<_> = {
+            let mut state = self.shared.state.lock().unwrap();
+            state.version += 1;
+            state.waiters.drain().map(|(_, waker)| waker).collect()
+        };
+        for waker in waiters {
+            waker.wake();
+        }
+        old
+    }
+
+    /// Valor actual
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref {
+            guard: self.shared.value.read().unwrap(),
+        }
+    }
+
+    /// Crea un receptor que considera visto el valor actual
+    pub fn subscribe(&self) -> Receiver<T> {
+        self.shared.new_receiver()
+    }
+
+    pub fn receiver_count(&self) -> usize {
+        self.shared.state.lock().unwrap().receivers
+    }
+
+    /// Indica si ya no queda ningún receptor
+    pub fn is_closed(&self) -> bool {
+        self.receiver_count() == 0
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let waiters: Vec<_> = {
+            let mut state = self.shared.state.lock().unwrap();
+            state.sender_dropped = true;
+            state.waiters.drain().map(|(_, waker)| waker).collect()
+        };
+        for waker in waiters {
+            waker.wake();
+        }
+    }
+}
+
+/// Receptor de un canal watch
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    id: u64,
+    /// Versión del último valor marcado como visto
+    seen: u64,
+}
+
+impl<T> Receiver<T> {
+    /// Valor actual, sin marcarlo como visto
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref {
+            guard: self.shared.value.read().unwrap(),
+        }
+    }
+
+    /// Valor actual, marcándolo como visto
+    pub fn borrow_and_update(&mut self) -> Ref<'_, T> {
+        let guard = self.shared.value.read().unwrap();
+        // Con el lock de lectura tomado la versión no puede avanzar
+        self.seen = self.shared.state.lock().unwrap().version;
+        Ref { guard }
+    }
+
+    /// Indica si hay un valor que este receptor no ha visto
+    pub fn has_changed(&self) -> Result<bool, RecvError> {
+        let state = self.shared.state.lock().unwrap();
+        if state.version != self.seen {
+            Ok(true)
+        } else if state.sender_dropped {
+            Err(RecvError(()))
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Espera a que haya un valor no visto y lo marca como visto
+    ///
+    /// Falla si el emisor se suelta sin que haya valores nuevos.
+    pub async fn changed(&mut self) -> Result<(), RecvError> {
+        poll_fn(|cx| self.poll_changed(cx)).await
+    }
+
+    /// Versión de `changed` para quien implementa `poll` a mano
+    pub(crate) fn poll_changed(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), RecvError>> {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.version != self.seen {
+            self.seen = state.version;
+            Poll::Ready(Ok(()))
+        } else if state.sender_dropped {
+            Poll::Ready(Err(RecvError(())))
+        } else {
+            state.waiters.insert(self.id, cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        let mut receiver = self.shared.new_receiver();
+        receiver.seen = self.seen;
+        receiver
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.receivers -= 1;
+        let waker = state.waiters.remove(&self.id);
+        drop(state);
+        drop(waker);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::channel;
+    use crate::executor::Executor;
+
+    #[test]
+    fn receivers_see_the_latest_value() {
+        let mut executor = Executor::new();
+        let (tx, mut rx) = channel(0);
+
+        let watcher = executor.spawn(async move {
+            let mut seen = Vec::new();
+            while rx.changed().await.is_ok() {
+                seen.push(*rx.borrow_and_update());
+            }
+            seen
+        });
+
+        executor.spawn(async move {
+            tx.send(1).unwrap();
+            // Sin ceder el control: el receptor solo verá el último valor
+            tx.send(2).unwrap();
+        });
+
+        let seen = executor.block_on(watcher).unwrap();
+        assert_eq!(seen, vec![2]);
+    }
+
+    #[test]
+    fn has_changed_tracks_versions() {
+        let (tx, mut rx) = channel("a");
+        assert_eq!(rx.has_changed(), Ok(false));
+        tx.send("b").unwrap();
+        assert_eq!(rx.has_changed(), Ok(true));
+        assert_eq!(*rx.borrow_and_update(), "b");
+        assert_eq!(rx.has_changed(), Ok(false));
+
+        drop(tx);
+        assert!(rx.has_changed().is_err());
+    }
+}
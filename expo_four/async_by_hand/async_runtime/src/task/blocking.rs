@@ -0,0 +1,42 @@
+use super::{JoinHandle, joinable};
+use crate::blocking;
+
+/// Ejecuta `f` en un hilo dedicado a trabajo bloqueante
+///
+/// Para código que bloquearía al worker que lo sondea: E/S de archivos,
+/// `thread::sleep`, cálculos largos, ... Dentro de un `Runtime` usa su pool
+/// (ver `Builder::max_blocking_threads`); fuera de él, un pool global.
+///
+/// El resultado vuelve por el `JoinHandle`, que puede esperarse con
+/// `.await` sin bloquear. Un pánico en `f` llega como
+/// `JoinError::Panicked`. `abort` solo tiene efecto si `f` aún no empezó.
+///
+/// # Ejemplo
+/// ```
+/// use std::{thread, time::Duration};
+///
+/// async_runtime::block_on(async {
+///     let handle = async_runtime::spawn_blocking(|| {
+///         thread::sleep(Duration::from_millis(10));
+///         "listo"
+///     });
+///     assert_eq!(handle.await.unwrap(), "listo");
+/// });
+/// ```
+pub fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    spawn_blocking_inner(f, None)
+}
+
+pub(crate) fn spawn_blocking_inner<F, T>(f: F, name: Option<String>) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (job, handle) = joinable(async move { f() }, name);
+    blocking::current().spawn(job);
+    handle
+}
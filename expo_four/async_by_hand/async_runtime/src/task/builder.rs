@@ -0,0 +1,64 @@
+use std::future::Future;
+
+use super::{JoinHandle, blocking::spawn_blocking_inner};
+use crate::{executor::Executor, runtime::Runtime};
+
+/// Configura una tarea antes de lanzarla
+///
+/// Por ahora solo admite un nombre, que queda en los metadatos de la tarea
+/// junto a su identificador (ver `task::current`).
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::{Runtime, task};
+///
+/// let runtime = Runtime::new(2).unwrap();
+/// let handle = task::Builder::new()
+///     .name("contador")
+///     .spawn_on(async { 1 + 1 }, &runtime);
+/// assert_eq!(handle.name(), Some("contador"));
+/// assert_eq!(handle.block_on().unwrap(), 2);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Builder {
+    name: Option<String>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Builder::default()
+    }
+
+    /// Nombre de la tarea
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Lanza la tarea en un `Runtime`
+    pub fn spawn_on<F, T>(self, future: F, runtime: &Runtime) -> JoinHandle<T>
+    where
+        F: Future<Output = T> + 'static + Send,
+        T: Send + 'static,
+    {
+        runtime.spawn_inner(future, self.name)
+    }
+
+    /// Lanza la tarea en un `Executor` de un solo hilo
+    pub fn spawn_on_executor<F, T>(self, future: F, executor: &mut Executor) -> JoinHandle<T>
+    where
+        F: Future<Output = T> + 'static + Send,
+        T: Send + 'static,
+    {
+        executor.spawn_inner(future, self.name)
+    }
+
+    /// Lanza `f` en el pool de hilos bloqueantes (ver `spawn_blocking`)
+    pub fn spawn_blocking<F, T>(self, f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        spawn_blocking_inner(f, self.name)
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    cell::Cell,
+    task::{Context, Poll},
+};
+
+/// Operaciones sobre recursos del runtime que una tarea puede completar
+/// en un mismo sondeo antes de ceder el hilo
+const BUDGET: u32 = 128;
+
+thread_local! {
+    /// Presupuesto restante de la tarea que se está sondeando
+    ///
+    /// `None` fuera de un ejecutor: ahí no hay nadie a quien ceder el turno.
+    static REMAINING: Cell<Option<u32>> = const { Cell::new(None) };
+}
+
+/// Restaura el presupuesto anterior al terminar el sondeo
+struct ResetGuard {
+    previous: Option<u32>,
+}
+
+impl Drop for ResetGuard {
+    fn drop(&mut self) {
+        REMAINING.with(|remaining| remaining.set(self.previous));
+    }
+}
+
+/// Ejecuta `f` (el sondeo de una tarea) con el presupuesto completo
+pub(crate) fn budget<R>(f: impl FnOnce() -> R) -> R {
+    let previous = REMAINING.with(|remaining| remaining.replace(Some(BUDGET)));
+    let _reset = ResetGuard { previous };
+    f()
+}
+
+/// Unidad de presupuesto tomada por una operación
+///
+/// Si la operación termina en `Pending` la unidad se devuelve al soltarse:
+/// solo cuenta el trabajo que realmente avanzó.
+struct RestoreOnPending(Option<u32>);
+
+impl RestoreOnPending {
+    /// La operación avanzó: la unidad queda consumida
+    fn made_progress(&mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for RestoreOnPending {
+    fn drop(&mut self) {
+        if let Some(previous) = self.0 {
+            REMAINING.with(|remaining| remaining.set(Some(previous)));
+        }
+    }
+}
+
+/// Toma una unidad del presupuesto de la tarea actual
+///
+/// Con el presupuesto agotado devuelve `Pending` tras despertar a la
+/// tarea: así vuelve al final de la cola y el resto de tareas, los
+/// temporizadores y la E/S tienen su turno, aunque el recurso estuviera
+/// listo.
+fn poll_proceed(cx: &mut Context<'_>) -> Poll<RestoreOnPending> {
+    REMAINING.with(|remaining| match remaining.get() {
+        None => Poll::Ready(RestoreOnPending(None)),
+        Some(0) => {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+        Some(n) => {
+            remaining.set(Some(n - 1));
+            Poll::Ready(RestoreOnPending(Some(n)))
+        }
+    })
+}
+
+/// Sondea `f` como una operación sobre un recurso del runtime
+///
+/// Si la tarea agotó su presupuesto, `f` ni se llama; si `f` devuelve
+/// `Ready`, consume una unidad.
+pub(crate) fn poll_budgeted<T>(
+    cx: &mut Context<'_>,
+    f: impl FnOnce(&mut Context<'_>) -> Poll<T>,
+) -> Poll<T> {
+    let mut coop = match poll_proceed(cx) {
+        Poll::Ready(coop) => coop,
+        Poll::Pending => return Poll::Pending,
+    };
+    let poll = f(cx);
+    if poll.is_ready() {
+        coop.made_progress();
+    }
+    poll
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BUDGET;
+    use crate::{executor::Executor, sync::mpsc};
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    #[test]
+    fn always_ready_channel_yields_after_budget() {
+        let mut executor = Executor::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        for i in 0..1000 {
+            tx.send(i).unwrap();
+        }
+
+        let received = Arc::new(AtomicUsize::new(0));
+        executor.spawn({
+            let received = received.clone();
+            async move {
+                while rx.recv().await.is_some() {
+                    received.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+        });
+
+        // Un solo sondeo: el canal siempre tiene datos, pero la tarea cede
+        // al agotar su presupuesto
+        executor.poll();
+        assert_eq!(received.load(Ordering::SeqCst), BUDGET as usize);
+        assert!(executor.has_ready_tasks());
+
+        executor.poll();
+        assert_eq!(received.load(Ordering::SeqCst), 2 * BUDGET as usize);
+    }
+}
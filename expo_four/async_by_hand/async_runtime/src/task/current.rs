@@ -0,0 +1,141 @@
+use crate::metrics::TaskStats;
+use std::{
+    cell::RefCell,
+    fmt,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+/// Contador global: los identificadores no se repiten entre ejecutores
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// Tarea que se está sondeando en este hilo
+    static CURRENT: RefCell<Option<TaskInfo>> = const { RefCell::new(None) };
+}
+
+/// Identificador único de una tarea en el proceso
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u64);
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Metadatos de una tarea: su identificador y, si se le dio, su nombre
+#[derive(Clone)]
+pub struct TaskInfo {
+    id: Id,
+    name: Option<Arc<str>>,
+    /// Contadores que muestran `metrics::Dump` y compañía
+    stats: Arc<TaskStats>,
+}
+
+impl TaskInfo {
+    /// Metadatos de una tarea nueva, con un identificador sin usar
+    pub(crate) fn new(name: Option<String>) -> Self {
+        TaskInfo {
+            id: Id(NEXT_ID.fetch_add(1, Ordering::Relaxed)),
+            name: name.map(Arc::from),
+            stats: Arc::default(),
+        }
+    }
+
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub(crate) fn stats(&self) -> &Arc<TaskStats> {
+        &self.stats
+    }
+}
+
+impl fmt::Debug for TaskInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskInfo")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Restaura la tarea anterior al terminar el sondeo
+pub(crate) struct EnterGuard {
+    previous: Option<TaskInfo>,
+}
+
+impl Drop for EnterGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Marca `info` como la tarea actual mientras viva el guard
+pub(crate) fn enter(info: TaskInfo) -> EnterGuard {
+    let previous = CURRENT.with(|current| current.borrow_mut().replace(info));
+    EnterGuard { previous }
+}
+
+/// Metadatos de la tarea que se está ejecutando
+///
+/// # Pánico
+/// Si se llama fuera de una tarea lanzada con `spawn`, `spawn_named` o
+/// `spawn_blocking`
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::{executor::Executor, task};
+///
+/// let mut executor = Executor::new();
+/// let handle = executor.spawn_named("saludo", async {
+///     task::current().name().map(String::from)
+/// });
+/// let name = executor.block_on(handle).unwrap();
+/// assert_eq!(name.as_deref(), Some("saludo"));
+/// ```
+pub fn current() -> TaskInfo {
+    try_current().expect("task::current debe llamarse dentro de una tarea de async_runtime")
+}
+
+/// Como `current`, pero devuelve `None` fuera de una tarea
+pub fn try_current() -> Option<TaskInfo> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{current, try_current};
+    use crate::{executor::Executor, task::Builder};
+
+    #[test]
+    fn spawned_tasks_see_their_own_metadata() {
+        let mut executor = Executor::new();
+        let named = executor.spawn_named("a", async { current() });
+        let unnamed = executor.spawn(async { current() });
+        let blocking = Builder::new().name("b").spawn_blocking(current);
+        let (named_id, unnamed_id, blocking_id) = (named.id(), unnamed.id(), blocking.id());
+
+        let (named, unnamed, blocking) = executor.block_on(async move {
+            (
+                named.await.unwrap(),
+                unnamed.await.unwrap(),
+                blocking.await.unwrap(),
+            )
+        });
+
+        assert_eq!((named.id(), named.name()), (named_id, Some("a")));
+        assert_eq!((unnamed.id(), unnamed.name()), (unnamed_id, None));
+        assert_eq!((blocking.id(), blocking.name()), (blocking_id, Some("b")));
+        assert_ne!(named_id, unnamed_id);
+        assert!(try_current().is_none());
+    }
+}
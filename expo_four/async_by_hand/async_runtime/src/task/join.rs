@@ -0,0 +1,331 @@
+use std::{
+    any::Any,
+    error::Error,
+    fmt,
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{Arc, Condvar, Mutex},
+    task::{Context, Poll, Waker},
+    time::Instant,
+};
+
+use super::{
+    coop,
+    current::{self, Id, TaskInfo},
+};
+use crate::executor::BoxFuture;
+
+/// Motivo por el que una tarea no produjo su resultado
+pub enum JoinError {
+    /// La tarea fue cancelada con `abort` o descartada por su ejecutor
+    Cancelled,
+    /// La tarea entró en pánico; contiene el valor del pánico
+    Panicked(Box<dyn Any + Send + 'static>),
+}
+
+impl JoinError {
+    /// Indica si la tarea fue cancelada
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, JoinError::Cancelled)
+    }
+
+    /// Indica si la tarea terminó en pánico
+    pub fn is_panic(&self) -> bool {
+        matches!(self, JoinError::Panicked(_))
+    }
+
+    /// Recupera el valor del pánico para relanzarlo con `panic::resume_unwind`
+    ///
+    /// # Pánico
+    /// Si la tarea no terminó en pánico
+    pub fn into_panic(self) -> Box<dyn Any + Send + 'static> {
+        match self {
+            JoinError::Panicked(payload) => payload,
+            JoinError::Cancelled => panic!("la tarea fue cancelada, no entró en pánico"),
+        }
+    }
+}
+
+impl fmt::Debug for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinError::Cancelled => write!(f, "JoinError::Cancelled"),
+            JoinError::Panicked(_) => write!(f, "JoinError::Panicked(..)"),
+        }
+    }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinError::Cancelled => write!(f, "la tarea fue cancelada"),
+            JoinError::Panicked(payload) => {
+                // Los pánicos con mensaje llevan un &str o un String
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+                match message {
+                    Some(message) => write!(f, "la tarea entró en pánico: {}", message),
+                    None => write!(f, "la tarea entró en pánico"),
+                }
+            }
+        }
+    }
+}
+
+impl Error for JoinError {}
+
+/// Estado compartido entre la tarea envuelta y su `JoinHandle`
+struct JoinState<T> {
+    /// Resultado pendiente de recoger
+    output: Option<Result<T, JoinError>>,
+    /// La tarea ya terminó (aunque el resultado ya se haya recogido)
+    finished: bool,
+    /// Se pidió cancelar la tarea
+    aborted: bool,
+    /// Waker de quien espera el resultado con `.await`
+    join_waker: Option<Waker>,
+    /// Waker de la propia tarea, para despertarla al cancelarla
+    task_waker: Option<Waker>,
+}
+
+struct Shared<T> {
+    state: Mutex<JoinState<T>>,
+    /// Avisa a quien espera de forma bloqueante con `JoinHandle::block_on`
+    done: Condvar,
+}
+
+impl<T> Shared<T> {
+    /// Publica el resultado y despierta a quien lo espera
+    fn complete(&self, result: Result<T, JoinError>) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            if state.finished {
+                return;
+            }
+            state.output = Some(result);
+            state.finished = true;
+            state.task_waker = None;
+            state.join_waker.take()
+        };
+
+        self.done.notify_all();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Futuro que envuelve a la tarea del usuario
+///
+/// Antes de cada sondeo comprueba si la tarea fue cancelada, atrapa los
+/// pánicos del futuro interno y publica el resultado en el `JoinHandle`.
+struct Harness<F: Future> {
+    future: Pin<Box<F>>,
+    shared: Arc<Shared<F::Output>>,
+    /// Lo que `task::current` devuelve mientras se sondea el futuro
+    info: TaskInfo,
+}
+
+impl<F: Future> Future for Harness<F> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        {
+            let mut state = self.shared.state.lock().unwrap();
+            if state.aborted {
+                drop(state);
+                self.shared.complete(Err(JoinError::Cancelled));
+                return Poll::Ready(());
+            }
+
+            // Guarda el waker actual para poder despertar la tarea en `abort`
+            match &state.task_waker {
+                Some(waker) if waker.will_wake(cx.waker()) => {}
+                _ => state.task_waker = Some(cx.waker().clone()),
+            }
+        }
+
+        let _current = current::enter(self.info.clone());
+        let start = Instant::now();
+        let future = self.future.as_mut();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| future.poll(cx)));
+        self.info.stats().record_poll(start.elapsed());
+
+        match result {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(output)) => {
+                self.shared.complete(Ok(output));
+                Poll::Ready(())
+            }
+            Err(payload) => {
+                self.shared.complete(Err(JoinError::Panicked(payload)));
+                Poll::Ready(())
+            }
+        }
+    }
+}
+
+impl<F: Future> Drop for Harness<F> {
+    /// Una tarea descartada sin terminar cuenta como cancelada
+    fn drop(&mut self) {
+        self.shared.complete(Err(JoinError::Cancelled));
+    }
+}
+
+/// Manejador para recuperar el resultado de una tarea asíncrona
+///
+/// Permite esperar la tarea con `.await` desde otro futuro, bloquear el
+/// hilo actual hasta que termine, consultar si ya terminó o cancelarla.
+/// Soltar el manejador no cancela la tarea: solo se pierde su resultado.
+pub struct JoinHandle<T> {
+    shared: Arc<Shared<T>>,
+    info: TaskInfo,
+}
+
+impl<T> JoinHandle<T> {
+    /// Identificador de la tarea
+    pub fn id(&self) -> Id {
+        self.info.id()
+    }
+
+    /// Nombre de la tarea, si se lanzó con uno
+    pub fn name(&self) -> Option<&str> {
+        self.info.name()
+    }
+
+    pub(crate) fn info(&self) -> &TaskInfo {
+        &self.info
+    }
+
+    /// Cancela la tarea
+    ///
+    /// La tarea se despierta para que su ejecutor la descarte en el
+    /// siguiente sondeo; quien la espere recibirá `JoinError::Cancelled`.
+    /// No tiene efecto si la tarea ya terminó.
+    pub fn abort(&self) {
+        let waker = {
+            let mut state = self.shared.state.lock().unwrap();
+            if state.finished {
+                return;
+            }
+            state.aborted = true;
+            state.task_waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    /// Indica si la tarea ya terminó, con éxito, cancelada o en pánico
+    pub fn is_finished(&self) -> bool {
+        self.shared.state.lock().unwrap().finished
+    }
+
+    /// Bloquea el hilo actual hasta obtener el resultado de la tarea
+    ///
+    /// # Retorno
+    /// - `Ok(T)` si la tarea terminó con éxito
+    /// - `Err(JoinError)` si fue cancelada o entró en pánico
+    pub fn block_on(self) -> Result<T, JoinError> {
+        let mut state = self.shared.state.lock().unwrap();
+        loop {
+            if let Some(output) = state.output.take() {
+                return output;
+            }
+            state = self.shared.done.wait(state).unwrap();
+        }
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    /// Recoge el resultado si la tarea ya terminó; si no, guarda el waker
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        coop::poll_budgeted(cx, |cx| {
+            let mut state = self.shared.state.lock().unwrap();
+
+            if let Some(output) = state.output.take() {
+                return Poll::Ready(output);
+            }
+
+            state.join_waker = Some(cx.waker().clone());
+            Poll::Pending
+        })
+    }
+}
+
+/// Envuelve un futuro para que publique su resultado en un `JoinHandle`
+///
+/// # Comportamiento
+/// El futuro devuelto:
+/// 1. Termina de inmediato si la tarea fue cancelada con `abort`
+/// 2. Sondea el futuro interno atrapando sus pánicos
+/// 3. Publica el resultado (o el error) y despierta a quien lo espera
+///
+/// Lo comparten todos los ejecutores del crate. `name` queda, junto con un
+/// identificador nuevo, en los metadatos de la tarea.
+pub(crate) fn joinable<F, T>(future: F, name: Option<String>) -> (BoxFuture, JoinHandle<T>)
+where
+    F: Future<Output = T> + 'static + Send,
+    T: Send + 'static,
+{
+    let shared = Arc::new(Shared {
+        state: Mutex::new(JoinState {
+            output: None,
+            finished: false,
+            aborted: false,
+            join_waker: None,
+            task_waker: None,
+        }),
+        done: Condvar::new(),
+    });
+
+    let info = TaskInfo::new(name);
+    let harness = Harness {
+        future: Box::pin(future),
+        shared: shared.clone(),
+        info: info.clone(),
+    };
+
+    (Box::pin(harness), JoinHandle { shared, info })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JoinError;
+    use crate::executor::Executor;
+    use std::future;
+
+    #[test]
+    fn abort_cancels_a_pending_task() {
+        let mut executor = Executor::new();
+        let handle = executor.spawn(future::pending::<()>());
+
+        executor.poll();
+        assert!(!handle.is_finished());
+
+        handle.abort();
+        executor.poll();
+
+        assert!(handle.is_finished());
+        assert!(matches!(handle.block_on(), Err(JoinError::Cancelled)));
+        assert!(executor.is_empty());
+    }
+
+    #[test]
+    fn panics_come_back_as_join_errors() {
+        let mut executor = Executor::new();
+        let handle = executor.spawn(async {
+            panic!("boom");
+        });
+
+        let error = executor.block_on(handle).unwrap_err();
+        assert!(error.is_panic());
+        assert_eq!(error.to_string(), "la tarea entró en pánico: boom");
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    cell::RefCell,
+    error::Error,
+    fmt,
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+    thread,
+};
+
+/// Error de `LocalKey::try_with`: el valor no está definido aquí
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct AccessError(());
+
+impl fmt::Display for AccessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "el task-local no tiene valor fuera de su scope")
+    }
+}
+
+impl Error for AccessError {}
+
+/// Clave de un valor local a la tarea, declarada con `task_local!`
+///
+/// A diferencia de un `thread_local!`, el valor acompaña al futuro a través
+/// de sus `.await`, aunque cada sondeo ocurra en un worker distinto: se
+/// instala en el hilo justo antes de sondearlo y se retira al terminar.
+pub struct LocalKey<T: 'static> {
+    #[doc(hidden)]
+    pub inner: thread::LocalKey<RefCell<Option<T>>>,
+}
+
+impl<T: 'static> LocalKey<T> {
+    /// Ejecuta `future` con `value` como valor de la clave
+    ///
+    /// # Ejemplo
+    /// ```
+    /// async_runtime::task_local! {
+    ///     static REQUEST_ID: u32;
+    /// }
+    ///
+    /// async_runtime::block_on(REQUEST_ID.scope(7, async {
+    ///     async_runtime::task::yield_now().await;
+    ///     assert_eq!(REQUEST_ID.get(), 7);
+    /// }));
+    /// ```
+    pub fn scope<F: Future>(&'static self, value: T, future: F) -> TaskLocalFuture<T, F> {
+        TaskLocalFuture {
+            key: self,
+            slot: Some(value),
+            future: Some(Box::pin(future)),
+        }
+    }
+
+    /// Ejecuta `f` con `value` como valor de la clave
+    pub fn sync_scope<F, R>(&'static self, value: T, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let mut slot = Some(value);
+        self.enter(&mut slot, f)
+            .expect("no se puede abrir un scope mientras se usa el valor con `with`")
+    }
+
+    /// Da acceso al valor actual
+    ///
+    /// # Pánico
+    /// Si se llama fuera de un `scope` de esta clave
+    pub fn with<F, R>(&'static self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.try_with(f)
+            .expect("task-local usado fuera de su scope")
+    }
+
+    /// Como `with`, pero devuelve un error fuera de un `scope`
+    pub fn try_with<F, R>(&'static self, f: F) -> Result<R, AccessError>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.inner
+            .try_with(|cell| cell.borrow().as_ref().map(f))
+            .ok()
+            .flatten()
+            .ok_or(AccessError(()))
+    }
+
+    /// Copia del valor actual
+    ///
+    /// # Pánico
+    /// Si se llama fuera de un `scope` de esta clave
+    pub fn get(&'static self) -> T
+    where
+        T: Clone,
+    {
+        self.with(T::clone)
+    }
+
+    /// Instala el contenido de `slot` durante `f` y lo devuelve después
+    ///
+    /// El intercambio se deshace aunque `f` entre en pánico. Falla si el
+    /// valor está prestado (dentro de un `with`) o el hilo está terminando.
+    fn enter<F, R>(&'static self, slot: &mut Option<T>, f: F) -> Result<R, AccessError>
+    where
+        F: FnOnce() -> R,
+    {
+        struct Guard<'a, T: 'static> {
+            key: &'static LocalKey<T>,
+            slot: &'a mut Option<T>,
+        }
+
+        impl<T: 'static> Drop for Guard<'_, T> {
+            fn drop(&mut self) {
+                self.key
+                    .inner
+                    .with(|cell| mem::swap(self.slot, &mut *cell.borrow_mut()));
+            }
+        }
+
+        self.inner
+            .try_with(|cell| {
+                cell.try_borrow_mut()
+                    .map(|mut value| mem::swap(slot, &mut *value))
+            })
+            .map_err(|_| AccessError(()))?
+            .map_err(|_| AccessError(()))?;
+
+        let _guard = Guard { key: self, slot };
+        Ok(f())
+    }
+}
+
+impl<T: 'static> fmt::Debug for LocalKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalKey").finish_non_exhaustive()
+    }
+}
+
+/// Futuro devuelto por `LocalKey::scope`
+pub struct TaskLocalFuture<T: 'static, F> {
+    key: &'static LocalKey<T>,
+    /// El valor mientras el futuro no se está sondeando
+    slot: Option<T>,
+    future: Option<Pin<Box<F>>>,
+}
+
+// `future` está en el heap: mover el envoltorio no lo mueve
+impl<T: 'static, F> Unpin for TaskLocalFuture<T, F> {}
+
+impl<T: 'static, F: Future> Future for TaskLocalFuture<T, F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        let this = &mut *self;
+        let future = &mut this.future;
+        this.key
+            .enter(&mut this.slot, || {
+                let poll = future
+                    .as_mut()
+                    .expect("TaskLocalFuture sondeado después de completarse")
+                    .as_mut()
+                    .poll(cx);
+                if poll.is_ready() {
+                    // Se suelta dentro del scope, igual que se ejecutó
+                    *future = None;
+                }
+                poll
+            })
+            .expect("no se puede sondear un scope mientras se usa el valor con `with`")
+    }
+}
+
+impl<T: 'static, F> Drop for TaskLocalFuture<T, F> {
+    fn drop(&mut self) {
+        if let Some(future) = self.future.take() {
+            // Si no se puede instalar el valor, el futuro se suelta sin él
+            let _ = self.key.enter(&mut self.slot, || drop(future));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{runtime::Runtime, task::yield_now};
+
+    crate::task_local! {
+        static REQUEST_ID: u32;
+    }
+
+    #[test]
+    fn value_follows_its_task_across_awaits() {
+        let runtime = Runtime::new(4).unwrap();
+
+        let handles: Vec<_> = (0..16)
+            .map(|id| {
+                runtime.spawn(REQUEST_ID.scope(id, async move {
+                    for _ in 0..50 {
+                        yield_now().await;
+                        assert_eq!(REQUEST_ID.get(), id);
+                    }
+                    // Un scope anidado tapa el valor solo mientras dura
+                    REQUEST_ID.sync_scope(id + 100, || assert_eq!(REQUEST_ID.get(), id + 100));
+                    REQUEST_ID.get()
+                }))
+            })
+            .collect();
+
+        for (id, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.block_on().unwrap(), id as u32);
+        }
+        // Fuera de cualquier scope no hay valor
+        assert!(REQUEST_ID.try_with(|_| ()).is_err());
+    }
+}
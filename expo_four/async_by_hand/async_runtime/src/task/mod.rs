@@ -0,0 +1,15 @@
+mod blocking;
+mod builder;
+pub(crate) mod coop;
+mod current;
+mod join;
+mod local;
+mod yield_now;
+
+pub use blocking::spawn_blocking;
+pub use builder::Builder;
+pub use current::{Id, TaskInfo, current, try_current};
+pub(crate) use join::joinable;
+pub use join::{JoinError, JoinHandle};
+pub use local::{AccessError, LocalKey, TaskLocalFuture};
+pub use yield_now::{YieldNow, yield_now};
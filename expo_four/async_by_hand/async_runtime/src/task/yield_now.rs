@@ -0,0 +1,76 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Futuro devuelto por `yield_now`
+pub struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        // Vuelve a la cola de listas detrás de las tareas ya despertadas
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// Cede el turno a las demás tareas del ejecutor
+///
+/// La tarea queda lista de nuevo de inmediato, pero se sondea después de
+/// las que ya esperaban. Útil en bucles largos que no esperan a ningún
+/// recurso y acapararían el hilo.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::task::yield_now;
+///
+/// async_runtime::block_on(async {
+///     for _ in 0..3 {
+///         yield_now().await;
+///     }
+/// });
+/// ```
+pub fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::yield_now;
+    use crate::{executor::Executor, sync::mpsc};
+
+    #[test]
+    fn yielding_tasks_take_turns() {
+        let mut executor = Executor::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        for name in ['a', 'b'] {
+            let tx = tx.clone();
+            executor.spawn(async move {
+                for _ in 0..3 {
+                    tx.send(name).unwrap();
+                    yield_now().await;
+                }
+            });
+        }
+        drop(tx);
+
+        let order = executor.block_on(async move {
+            let mut order = String::new();
+            while let Some(name) = rx.recv().await {
+                order.push(name);
+            }
+            order
+        });
+        assert_eq!(order, "ababab");
+    }
+}
@@ -0,0 +1,270 @@
+use std::{
+    env,
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    context,
+    driver::Handle,
+    executor::Executor,
+    future,
+    task::{JoinHandle, yield_now},
+    time::Clock,
+};
+
+/// Variable de entorno con la que `Builder::new` toma su semilla
+pub const SEED_VAR: &str = "ASYNC_RUNTIME_SEED";
+
+/// Configura un runtime de pruebas determinista
+///
+/// El runtime tiene un solo hilo, elige entre las tareas listas con un
+/// generador pseudoaleatorio sembrado y mide el tiempo con un reloj
+/// simulado: `sleep`, `timeout` e `interval` no esperan de verdad. Con la
+/// misma semilla, una prueba sin E/S real se ejecuta siempre en el mismo
+/// orden; ante un fallo, la semilla se muestra para poder repetirlo.
+///
+/// Lo normal es usarlo a través del atributo `#[async_runtime::test]`,
+/// que admite las mismas opciones: `#[async_runtime::test(seed = 7,
+/// auto_advance = false)]`.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::{test, time};
+/// use std::time::Duration;
+///
+/// let start = std::time::Instant::now();
+/// test::Builder::new().seed(7).run(async {
+///     let before = time::now();
+///     time::sleep(Duration::from_secs(3600)).await;
+///     assert_eq!(time::now() - before, Duration::from_secs(3600));
+/// });
+/// assert!(start.elapsed() < Duration::from_secs(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Builder {
+    seed: Option<u64>,
+    auto_advance: bool,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Builder {
+    /// Reloj que avanza solo y semilla de `ASYNC_RUNTIME_SEED` o aleatoria
+    pub fn new() -> Self {
+        Builder {
+            seed: env::var(SEED_VAR).ok().and_then(|seed| seed.parse().ok()),
+            auto_advance: true,
+        }
+    }
+
+    /// Fija la semilla del planificador y de `select!`
+    pub fn seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Si el reloj salta al próximo temporizador cuando no hay nada que hacer
+    ///
+    /// Activado por defecto. Desactivado, el tiempo solo pasa con
+    /// `advance`. Con E/S real en curso el salto puede adelantarse a ella:
+    /// solo se tiene en cuenta la E/S que ya está lista.
+    pub fn auto_advance(&mut self, enabled: bool) -> &mut Self {
+        self.auto_advance = enabled;
+        self
+    }
+
+    pub fn build(&mut self) -> Runtime {
+        let seed = self.seed.unwrap_or_else(future::seed);
+        let clock = Clock::mock(self.auto_advance);
+        Runtime {
+            executor: Executor::with_driver(Handle::with_clock(clock.clone()), true),
+            clock,
+            seed,
+            // Mezcla la semilla para que semillas cercanas den secuencias distintas
+            rng: seed.wrapping_mul(0x9E37_79B9_7F4A_7C15),
+        }
+    }
+
+    /// Crea el runtime y ejecuta `future` en él
+    ///
+    /// Si la prueba entra en pánico, muestra la semilla antes de propagarlo.
+    pub fn run<F: Future>(&mut self, future: F) -> F::Output {
+        let mut runtime = self.build();
+        let seed = runtime.seed();
+        match panic::catch_unwind(AssertUnwindSafe(|| runtime.block_on(future))) {
+            Ok(output) => output,
+            Err(payload) => {
+                eprintln!(
+                    "async_runtime::test: falló con la semilla {seed} ({SEED_VAR}={seed} para repetirla)"
+                );
+                panic::resume_unwind(payload)
+            }
+        }
+    }
+}
+
+/// Runtime de pruebas de un solo hilo con reloj simulado (ver `Builder`)
+pub struct Runtime {
+    executor: Executor,
+    clock: Clock,
+    seed: u64,
+    /// Estado del generador entre llamadas a `block_on`
+    rng: u64,
+}
+
+impl Runtime {
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Hora del reloj simulado
+    pub fn now(&self) -> Instant {
+        self.clock.now()
+    }
+
+    /// Adelanta el reloj y despierta las tareas cuyos temporizadores vencen
+    ///
+    /// Las tareas despertadas avanzan en la siguiente llamada a `block_on`.
+    /// Dentro de una tarea se usa la función `test::advance`.
+    pub fn advance(&mut self, duration: Duration) {
+        self.clock.advance(duration);
+        self.executor.driver().time.process(self.clock.now());
+    }
+
+    pub fn spawn<F, T>(&mut self, future: F) -> JoinHandle<T>
+    where
+        F: Future<Output = T> + 'static + Send,
+        T: Send + 'static,
+    {
+        self.executor.spawn(future)
+    }
+
+    pub fn spawn_named<F, T>(&mut self, name: impl Into<String>, future: F) -> JoinHandle<T>
+    where
+        F: Future<Output = T> + 'static + Send,
+        T: Send + 'static,
+    {
+        self.executor.spawn_named(name, future)
+    }
+
+    /// Ejecuta `future` hasta completarse junto con las tareas lanzadas
+    pub fn block_on<F: Future>(&mut self, future: F) -> F::Output {
+        /// Devuelve al hilo su generador y guarda el del runtime
+        struct Restore<'a> {
+            rng: &'a mut u64,
+            previous: u64,
+        }
+
+        impl Drop for Restore<'_> {
+            fn drop(&mut self) {
+                *self.rng = future::swap_rng(self.previous);
+            }
+        }
+
+        let previous = future::swap_rng(self.rng);
+        let _restore = Restore {
+            rng: &mut self.rng,
+            previous,
+        };
+        self.executor.block_on(future)
+    }
+}
+
+/// Adelanta el reloj simulado del runtime de pruebas actual
+///
+/// Despierta las tareas cuyos temporizadores vencen y les cede el turno
+/// antes de volver.
+///
+/// # Pánico
+/// Fuera de un runtime de `test`
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::{test, time};
+/// use std::time::Duration;
+///
+/// test::Builder::new().auto_advance(false).run(async {
+///     let sleep = time::sleep(Duration::from_secs(10));
+///     test::advance(Duration::from_secs(10)).await;
+///     assert!(sleep.is_elapsed());
+/// });
+/// ```
+pub async fn advance(duration: Duration) {
+    context::with_current(|handle| {
+        handle.clock.advance(duration);
+        handle.time.process(handle.clock.now());
+    })
+    .expect("test::advance debe usarse dentro de un runtime de `test`");
+    yield_now().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Builder;
+    use crate::{
+        task::yield_now,
+        time::{self, sleep},
+    };
+    use std::{
+        cell::Cell,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    /// Orden en que ocho tareas que ceden el turno avanzan con `seed`
+    fn schedule(seed: u64) -> Vec<usize> {
+        let mut runtime = Builder::new().seed(seed).build();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let order = order.clone();
+                runtime.spawn(async move {
+                    for _ in 0..3 {
+                        order.lock().unwrap().push(i);
+                        yield_now().await;
+                    }
+                })
+            })
+            .collect();
+        runtime.block_on(async {
+            for handle in handles {
+                handle.await.unwrap();
+            }
+        });
+        Arc::try_unwrap(order).unwrap().into_inner().unwrap()
+    }
+
+    #[test]
+    fn same_seed_gives_same_schedule() {
+        let order = schedule(7);
+        assert_eq!(order, schedule(7));
+        assert!((0..5).any(|seed| schedule(seed) != order));
+    }
+
+    #[crate::test(auto_advance = false)]
+    async fn time_only_moves_when_advanced() {
+        let start = time::now();
+        let woken = Cell::new(false);
+
+        let (elapsed, ()) = crate::join!(
+            async {
+                sleep(Duration::from_secs(10)).await;
+                woken.set(true);
+                time::now() - start
+            },
+            async {
+                super::advance(Duration::from_secs(5)).await;
+                assert!(!woken.get());
+                super::advance(Duration::from_secs(5)).await;
+                assert!(woken.get());
+            },
+        );
+        assert_eq!(elapsed, Duration::from_secs(10));
+    }
+}
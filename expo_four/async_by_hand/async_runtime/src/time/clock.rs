@@ -0,0 +1,72 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::context;
+
+/// Reloj de un driver: el del sistema o uno simulado
+///
+/// El simulado solo avanza cuando se le pide (`advance`) o, con
+/// `auto_advance`, cuando el ejecutor no tiene nada que hacer salvo esperar
+/// al próximo temporizador. Lo usa el runtime de pruebas (ver `test`).
+#[derive(Clone)]
+pub(crate) enum Clock {
+    System,
+    Mock(Arc<Mock>),
+}
+
+pub(crate) struct Mock {
+    now: Mutex<Instant>,
+    auto_advance: bool,
+}
+
+impl Clock {
+    /// Reloj simulado, detenido en el instante actual del sistema
+    pub(crate) fn mock(auto_advance: bool) -> Self {
+        Clock::Mock(Arc::new(Mock {
+            now: Mutex::new(Instant::now()),
+            auto_advance,
+        }))
+    }
+
+    pub(crate) fn now(&self) -> Instant {
+        match self {
+            Clock::System => Instant::now(),
+            Clock::Mock(mock) => *mock.now.lock().unwrap(),
+        }
+    }
+
+    pub(crate) fn is_mock(&self) -> bool {
+        matches!(self, Clock::Mock(_))
+    }
+
+    /// El ejecutor puede saltar al próximo temporizador cuando está ocioso
+    pub(crate) fn auto_advances(&self) -> bool {
+        matches!(self, Clock::Mock(mock) if mock.auto_advance)
+    }
+
+    /// Adelanta el reloj simulado
+    ///
+    /// # Pánico
+    /// Si el reloj es el del sistema
+    pub(crate) fn advance(&self, duration: Duration) {
+        match self {
+            Clock::System => panic!("solo se puede adelantar el reloj simulado de `test`"),
+            Clock::Mock(mock) => *mock.now.lock().unwrap() += duration,
+        }
+    }
+
+    /// Lleva el reloj simulado hasta `deadline`, si aún no llegó
+    pub(crate) fn advance_to(&self, deadline: Instant) {
+        if let Clock::Mock(mock) = self {
+            let mut now = mock.now.lock().unwrap();
+            *now = (*now).max(deadline);
+        }
+    }
+}
+
+/// Hora según el reloj del ejecutor actual, o la del sistema fuera de uno
+pub(crate) fn now() -> Instant {
+    context::with_current(|handle| handle.clock.now()).unwrap_or_else(Instant::now)
+}
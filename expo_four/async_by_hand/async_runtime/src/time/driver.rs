@@ -0,0 +1,164 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    sync::Mutex,
+    task::Waker,
+    time::Instant,
+};
+
+/// Identificador de un temporizador registrado
+pub(crate) type TimerKey = u64;
+
+/// Temporizador registrado: cuándo vence y a quién despertar
+struct Entry {
+    deadline: Instant,
+    waker: Waker,
+}
+
+struct Inner {
+    /// Montículo de mínimos por fecha de vencimiento
+    ///
+    /// Puede contener entradas obsoletas (canceladas o reprogramadas): se
+    /// descartan al llegar a la cima comparándolas con `entries`.
+    heap: BinaryHeap<Reverse<(Instant, TimerKey)>>,
+    /// Temporizadores vivos
+    entries: HashMap<TimerKey, Entry>,
+    next_key: TimerKey,
+}
+
+impl Inner {
+    /// Descarta las entradas obsoletas de la cima del montículo
+    fn peek_deadline(&mut self) -> Option<Instant> {
+        while let Some(Reverse((deadline, key))) = self.heap.peek().copied() {
+            match self.entries.get(&key) {
+                Some(entry) if entry.deadline == deadline => return Some(deadline),
+                _ => {
+                    self.heap.pop();
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Montículo binario de temporizadores
+///
+/// Cada `Sleep` registra aquí su fecha de vencimiento junto con su waker.
+/// El ejecutor duerme hasta el vencimiento más cercano y, al despertar,
+/// despierta a las tareas cuyos temporizadores ya vencieron.
+pub(crate) struct TimerHeap {
+    inner: Mutex<Inner>,
+}
+
+impl TimerHeap {
+    pub(crate) fn new() -> Self {
+        TimerHeap {
+            inner: Mutex::new(Inner {
+                heap: BinaryHeap::new(),
+                entries: HashMap::new(),
+                next_key: 0,
+            }),
+        }
+    }
+
+    /// Registra un temporizador
+    ///
+    /// # Retorno
+    /// La clave del temporizador y si pasó a ser el vencimiento más
+    /// cercano, en cuyo caso hay que despertar al hilo dormido para que
+    /// recalcule cuánto dormir.
+    pub(crate) fn register(&self, deadline: Instant, waker: Waker) -> (TimerKey, bool) {
+        let mut inner = self.inner.lock().unwrap();
+        let key = inner.next_key;
+        inner.next_key += 1;
+
+        let earliest = inner.peek_deadline().is_none_or(|next| deadline < next);
+        inner.entries.insert(key, Entry { deadline, waker });
+        inner.heap.push(Reverse((deadline, key)));
+        (key, earliest)
+    }
+
+    /// Actualiza el waker de un temporizador
+    ///
+    /// # Retorno
+    /// `false` si el temporizador ya venció y fue retirado
+    pub(crate) fn set_waker(&self, key: TimerKey, waker: &Waker) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.entries.get_mut(&key) {
+            Some(entry) => {
+                if !entry.waker.will_wake(waker) {
+                    entry.waker = waker.clone();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Cambia la fecha de vencimiento conservando el waker registrado
+    ///
+    /// # Retorno
+    /// - `None` si el temporizador ya venció y fue retirado
+    /// - `Some(earliest)` indicando si pasó a ser el vencimiento más cercano
+    pub(crate) fn reset(&self, key: TimerKey, deadline: Instant) -> Option<bool> {
+        let mut inner = self.inner.lock().unwrap();
+        let earliest = inner.peek_deadline().is_none_or(|next| deadline < next);
+
+        let entry = inner.entries.get_mut(&key)?;
+        entry.deadline = deadline;
+        inner.heap.push(Reverse((deadline, key)));
+        Some(earliest)
+    }
+
+    /// Retira un temporizador sin despertar a nadie
+    pub(crate) fn cancel(&self, key: TimerKey) {
+        let entry = self.inner.lock().unwrap().entries.remove(&key);
+        // El waker se suelta fuera del lock
+        drop(entry);
+    }
+
+    /// Vencimiento más cercano entre los temporizadores vivos
+    pub(crate) fn next_deadline(&self) -> Option<Instant> {
+        self.inner.lock().unwrap().peek_deadline()
+    }
+
+    /// Retira los temporizadores vencidos en `now` y despierta a sus tareas
+    ///
+    /// # Retorno
+    /// Cuántos temporizadores vencieron
+    pub(crate) fn process(&self, now: Instant) -> usize {
+        let mut expired = Vec::new();
+        {
+            let mut inner = self.inner.lock().unwrap();
+            while let Some(deadline) = inner.peek_deadline() {
+                if deadline > now {
+                    break;
+                }
+                let Reverse((_, key)) = inner.heap.pop().unwrap();
+                if let Some(entry) = inner.entries.remove(&key) {
+                    expired.push(entry.waker);
+                }
+            }
+        }
+
+        // Se despierta fuera del lock: el waker puede volver a registrar temporizadores
+        let count = expired.len();
+        for waker in expired {
+            waker.wake();
+        }
+        count
+    }
+
+    /// Descarta todos los temporizadores (al apagar el ejecutor)
+    ///
+    /// Los wakers guardados referencian a las tareas, que a su vez
+    /// referencian al ejecutor: soltarlos rompe ese ciclo.
+    pub(crate) fn clear(&self) {
+        let entries = {
+            let mut inner = self.inner.lock().unwrap();
+            inner.heap.clear();
+            std::mem::take(&mut inner.entries)
+        };
+        drop(entries);
+    }
+}
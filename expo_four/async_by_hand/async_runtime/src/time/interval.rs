@@ -0,0 +1,174 @@
+use std::{
+    future::{Future, poll_fn},
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use crate::sleep::Sleep;
+
+/// Margen a partir del cual un tick se considera perdido
+///
+/// Evita tratar como retraso las pequeñas variaciones del planificador.
+const MISSED_TICK_TOLERANCE: Duration = Duration::from_millis(5);
+
+/// Qué hacer cuando se pierden ticks porque la tarea llegó tarde
+///
+/// Ejemplo con periodo de 10ms, si la tarea llega 25ms tarde al tick de 0ms:
+/// - `Burst`: entrega de inmediato los ticks de 10ms y 20ms, después 30ms, 40ms, ...
+/// - `Delay`: el siguiente tick es 10ms después de ahora (35ms, 45ms, ...)
+/// - `Skip`: salta al siguiente múltiplo del periodo (30ms, 40ms, ...)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissedTickBehavior {
+    /// Recupera los ticks perdidos tan rápido como se pidan
+    #[default]
+    Burst,
+    /// Reinicia el periodo a partir del momento actual
+    Delay,
+    /// Descarta los ticks perdidos y mantiene la cadencia original
+    Skip,
+}
+
+impl MissedTickBehavior {
+    /// Calcula el siguiente vencimiento cuando el tick `timeout` llegó tarde
+    fn next_timeout(&self, timeout: Instant, now: Instant, period: Duration) -> Instant {
+        match self {
+            MissedTickBehavior::Burst => timeout + period,
+            MissedTickBehavior::Delay => now + period,
+            MissedTickBehavior::Skip => {
+                let behind = (now - timeout).as_nanos() % period.as_nanos();
+                now + period - Duration::from_nanos(behind as u64)
+            }
+        }
+    }
+}
+
+/// Genera ticks a intervalos regulares
+///
+/// Se crea con `interval` o `interval_at` y se consume con `tick().await`.
+pub struct Interval {
+    /// Próximo tick
+    sleep: Sleep,
+    period: Duration,
+    missed_tick_behavior: MissedTickBehavior,
+}
+
+impl Interval {
+    /// Espera al siguiente tick
+    ///
+    /// # Retorno
+    /// El instante en el que estaba programado el tick
+    pub async fn tick(&mut self) -> Instant {
+        poll_fn(|cx| self.poll_tick(cx)).await
+    }
+
+    /// Versión por sondeo de `tick`, para usarla dentro de otros futuros
+    pub fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<Instant> {
+        if Pin::new(&mut self.sleep).poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+
+        let timeout = self.sleep.deadline();
+        let now = super::now();
+
+        let next = if now > timeout + MISSED_TICK_TOLERANCE {
+            self.missed_tick_behavior
+                .next_timeout(timeout, now, self.period)
+        } else {
+            timeout + self.period
+        };
+        self.sleep.reset(next);
+
+        Poll::Ready(timeout)
+    }
+
+    /// Reinicia el intervalo: el siguiente tick será dentro de un periodo
+    pub fn reset(&mut self) {
+        self.sleep.reset(super::now() + self.period);
+    }
+
+    /// Periodo entre ticks
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Comportamiento ante ticks perdidos
+    pub fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
+
+    /// Cambia el comportamiento ante ticks perdidos
+    pub fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) {
+        self.missed_tick_behavior = behavior;
+    }
+}
+
+/// Crea un intervalo cuyo primer tick se completa de inmediato
+///
+/// # Pánico
+/// Si `period` es cero
+pub fn interval(period: Duration) -> Interval {
+    interval_at(super::now(), period)
+}
+
+/// Crea un intervalo cuyo primer tick se completa en `start`
+///
+/// # Pánico
+/// Si `period` es cero
+pub fn interval_at(start: Instant, period: Duration) -> Interval {
+    assert!(
+        !period.is_zero(),
+        "el periodo de un intervalo debe ser mayor que cero"
+    );
+
+    Interval {
+        sleep: Sleep::until(start),
+        period,
+        missed_tick_behavior: MissedTickBehavior::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MissedTickBehavior, interval};
+    use crate::block_on;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn ticks_follow_the_period() {
+        let start = Instant::now();
+
+        let ticks = block_on(async {
+            let mut interval = interval(Duration::from_millis(20));
+            let mut ticks = Vec::new();
+            for _ in 0..3 {
+                ticks.push(interval.tick().await);
+            }
+            ticks
+        });
+
+        assert_eq!(ticks[1] - ticks[0], Duration::from_millis(20));
+        assert_eq!(ticks[2] - ticks[1], Duration::from_millis(20));
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[test]
+    fn missed_ticks() {
+        let period = Duration::from_millis(10);
+        let timeout = Instant::now();
+        let now = timeout + Duration::from_millis(25);
+
+        assert_eq!(
+            MissedTickBehavior::Burst.next_timeout(timeout, now, period),
+            timeout + period
+        );
+        assert_eq!(
+            MissedTickBehavior::Delay.next_timeout(timeout, now, period),
+            now + period
+        );
+        assert_eq!(
+            MissedTickBehavior::Skip.next_timeout(timeout, now, period),
+            timeout + Duration::from_millis(30)
+        );
+    }
+}
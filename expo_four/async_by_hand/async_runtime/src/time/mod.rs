@@ -0,0 +1,34 @@
+mod clock;
+pub(crate) mod driver;
+mod interval;
+mod timeout;
+
+use std::time::{Duration, Instant};
+
+pub use crate::sleep::Sleep;
+pub(crate) use clock::Clock;
+pub use interval::{Interval, MissedTickBehavior, interval, interval_at};
+pub use timeout::{Elapsed, Timeout, timeout, timeout_at};
+
+/// Hora actual según el ejecutor
+///
+/// Dentro del runtime de pruebas (ver `test`) es la del reloj simulado; en
+/// cualquier otro caso, `Instant::now()`. Los temporizadores del crate se
+/// miden siempre con esta función.
+pub fn now() -> Instant {
+    clock::now()
+}
+
+/// Espera a que pase `duration`
+///
+/// Atajo para `Sleep::new`.
+pub fn sleep(duration: Duration) -> Sleep {
+    Sleep::new(duration)
+}
+
+/// Espera hasta el instante `deadline`
+///
+/// Atajo para `Sleep::until`.
+pub fn sleep_until(deadline: Instant) -> Sleep {
+    Sleep::until(deadline)
+}
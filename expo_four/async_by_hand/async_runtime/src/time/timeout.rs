@@ -0,0 +1,93 @@
+use std::{
+    error::Error,
+    fmt,
+    future::Future,
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use crate::sleep::Sleep;
+
+/// Error devuelto cuando un futuro no termina antes de su plazo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed(());
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "el plazo expiró")
+    }
+}
+
+impl Error for Elapsed {}
+
+impl From<Elapsed> for io::Error {
+    fn from(elapsed: Elapsed) -> Self {
+        io::Error::new(io::ErrorKind::TimedOut, elapsed)
+    }
+}
+
+/// Futuro que limita el tiempo de ejecución de otro futuro
+///
+/// Se crea con `timeout` o `timeout_at`.
+pub struct Timeout<F> {
+    /// Futuro limitado; se fija en el heap para no exigir `Unpin`
+    future: Pin<Box<F>>,
+    /// Plazo máximo
+    sleep: Sleep,
+}
+
+impl<F> Timeout<F> {
+    /// Momento en el que vence el plazo
+    pub fn deadline(&self) -> Instant {
+        self.sleep.deadline()
+    }
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, Elapsed>;
+
+    /// Sondea primero el futuro interno y después el plazo
+    ///
+    /// Así, si ambos están listos en el mismo sondeo gana el resultado.
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(output) = self.future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+
+        match Pin::new(&mut self.sleep).poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(Elapsed(()))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Exige que `future` termine antes de que pase `duration`
+///
+/// # Retorno
+/// - `Ok(T)` con el resultado del futuro si terminó a tiempo
+/// - `Err(Elapsed)` si venció el plazo; el futuro se descarta
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::time::{sleep, timeout};
+/// use std::time::Duration;
+///
+/// let result = async_runtime::block_on(timeout(
+///     Duration::from_millis(10),
+///     sleep(Duration::from_secs(60)),
+/// ));
+/// assert!(result.is_err());
+/// ```
+pub fn timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
+    timeout_at(super::now() + duration, future)
+}
+
+/// Exige que `future` termine antes del instante `deadline`
+pub fn timeout_at<F: Future>(deadline: Instant, future: F) -> Timeout<F> {
+    Timeout {
+        future: Box::pin(future),
+        sleep: Sleep::until(deadline),
+    }
+}
@@ -1,26 +1,153 @@
-use std::task::{RawWaker, RawWakerVTable};
+use std::{
+    mem::ManuallyDrop,
+    sync::Arc,
+    task::{RawWaker, RawWakerVTable, Waker},
+};
 
-// La vtable se usa como wrapper para todos los metodos del waker, que será pasado como referencia usando un unsafe pointer
-static VTABLE: RawWakerVTable = RawWakerVTable::new(my_clone, my_wake, my_wake_by_ref, my_drop);
+/// Estado compartido que sabe cómo despertar a su tarea
+///
+/// Se implementa sobre el tipo que vive detrás del `Arc` (la tarea, el
+/// planificador, ...). El `Waker` resultante guarda un puntero a ese `Arc`
+/// y cada operación de la vtable ajusta su contador de referencias.
+pub trait ArcWake: Send + Sync + 'static {
+    /// Despierta la tarea sin consumir la referencia
+    fn wake_by_ref(arc_self: &Arc<Self>);
 
-unsafe fn my_clone(raw_waker: *const ()) -> RawWaker {
-    RawWaker::new(raw_waker, &VTABLE)
+    /// Despierta la tarea consumiendo la referencia
+    ///
+    /// Por defecto delega en `wake_by_ref` y después libera el `Arc`.
+    fn wake(self: Arc<Self>) {
+        Self::wake_by_ref(&self);
+    }
+}
+
+// La vtable se usa como wrapper para todos los metodos del waker, que será pasado como referencia usando un unsafe pointer.
+// Es genérica sobre `W` porque cada función necesita saber el tipo real que hay detrás del puntero.
+fn vtable<W: ArcWake>() -> &'static RawWakerVTable {
+    &RawWakerVTable::new(
+        my_clone::<W>,
+        my_wake::<W>,
+        my_wake_by_ref::<W>,
+        my_drop::<W>,
+    )
 }
 
-unsafe fn my_wake(raw_waker: *const ()) {
+/// Clona el waker: una referencia fuerte más sobre el mismo `Arc`
+unsafe fn my_clone<W: ArcWake>(raw_waker: *const ()) -> RawWaker {
     unsafe {
-        drop(Box::from_raw(raw_waker as *mut u32));
+        Arc::increment_strong_count(raw_waker as *const W);
     }
+    RawWaker::new(raw_waker, vtable::<W>())
+}
+
+/// Despierta y consume el waker: la referencia que poseía pasa a `wake`
+unsafe fn my_wake<W: ArcWake>(raw_waker: *const ()) {
+    let arc = unsafe { Arc::from_raw(raw_waker as *const W) };
+    ArcWake::wake(arc);
 }
 
-unsafe fn my_drop(raw_waker: *const ()) {
-    drop(Box::from_raw(raw_waker as *mut u32));
+/// Despierta sin consumir: el `Arc` se reconstruye solo de forma temporal
+unsafe fn my_wake_by_ref<W: ArcWake>(raw_waker: *const ()) {
+    // ManuallyDrop evita decrementar el contador al salir de la función
+    let arc = ManuallyDrop::new(unsafe { Arc::from_raw(raw_waker as *const W) });
+    ArcWake::wake_by_ref(&arc);
 }
 
-unsafe fn my_wake_by_ref(_raw_waker: *const ()) {}
+/// Libera la referencia que poseía el waker
+unsafe fn my_drop<W: ArcWake>(raw_waker: *const ()) {
+    unsafe {
+        drop(Arc::from_raw(raw_waker as *const W));
+    }
+}
+
+/// Crea un `RawWaker` que toma posesión de una referencia de `data`
+pub fn create_raw_waker<W: ArcWake>(data: Arc<W>) -> RawWaker {
+    RawWaker::new(Arc::into_raw(data) as *const (), vtable::<W>())
+}
+
+/// Crea un `Waker` a partir de un `Arc` a un tipo que implementa `ArcWake`
+pub fn create_waker<W: ArcWake>(data: Arc<W>) -> Waker {
+    // Seguro: la vtable respeta el contrato de RawWaker para el tipo `W`
+    unsafe { Waker::from_raw(create_raw_waker(data)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArcWake, create_waker};
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        thread,
+    };
+
+    struct Counter {
+        wakes: AtomicUsize,
+    }
+
+    impl ArcWake for Counter {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            arc_self.wakes.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn counter() -> Arc<Counter> {
+        Arc::new(Counter {
+            wakes: AtomicUsize::new(0),
+        })
+    }
+
+    #[test]
+    fn clone_and_drop_balance_refcount() {
+        let data = counter();
+        let waker = create_waker(data.clone());
+        assert_eq!(Arc::strong_count(&data), 2);
+
+        let clone = waker.clone();
+        assert_eq!(Arc::strong_count(&data), 3);
+
+        drop(clone);
+        drop(waker);
+        assert_eq!(Arc::strong_count(&data), 1);
+    }
 
-pub fn create_raw_waker() -> RawWaker {
-    let data = Box::into_raw(Box::new(42));
+    #[test]
+    fn wake_consumes_and_wake_by_ref_does_not() {
+        let data = counter();
+        let waker = create_waker(data.clone());
 
-    RawWaker::new(data as *const (), &VTABLE)
+        waker.wake_by_ref();
+        assert_eq!(Arc::strong_count(&data), 2);
+
+        waker.wake();
+        assert_eq!(Arc::strong_count(&data), 1);
+        assert_eq!(data.wakes.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn wakers_cross_threads() {
+        let data = counter();
+        let waker = create_waker(data.clone());
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let waker = waker.clone();
+                thread::spawn(move || {
+                    let other = waker.clone();
+                    other.wake_by_ref();
+                    other.wake();
+                    waker.wake();
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        drop(waker);
+        assert_eq!(data.wakes.load(Ordering::SeqCst), 8 * 3);
+        assert_eq!(Arc::strong_count(&data), 1);
+    }
 }
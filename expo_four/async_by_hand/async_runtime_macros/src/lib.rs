@@ -0,0 +1,137 @@
+use proc_macro::{Delimiter, Group, Span, TokenStream, TokenTree};
+
+/// Convierte una función `async` en una prueba sobre `async_runtime::test`
+///
+/// El cuerpo se ejecuta con `async_runtime::test::Builder::run`: un solo
+/// hilo, planificador sembrado y reloj simulado. Opciones, separadas por
+/// comas:
+/// - `seed = <u64>`: semilla fija (por defecto `ASYNC_RUNTIME_SEED` o aleatoria)
+/// - `auto_advance = <bool>`: si el reloj salta solo al próximo temporizador
+///
+/// ```ignore
+/// #[async_runtime::test(seed = 42)]
+/// async fn responde_tras_un_segundo() {
+///     async_runtime::time::sleep(std::time::Duration::from_secs(1)).await;
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn test(args: TokenStream, item: TokenStream) -> TokenStream {
+    match expand(args, item) {
+        Ok(tokens) => tokens,
+        Err((message, span)) => compile_error(&message, span),
+    }
+}
+
+type Error = (String, Span);
+
+fn expand(args: TokenStream, item: TokenStream) -> Result<TokenStream, Error> {
+    let options = parse_options(args)?;
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+
+    // `async` va justo antes de `fn`, tras los atributos y la visibilidad
+    let fn_index = tokens
+        .iter()
+        .position(|token| is_ident(token, "fn"))
+        .ok_or_else(|| ("se esperaba una función".to_owned(), Span::call_site()))?;
+    if fn_index == 0 || !is_ident(&tokens[fn_index - 1], "async") {
+        return Err((
+            "la función de prueba debe ser `async`".to_owned(),
+            tokens[fn_index].span(),
+        ));
+    }
+    tokens.remove(fn_index - 1);
+
+    if let Some(TokenTree::Group(params)) = tokens.get(fn_index + 1)
+        && params.delimiter() == Delimiter::Parenthesis
+        && !params.stream().is_empty()
+    {
+        return Err(("una prueba no recibe parámetros".to_owned(), params.span()));
+    }
+
+    let body = match tokens.pop() {
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Brace => body,
+        _ => {
+            return Err((
+                "se esperaba el cuerpo de la función".to_owned(),
+                Span::call_site(),
+            ));
+        }
+    };
+
+    // { ::async_runtime::test::Builder::new()<opciones>.run(async move <cuerpo>) }
+    let mut run: TokenStream = format!("::async_runtime::test::Builder::new(){options}.run")
+        .parse()
+        .unwrap();
+    let mut future: TokenStream = "async move".parse().unwrap();
+    future.extend([TokenTree::Group(body.clone())]);
+    run.extend([TokenTree::Group(Group::new(Delimiter::Parenthesis, future))]);
+    let mut new_body = Group::new(Delimiter::Brace, run);
+    new_body.set_span(body.span());
+
+    let mut output: TokenStream = "#[::core::prelude::v1::test]".parse().unwrap();
+    output.extend(tokens);
+    output.extend([TokenTree::Group(new_body)]);
+    Ok(output)
+}
+
+/// Traduce `clave = valor, ...` a llamadas sobre el `Builder`
+fn parse_options(args: TokenStream) -> Result<String, Error> {
+    let mut options = String::new();
+    let mut tokens = args.into_iter().peekable();
+
+    while let Some(key) = tokens.next() {
+        let TokenTree::Ident(key) = key else {
+            return Err(("se esperaba el nombre de una opción".to_owned(), key.span()));
+        };
+        match tokens.next() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '=' => {}
+            _ => return Err((format!("se esperaba `=` tras `{key}`"), key.span())),
+        }
+        let value = tokens
+            .next()
+            .ok_or_else(|| (format!("falta el valor de `{key}`"), key.span()))?;
+
+        match key.to_string().as_str() {
+            "seed" if matches!(value, TokenTree::Literal(_)) => {
+                options.push_str(&format!(".seed({value})"));
+            }
+            "auto_advance" if is_ident(&value, "true") || is_ident(&value, "false") => {
+                options.push_str(&format!(".auto_advance({value})"));
+            }
+            "seed" | "auto_advance" => {
+                return Err((format!("valor no válido para `{key}`"), value.span()));
+            }
+            _ => {
+                return Err((
+                    format!("opción desconocida `{key}`: se admiten `seed` y `auto_advance`"),
+                    key.span(),
+                ));
+            }
+        }
+
+        match tokens.next() {
+            None => break,
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => {}
+            Some(other) => return Err(("se esperaba `,`".to_owned(), other.span())),
+        }
+    }
+    Ok(options)
+}
+
+fn is_ident(token: &TokenTree, name: &str) -> bool {
+    matches!(token, TokenTree::Ident(ident) if ident.to_string() == name)
+}
+
+/// `compile_error!` con el mensaje apuntando a `span`
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let tokens: TokenStream = format!("::core::compile_error!({message:?});")
+        .parse()
+        .unwrap();
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
@@ -1,53 +1,55 @@
-use async_runtime::{executor::Executor, reciever::TcpReceiver, sender::TcpSender};
-use data_layer::data::Data;
-use std::{
-    io,
-    net::TcpStream,
-    sync::{Arc, Mutex},
-    time::Instant,
+use async_runtime::{
+    executor::Executor, io::Framed, net::TcpStream, sync::Semaphore, task::JoinError, task_local,
 };
+use data_layer::{codec::DataCodec, data::Data};
+use std::{io, sync::Arc, time::Instant};
+
+/// Máximo de conexiones abiertas a la vez contra el servidor
+const MAX_IN_FLIGHT: usize = 1000;
+
+task_local! {
+    /// Número de petición de la tarea, disponible en cualquier punto de `send_data`
+    static REQUEST_ID: u32;
+}
 
 /// Envía datos estructurados al servidor y recibe respuesta
 ///
 /// # Flujo de operación:
 /// 1. Establece conexión TCP con el servidor
-/// 2. Serializa la estructura Data
-/// 3. Envía los datos serializados usando TcpSender
-/// 4. Recibe la respuesta usando TcpReceiver
-/// 5. Convierte la respuesta a String UTF-8
+/// 2. Envía la estructura Data por la mitad de escritura con `DataCodec`
+/// 3. Recibe la respuesta por la mitad de lectura hasta EOF
+/// 4. Convierte la respuesta a String UTF-8
 ///
 /// # Parámetros
 /// - `field1`, `field2`, `field3`: Datos a enviar
 ///
 /// # Retorno
-/// Respuesta del servidor como String o error de IO
+/// Respuesta del servidor como String o error de IO, etiquetado con la
+/// petición que lo produjo
 async fn send_data(field1: u32, field2: u16, field3: String) -> io::Result<String> {
-    // Conexión compartida con Arc<Mutex> para uso seguro en futuros
-    let stream = Arc::new(Mutex::new(TcpStream::connect("127.0.0.1:7878")?));
+    exchange(field1, field2, field3).await.map_err(|e| {
+        let request = REQUEST_ID.get();
+        io::Error::new(e.kind(), format!("petición {request}: {e}"))
+    })
+}
 
-    // Construye y serializa los datos
+async fn exchange(field1: u32, field2: u16, field3: String) -> io::Result<String> {
+    // Conexión no bloqueante dividida en mitades de lectura y escritura
+    let stream = TcpStream::connect("127.0.0.1:7878").await?;
+    let (mut reader, writer) = stream.into_split();
+
+    // Construye los datos y los envía serializados por el codec
     let message = Data {
         field1,
         field2,
         field3,
     };
-    let serialized = message.serialize()?;
-
-    // Envía los datos (operación asíncrona)
-    TcpSender {
-        stream: stream.clone(),
-        buffer: serialized,
-    }
-    .await?; // Espera hasta completar el envío
+    let mut writer = Framed::new(writer, DataCodec::new());
+    writer.send(message).await?; // Espera hasta completar el envío
 
-    // Prepara para recibir respuesta
-    let receiver = TcpReceiver {
-        stream: stream.clone(),
-        buffer: Vec::new(),
-    };
-
-    // Recibe datos y convierte a String
-    let response_bytes = receiver.await?;
+    // Recibe datos hasta que el servidor cierre y convierte a String
+    let mut response_bytes = Vec::new();
+    reader.read_to_end(&mut response_bytes).await?;
     String::from_utf8(response_bytes)
         .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Respuesta no UTF-8"))
 }
@@ -55,38 +57,45 @@ async fn send_data(field1: u32, field2: u16, field3: String) -> io::Result<Strin
 /// Punto de entrada del cliente de carga
 ///
 /// Realiza una prueba de carga enviando 4000 peticiones concurrentes
-/// al servidor y mide el tiempo total de ejecución.
+/// al servidor, con a lo sumo `MAX_IN_FLIGHT` conexiones abiertas a la vez,
+/// y mide el tiempo total de ejecución.
 fn main() -> io::Result<()> {
     // Inicializa ejecutor y contenedor de manejadores
     let mut executor = Executor::new();
     let mut handles = Vec::with_capacity(4000);
+    let in_flight = Arc::new(Semaphore::new(MAX_IN_FLIGHT));
 
     // Registra tiempo inicial
     let start = Instant::now();
 
     // Genera 4000 tareas concurrentes
     for i in 0..4000 {
-        // Crea tarea asíncrona para enviar datos
-        let handle = executor.spawn(send_data(i, i as u16, format!("Mensaje {}", i)));
+        // Crea tarea asíncrona para enviar datos; espera turno si hay demasiadas conexiones
+        let in_flight = in_flight.clone();
+        let request = REQUEST_ID.scope(i, async move {
+            let _permit = in_flight.acquire().await.unwrap();
+            send_data(i, i as u16, format!("Mensaje {}", i)).await
+        });
+        let handle = executor.spawn_named(format!("send_data-{i}"), request);
         handles.push(handle);
     }
 
-    // Hilo dedicado para procesar tareas
-    std::thread::spawn(move || {
-        loop {
-            executor.poll(); // Procesa todas las tareas pendientes
-        }
-    });
-
     println!("Esperando resultados...");
 
-    // Recopila resultados de todas las tareas
-    for handle in handles {
-        match handle.block_on().unwrap() {
-            Ok(result) => println!("Respuesta: {}", result),
-            Err(e) => println!("Error: {}", e),
-        };
-    }
+    // Ejecuta las tareas en este hilo mientras recopila sus resultados
+    executor.block_on(async move {
+        for handle in handles {
+            let (id, name) = (handle.id(), handle.name().unwrap_or("?").to_owned());
+            // Un pánico o una cancelación solo descartan su propia petición
+            match handle.await {
+                Ok(Ok(result)) => println!("Respuesta: {}", result),
+                Ok(Err(e)) => println!("Error en {} (tarea {}): {}", name, id, e),
+                Err(e @ (JoinError::Panicked(_) | JoinError::Cancelled)) => {
+                    println!("Error en {} (tarea {}): {}", name, id, e)
+                }
+            };
+        }
+    });
 
     // Calcula y muestra tiempo total
     let duration = start.elapsed();
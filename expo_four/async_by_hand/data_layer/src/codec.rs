@@ -0,0 +1,111 @@
+use std::io::{self, Cursor};
+
+use async_runtime::io::{Decoder, Encoder};
+
+use crate::data::Data;
+
+/// Tamaño de la cabecera fija de `Data`: field1, field2 y longitud de field3
+const HEADER_LEN: usize = 4 + 2 + 4;
+
+/// Longitud máxima por defecto de `field3`
+const DEFAULT_MAX_TEXT_LENGTH: usize = 64 * 1024;
+
+/// Codec de `Data` para usar con `async_runtime::io::Framed`
+///
+/// El formato de `Data` ya indica su propia longitud, así que los mensajes
+/// se envían uno detrás de otro sin prefijo adicional.
+///
+/// # Ejemplo
+/// ```
+/// use async_runtime::io::{Decoder, Encoder};
+/// use data_layer::{codec::DataCodec, data::Data};
+///
+/// let mut codec = DataCodec::new();
+/// let mut bytes = Vec::new();
+/// let data = Data { field1: 1, field2: 2, field3: "hola".to_string() };
+/// codec.encode(data, &mut bytes).unwrap();
+///
+/// // Con el mensaje incompleto hay que esperar más bytes
+/// assert!(codec.decode(&bytes[..7]).unwrap().is_none());
+///
+/// let (data, len) = codec.decode(&bytes).unwrap().unwrap();
+/// assert_eq!((data.field3.as_str(), len), ("hola", bytes.len()));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DataCodec {
+    max_text_length: usize,
+}
+
+impl DataCodec {
+    pub fn new() -> Self {
+        DataCodec {
+            max_text_length: DEFAULT_MAX_TEXT_LENGTH,
+        }
+    }
+
+    /// Longitud máxima de `field3`; un mensaje mayor es un error `InvalidData`
+    ///
+    /// La longitud llega del otro extremo: sin límite, un cliente podría
+    /// hacer reservar memoria arbitraria al servidor.
+    pub fn with_max_text_length(max_text_length: usize) -> Self {
+        DataCodec { max_text_length }
+    }
+
+    fn check_length(&self, len: usize) -> io::Result<()> {
+        if len > self.max_text_length {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "texto de {} bytes, el máximo es {}",
+                    len, self.max_text_length
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for DataCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder for DataCodec {
+    type Item = Data;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &[u8]) -> io::Result<Option<(Data, usize)>> {
+        let Some(header) = src.first_chunk::<HEADER_LEN>() else {
+            return Ok(None);
+        };
+        // Comprueba la longitud antes de que `deserialize` reserve memoria
+        let len = u32::from_ne_bytes([header[6], header[7], header[8], header[9]]) as usize;
+        self.check_length(len)?;
+        if src.len() < HEADER_LEN + len {
+            return Ok(None);
+        }
+
+        let mut cursor = Cursor::new(src);
+        let data = Data::deserialize(&mut cursor)?;
+        Ok(Some((data, cursor.position() as usize)))
+    }
+}
+
+impl Encoder<Data> for DataCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Data, dst: &mut Vec<u8>) -> io::Result<()> {
+        self.encode(&item, dst)
+    }
+}
+
+impl Encoder<&Data> for DataCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: &Data, dst: &mut Vec<u8>) -> io::Result<()> {
+        self.check_length(item.field3.len())?;
+        dst.extend_from_slice(&item.serialize()?);
+        Ok(())
+    }
+}
@@ -26,6 +26,8 @@ impl Data {
     ///
     /// # Ejemplo
     /// ```
+    /// use data_layer::data::Data;
+    ///
     /// let data = Data {
     ///     field1: 42,
     ///     field2: 7,
@@ -65,7 +67,11 @@ impl Data {
     ///
     /// # Ejemplo
     /// ```
-    /// let buffer: &[u8] = ...; // Datos serializados
+    /// use data_layer::data::Data;
+    /// use std::io::Cursor;
+    ///
+    /// # let bytes = Data { field1: 42, field2: 7, field3: "Hola".to_string() }.serialize().unwrap();
+    /// let buffer: &[u8] = &bytes; // Datos serializados
     /// let mut cursor = Cursor::new(buffer);
     /// let data = Data::deserialize(&mut cursor).unwrap();
     /// ```
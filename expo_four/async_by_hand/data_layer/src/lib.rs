@@ -1 +1,3 @@
+#[cfg(feature = "async_runtime")]
+pub mod codec;
 pub mod data;
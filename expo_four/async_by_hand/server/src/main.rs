@@ -1,171 +1,380 @@
 use std::{
-    io::{self, Cursor, ErrorKind, Read, Write},
-    net::{TcpListener, TcpStream},
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        mpsc::{Receiver, channel},
-    },
-    thread::{self, JoinHandle},
+    env, fs,
+    io::{self, ErrorKind},
+    os::unix::fs::FileTypeExt,
+    path::PathBuf,
+    process,
     time::Duration,
 };
 
-use async_runtime::{executor::Executor, sleep::Sleep};
-use data_layer::data::Data;
+use async_runtime::{
+    Builder, Runtime,
+    io::{AsyncRead, AsyncWrite, AsyncWriteExt, Framed},
+    net::{TcpListener, UnixListener},
+    select,
+    signal::{SignalKind, signal},
+    sleep::Sleep,
+    stream::StreamExt,
+    sync::{CancellationToken, mpsc},
+    time::timeout,
+};
+use data_layer::codec::DataCodec;
 
-// Flags atómicas para rastrear el estado de los workers
-// Cada flag indica si el worker correspondiente está dormido
-static FLAGS: [AtomicBool; 3] = [
-    AtomicBool::new(false),
-    AtomicBool::new(false),
-    AtomicBool::new(false),
-];
+/// Dirección TCP en la que escucha el servidor si no se indica otra cosa
+const TCP_ADDR: &str = "0.0.0.0:7878";
 
-/// Inicia un worker thread que procesa conexiones TCP
-///
-/// # Parámetros
-/// - `name`: Identificador del worker
-/// - `rx`: Canal receptor para nuevas conexiones
-/// - `flag`: Flag atómica para comunicar estado de reposo
-///
-/// # Comportamiento
-/// 1. Recibe conexiones del canal
-/// 2. Ejecuta futuros asíncronos para manejar cada cliente
-/// 3. Entra en reposo cuando no hay trabajo
-fn spawn_worker(
-    name: &'static str,
-    rx: Receiver<TcpStream>,
-    flag: &'static AtomicBool,
-) -> JoinHandle<()> {
-    thread::spawn(move || {
-        let mut executor = Executor::new();
-        loop {
-            // Intenta recibir nuevas conexiones
-            if let Ok(stream) = rx.try_recv() {
-                println!(
-                    "{} Received connection {}",
-                    name,
-                    stream.peer_addr().unwrap()
-                );
-                // Crea una nueva tarea asíncrona para el cliente
-                executor.spawn(handle_client(stream));
-            } else {
-                // Si no hay tareas, entra en reposo
-                if executor.polling.is_empty() {
-                    println!("{} is sleeping", name);
-                    flag.store(true, Ordering::SeqCst);
-                    thread::park(); // Suspende el thread
-                }
+/// Número de hilos worker del runtime
+const WORKERS: usize = 3;
+
+/// Tiempo máximo para recibir la petición de un cliente
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Tiempo que los clientes en curso tienen para terminar tras pedir el apagado
+const GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+/// Conexión aceptada por cualquiera de los listeners del servidor
+trait Connection: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Connection for T {}
+
+/// Socket de escucha del servidor: TCP o Unix
+enum Listener {
+    Tcp(TcpListener),
+    Unix {
+        listener: UnixListener,
+        path: PathBuf,
+        /// Conexiones aceptadas; numera a los clientes, que no tienen dirección
+        accepted: u64,
+    },
+}
+
+impl Listener {
+    /// Escucha en `TCP_ADDR`, o en el socket Unix `unix_path` si se indica
+    ///
+    /// Un socket Unix que haya quedado de una ejecución anterior se borra
+    /// antes de crear el nuevo.
+    fn bind(unix_path: Option<PathBuf>) -> io::Result<Listener> {
+        let Some(path) = unix_path else {
+            let listener = TcpListener::bind(TCP_ADDR)?;
+            println!("Server Listening on port 7878");
+            return Ok(Listener::Tcp(listener));
+        };
+
+        if let Ok(metadata) = fs::symlink_metadata(&path)
+            && metadata.file_type().is_socket()
+        {
+            fs::remove_file(&path)?;
+        }
+        let listener = UnixListener::bind(&path)?;
+        println!("Server Listening on {}", path.display());
+        Ok(Listener::Unix {
+            listener,
+            path,
+            accepted: 0,
+        })
+    }
 
-                // Procesa tareas pendientes
-                executor.poll();
+    /// Espera la siguiente conexión y devuelve un nombre para identificarla
+    async fn accept(&mut self) -> io::Result<(Box<dyn Connection>, String)> {
+        match self {
+            Listener::Tcp(listener) => {
+                let (stream, addr) = listener.accept().await?;
+                Ok((Box::new(stream), addr.to_string()))
+            }
+            Listener::Unix {
+                listener,
+                path,
+                accepted,
+            } => {
+                let (stream, _) = listener.accept().await?;
+                *accepted += 1;
+                Ok((Box::new(stream), format!("{}#{}", path.display(), accepted)))
             }
         }
-    })
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        if let Listener::Unix { path, .. } = self {
+            let _ = fs::remove_file(path);
+        }
+    }
 }
 
 /// Maneja una conexión cliente de forma asíncrona
 ///
+/// `peer` identifica al cliente en los mensajes.
+///
 /// # Flujo de trabajo
-/// 1. Lee datos hasta encontrar EOF o bloqueo
-/// 2. Deserializa los datos en una estructura `Data`
+/// 1. Espera un mensaje `Data` completo, con un plazo de `READ_TIMEOUT`
+/// 2. Muestra el mensaje, o el motivo por el que no se pudo decodificar
 /// 3. Envía una respuesta después de un retraso simulado
-async fn handle_client(mut stream: TcpStream) -> std::io::Result<()> {
-    stream.set_nonblocking(true)?;
-    let mut buffer = Vec::new();
-    let mut local_buf = [0; 1024]; // Buffer de lectura temporal
-
-    loop {
-        match stream.read(&mut local_buf) {
-            // Fin de conexión
-            Ok(0) => break,
-
-            // Datos recibidos
-            Ok(len) => {
-                buffer.extend_from_slice(&local_buf[..len]);
-            }
+async fn handle_client(stream: impl Connection, peer: &str) -> std::io::Result<()> {
+    let mut requests = Framed::new(stream, DataCodec::new());
 
-            // Bloqueo temporal - procesa si tenemos datos
-            Err(ref e) if e.kind() == ErrorKind::WouldBlock => {
-                if !buffer.is_empty() {
-                    break;
-                }
-                // Espera breve antes de reintentar
-                Sleep::new(Duration::from_millis(10)).await;
-                continue;
-            }
-
-            // Error fatal
-            Err(e) => {
-                println!("Failed to read from connection: {}", e);
-                return Err(e);
-            }
+    // Un cliente que se conecta y nunca envía nada no retiene la tarea para siempre
+    let request = match timeout(READ_TIMEOUT, requests.next()).await {
+        Ok(request) => request,
+        Err(elapsed) => {
+            println!("Client {} timed out", peer);
+            return Err(elapsed.into());
         }
-    }
+    };
 
-    // Deserializa los datos recibidos
-    match Data::deserialize(&mut Cursor::new(buffer.as_slice())) {
-        Ok(message) => {
+    match request {
+        Some(Ok(message)) => {
             println!("Received message: {:?}", message);
         }
-        Err(e) => {
+        // Mensaje truncado o mal formado: se responde igualmente
+        Some(Err(e)) if matches!(e.kind(), ErrorKind::InvalidData | ErrorKind::UnexpectedEof) => {
             println!("Failed to decode message: {}", e);
         }
+        Some(Err(e)) => {
+            println!("Failed to read from connection: {}", e);
+            return Err(e);
+        }
+        None => {
+            println!("Failed to decode message: connection closed without a message");
+        }
     }
 
     // Simula procesamiento y envía respuesta
     Sleep::new(Duration::from_secs(1)).await;
-    stream.write_all(b"Hello, Client!")?;
+    let mut stream = requests.into_inner();
+    stream.write_all(b"Hello, Client!").await?;
 
     Ok(())
 }
 
-/// Punto de entrada principal del servidor TCP
+/// Acepta conexiones y lanza una tarea por cliente hasta que se pida el apagado
+///
+/// Corre en el hilo principal con `Runtime::block_on`: la espera de
+/// `accept` suelta el hilo hasta que el reactor avisa de una conexión.
+/// Tras la señal de apagado deja de aceptar y espera como mucho
+/// `GRACE_PERIOD` a que terminen los clientes en curso; si alguno no lo
+/// hace, muestra el estado de las tareas que quedan.
+async fn serve(
+    runtime: &Runtime,
+    shutdown: &CancellationToken,
+    unix_path: Option<PathBuf>,
+) -> io::Result<()> {
+    spawn_signal_listener(runtime, shutdown.clone())?;
+    let mut listener = Listener::bind(unix_path)?;
+
+    // Cada tarea de cliente guarda una copia del emisor: cuando el canal se
+    // cierra ya no queda ninguna en curso
+    let (in_flight, mut all_done) = mpsc::channel::<()>(1);
+
+    // Bucle principal de aceptación de conexiones
+    loop {
+        select! {
+            biased;
+            _ = shutdown.cancelled() => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, peer)) => {
+                    println!("Received connection {}", peer);
+                    // Crea una nueva tarea asíncrona para el cliente
+                    let in_flight = in_flight.clone();
+                    runtime.spawn_named(format!("client-{}", peer), async move {
+                        let result = handle_client(stream, &peer).await;
+                        drop(in_flight);
+                        result
+                    });
+                }
+                Err(e) => {
+                    println!("Connection failed: {}", e)
+                }
+            },
+        }
+    }
+
+    // Cierra el socket de escucha (y borra el de Unix): las conexiones
+    // nuevas se rechazan
+    drop(listener);
+    drop(in_flight);
+
+    println!("Waiting up to {:?} for in-flight clients", GRACE_PERIOD);
+    if timeout(GRACE_PERIOD, all_done.recv()).await.is_err() {
+        println!("Grace period expired, dropping remaining clients:");
+        print!("{}", runtime.dump());
+    }
+    Ok(())
+}
+
+/// Atiende SIGINT, SIGTERM y SIGHUP en una tarea del runtime
+///
+/// Las señales se escuchan desde el momento de la llamada, que debe
+/// hacerse dentro del runtime. La primera cancela `shutdown`; una segunda
+/// termina el proceso sin esperar a los clientes.
+fn spawn_signal_listener(runtime: &Runtime, shutdown: CancellationToken) -> io::Result<()> {
+    let mut interrupt = signal(SignalKind::interrupt())?;
+    let mut terminate = signal(SignalKind::terminate())?;
+    let mut hangup = signal(SignalKind::hangup())?;
+
+    runtime.spawn_named("signals", async move {
+        loop {
+            let kind = select! {
+                _ = interrupt.recv() => SignalKind::interrupt(),
+                _ = terminate.recv() => SignalKind::terminate(),
+                _ = hangup.recv() => SignalKind::hangup(),
+            };
+            if shutdown.is_cancelled() {
+                println!("Received {} again, exiting now", kind);
+                process::exit(1);
+            }
+            println!("Received {}, shutting down", kind);
+            shutdown.cancel();
+        }
+    });
+    Ok(())
+}
+
+/// Lee la ruta del socket Unix de `--unix RUTA`, si se indicó
+fn parse_args() -> io::Result<Option<PathBuf>> {
+    let mut args = env::args_os().skip(1);
+    let Some(flag) = args.next() else {
+        return Ok(None);
+    };
+    match (flag.to_str(), args.next(), args.next()) {
+        (Some("--unix"), Some(path), None) => Ok(Some(PathBuf::from(path))),
+        _ => Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            "uso: server [--unix RUTA]",
+        )),
+    }
+}
+
+/// Punto de entrada principal del servidor
 ///
 /// # Arquitectura
-/// - 3 workers threads con ejecutores asíncronos
-/// - Balanceador round-robin para distribuir conexiones
-/// - Sistema de reactivación para workers dormidos
+/// - Un runtime multihilo con `WORKERS` workers y robo de trabajo
+/// - El bucle de aceptación corre en el hilo principal sobre el reactor
+/// - Cada conexión aceptada se lanza como una tarea en el runtime
+/// - Los workers se duermen y despiertan solos según haya trabajo
+/// - SIGINT/SIGTERM/SIGHUP detienen la aceptación, dan un margen a los clientes
+///   en curso y después apagan el runtime esperando a sus workers
+///
+/// # Uso
+/// `server` escucha en `TCP_ADDR`; `server --unix RUTA` escucha en un
+/// socket Unix en `RUTA`.
 fn main() -> io::Result<()> {
-    // Canales de comunicación con los workers
-    let (one_tx, one_rx) = channel::<TcpStream>();
-    let (two_tx, two_rx) = channel::<TcpStream>();
-    let (three_tx, three_rx) = channel::<TcpStream>();
+    let unix_path = parse_args()?;
+    let shutdown = CancellationToken::new();
 
-    // Inicia los workers
-    let worker_one = spawn_worker("One", one_rx, &FLAGS[0]);
-    let worker_two = spawn_worker("Two", two_rx, &FLAGS[1]);
-    let worker_three = spawn_worker("Three", three_rx, &FLAGS[2]);
+    let runtime = Builder::new_multi_thread()
+        .worker_threads(WORKERS)
+        .thread_name("server-worker")
+        .build()?;
 
-    // Configuración de enrutamiento
-    let router = [one_tx, two_tx, three_tx];
-    let threads = [worker_one, worker_two, worker_three];
-    let mut index: usize = 0; // Índice para round-robin
+    let result = runtime.block_on(serve(&runtime, &shutdown, unix_path));
 
-    // Escucha en el puerto 7878
-    let listener = TcpListener::bind("0.0.0.0:7878")?;
-    println!("Server Listening on port 7878");
+    // Descarta a los clientes que no terminaron y espera a los workers
+    runtime.shutdown();
+    println!("Server stopped");
+    result
+}
 
-    // Bucle principal de aceptación de conexiones
-    for stream in listener.incoming() {
-        match stream {
-            Ok(stream) => {
-                // Envía conexión al worker actual
-                let _ = router[index].send(stream);
-
-                // Reactiva worker si estaba dormido
-                if FLAGS[index].load(Ordering::SeqCst) {
-                    FLAGS[index].store(false, Ordering::SeqCst);
-                    threads[index].thread().unpark();
-                }
+#[cfg(test)]
+mod tests {
+    use super::{READ_TIMEOUT, handle_client};
+    use async_runtime::{
+        io::{AsyncRead, AsyncWrite, Framed},
+        time,
+    };
+    use data_layer::{codec::DataCodec, data::Data};
+    use std::{
+        io,
+        pin::Pin,
+        task::{Context, Poll},
+        time::Duration,
+    };
+
+    /// Conexión en memoria, sin E/S real que el reloj simulado pueda adelantar
+    ///
+    /// Entrega `request` y después no vuelve a estar lista, como un cliente
+    /// que mantiene la conexión abierta. Lo escrito se guarda en `response`.
+    #[derive(Default)]
+    struct MockConnection {
+        request: Vec<u8>,
+        read: usize,
+        response: Vec<u8>,
+    }
 
-                // Actualiza índice para round-robin
-                index = (index + 1) % 3;
+    impl MockConnection {
+        /// Conexión que envía un mensaje como el cliente de carga
+        async fn with_request() -> MockConnection {
+            let message = Data {
+                field1: 1,
+                field2: 2,
+                field3: "hola".to_owned(),
+            };
+            let mut framed = Framed::new(Vec::new(), DataCodec::new());
+            framed.send(message).await.unwrap();
+            MockConnection {
+                request: framed.into_inner(),
+                ..MockConnection::default()
             }
-            Err(e) => {
-                println!("Connection failed: {}", e)
+        }
+    }
+
+    impl AsyncRead for MockConnection {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            let this = &mut *self;
+            let pending = &this.request[this.read..];
+            if pending.is_empty() {
+                return Poll::Pending;
             }
+            let len = pending.len().min(buf.len());
+            buf[..len].copy_from_slice(&pending[..len]);
+            this.read += len;
+            Poll::Ready(Ok(len))
         }
     }
 
-    Ok(())
+    impl AsyncWrite for MockConnection {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            Pin::new(&mut self.response).poll_write(cx, buf)
+        }
+
+        fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.response).poll_flush(cx)
+        }
+
+        fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.response).poll_shutdown(cx)
+        }
+    }
+
+    #[async_runtime::test]
+    async fn replies_after_simulated_processing() {
+        let mut connection = MockConnection::with_request().await;
+        let start = time::now();
+
+        handle_client(&mut connection, "prueba").await.unwrap();
+
+        assert_eq!(connection.response, b"Hello, Client!");
+        // El segundo de procesamiento pasa en el reloj simulado, no en el real
+        assert_eq!(time::now() - start, Duration::from_secs(1));
+    }
+
+    #[async_runtime::test]
+    async fn silent_client_times_out() {
+        let mut connection = MockConnection::default();
+        let start = time::now();
+
+        let served = handle_client(&mut connection, "prueba").await;
+
+        assert_eq!(served.unwrap_err().kind(), io::ErrorKind::TimedOut);
+        assert_eq!(time::now() - start, READ_TIMEOUT);
+        assert!(connection.response.is_empty());
+    }
 }